@@ -2,8 +2,11 @@
 // Ensures all three teams can work together without conflicts
 
 use std::collections::HashMap;
+use std::net::SocketAddr;
 use std::time::{Duration, Instant};
 
+use serde::Deserialize;
+
 // Import shared interfaces
 use super::super::interfaces::{
     network_layer::*,
@@ -11,12 +14,200 @@ use super::super::interfaces::{
     security_layer::*,
 };
 
+/// Name the default four-proof consensus engine is registered under.
+pub const DEFAULT_CONSENSUS_ENGINE: &str = "four_proof";
+
+/// A declarative suite of proof-validation vectors loaded from disk instead of
+/// hardcoded in the test builders. Keeping vectors in JSON/TOML lets CI grow
+/// the regression corpus — and lets the banking/ops teams contribute scenarios
+/// — without recompiling the integration crate.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TestSpec {
+    pub name: String,
+    #[serde(default)]
+    pub scenarios: Vec<TestScenario>,
+    #[serde(default)]
+    pub thresholds: SpecThresholds,
+}
+
+/// One proof-validation vector: the four-proof fields worth varying plus the
+/// expected verdict. Every field defaults to the built-in minimal valid proof
+/// so a scenario only has to spell out what it changes.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct TestScenario {
+    pub name: String,
+    pub capacity_commitment: u64,
+    pub stake_amount: u64,
+    pub cpu_cores: u32,
+    pub gpu_compute_units: u32,
+    pub memory_gb: u64,
+    pub storage_gb: u64,
+    pub bandwidth_mbps: u64,
+    pub difficulty_target: u64,
+    pub nonce: u64,
+    pub expect_valid: bool,
+}
+
+impl Default for TestScenario {
+    fn default() -> Self {
+        // Mirrors the original hardcoded `create_test_four_proof` vector.
+        TestScenario {
+            name: "default".to_string(),
+            capacity_commitment: 1000,
+            stake_amount: 1000,
+            cpu_cores: 4,
+            gpu_compute_units: 2,
+            memory_gb: 8,
+            storage_gb: 100,
+            bandwidth_mbps: 1000,
+            difficulty_target: 1000,
+            nonce: 12345,
+            expect_valid: true,
+        }
+    }
+}
+
+/// Assertion budgets a spec run holds its scenarios to. Defaults fall back to
+/// the interface-level targets so a spec can omit them entirely.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct SpecThresholds {
+    pub target_validation_time_ms: u64,
+}
+
+impl Default for SpecThresholds {
+    fn default() -> Self {
+        SpecThresholds {
+            target_validation_time_ms: TARGET_VALIDATION_TIME_MS,
+        }
+    }
+}
+
+impl TestSpec {
+    /// Load a spec from a JSON or TOML file, dispatching on the file extension.
+    pub fn from_file(path: &str) -> Result<TestSpec, String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| format!("cannot read spec file '{}': {}", path, e))?;
+        if path.ends_with(".toml") {
+            toml::from_str(&contents)
+                .map_err(|e| format!("invalid TOML spec '{}': {}", path, e))
+        } else {
+            serde_json::from_str(&contents)
+                .map_err(|e| format!("invalid JSON spec '{}': {}", path, e))
+        }
+    }
+}
+
 /// Integration test suite for all three teams
 pub struct CrossTeamIntegrationTestSuite {
     pub network_layer: Box<dyn NetworkLayer>,
-    pub consensus_layer: Box<dyn ConsensusLayer>,
+    /// Named consensus backends the suite validates. Every consensus-touching
+    /// test runs against each registered engine, so a new engine can be
+    /// confirmed as a drop-in replacement that passes the same cross-team
+    /// contract as the reference four-proof engine.
+    pub consensus_engines: HashMap<String, Box<dyn ConsensusLayer>>,
     pub security_layer: Box<dyn SecurityLayer>,
     pub test_results: HashMap<String, IntegrationTestResult>,
+    /// Network profile currently being exercised. `run_all_tests` sets this for
+    /// each profile in the matrix so individual tests read profile-specific
+    /// assertion thresholds and results are keyed per profile.
+    active_profile: NetworkProfile,
+    /// Compliance policy every proof/channel/adapter is evaluated against. A
+    /// single policy object governs the security thresholds across all tests,
+    /// so enterprise deployments can swap in a stricter policy without editing
+    /// individual test assertions.
+    policy: SecurityPolicy,
+}
+
+/// Network environment a pass of the suite runs under. Iterating the matrix of
+/// profiles turns the suite from a single happy-path check into coverage that
+/// catches profile-specific regressions (e.g. a NAT/firewall path that only
+/// works on the public internet).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkProfile {
+    PublicInternet,
+    EnterpriseFirewalled,
+    TestnetLowReachability,
+    AirGapped,
+}
+
+impl NetworkProfile {
+    /// Every profile, for running the full matrix.
+    pub fn iter() -> impl Iterator<Item = NetworkProfile> {
+        [
+            NetworkProfile::PublicInternet,
+            NetworkProfile::EnterpriseFirewalled,
+            NetworkProfile::TestnetLowReachability,
+            NetworkProfile::AirGapped,
+        ]
+        .into_iter()
+    }
+
+    /// Stable short name used to key per-profile results.
+    pub fn name(self) -> &'static str {
+        match self {
+            NetworkProfile::PublicInternet => "public_internet",
+            NetworkProfile::EnterpriseFirewalled => "enterprise_firewalled",
+            NetworkProfile::TestnetLowReachability => "testnet_low_reachability",
+            NetworkProfile::AirGapped => "air_gapped",
+        }
+    }
+
+    /// Assertion thresholds that vary by environment. Enterprise and air-gapped
+    /// deployments reach fewer public peers and run on constrained links, so the
+    /// same contract holds them to different bars than the public internet.
+    pub fn thresholds(self) -> ProfileThresholds {
+        match self {
+            NetworkProfile::PublicInternet => ProfileThresholds {
+                min_internet_reachability: TARGET_INTERNET_REACHABILITY,
+                require_firewall_compatible: false,
+                target_throughput_gbps: TARGET_THROUGHPUT_GBPS,
+            },
+            NetworkProfile::EnterpriseFirewalled => ProfileThresholds {
+                min_internet_reachability: 0.50,
+                require_firewall_compatible: true,
+                target_throughput_gbps: 5.0,
+            },
+            NetworkProfile::TestnetLowReachability => ProfileThresholds {
+                min_internet_reachability: 0.10,
+                require_firewall_compatible: false,
+                target_throughput_gbps: 1.0,
+            },
+            NetworkProfile::AirGapped => ProfileThresholds {
+                min_internet_reachability: 0.0,
+                require_firewall_compatible: true,
+                target_throughput_gbps: 1.0,
+            },
+        }
+    }
+}
+
+/// Size of the memory-mapped dataset streamed through encryption in the
+/// throughput test. Defaults to 256 MiB to exercise realistic payload sizes
+/// without pinning the whole buffer in RAM.
+pub const THROUGHPUT_DATASET_BYTES: usize = 256 * 1024 * 1024;
+/// Chunk size the memory-mapped dataset is streamed through encryption in.
+pub const THROUGHPUT_CHUNK_BYTES: usize = 1024 * 1024;
+
+/// Measured throughput of the memory-mapped encryption stream.
+#[derive(Debug, Clone, Copy)]
+pub struct MmapThroughput {
+    /// Sustained encryption throughput in gigabits per second.
+    pub gbps: f64,
+    /// Number of chunks streamed (the operation count for metrics).
+    pub chunks: u64,
+}
+
+/// Per-profile assertion thresholds resolved from a [`NetworkProfile`].
+#[derive(Debug, Clone, Copy)]
+pub struct ProfileThresholds {
+    /// Minimum fraction of internet users the network must reach.
+    pub min_internet_reachability: f32,
+    /// Whether enterprise-firewall compatibility is mandatory.
+    pub require_firewall_compatible: bool,
+    /// Throughput target the transport must sustain, in Gbps.
+    pub target_throughput_gbps: f64,
 }
 
 /// Result of integration test
@@ -45,45 +236,74 @@ impl CrossTeamIntegrationTestSuite {
         consensus: Box<dyn ConsensusLayer>,
         security: Box<dyn SecurityLayer>,
     ) -> Self {
+        let mut consensus_engines: HashMap<String, Box<dyn ConsensusLayer>> = HashMap::new();
+        consensus_engines.insert(DEFAULT_CONSENSUS_ENGINE.to_string(), consensus);
         Self {
             network_layer: network,
-            consensus_layer: consensus,
+            consensus_engines,
             security_layer: security,
             test_results: HashMap::new(),
+            active_profile: NetworkProfile::PublicInternet,
+            policy: SecurityPolicy::default(),
         }
     }
 
-    /// Run all integration tests
-    pub fn run_all_tests(&mut self) -> Result<IntegrationTestSummary, String> {
+    /// Replace the compliance policy all tests evaluate against. Lets an
+    /// enterprise entity install its own thresholds (minimum channel security,
+    /// allowed algorithms, economic floors) before running the suite.
+    pub fn set_policy(&mut self, policy: SecurityPolicy) {
+        self.policy = policy;
+    }
+
+    /// Register an additional named consensus engine to validate alongside the
+    /// default. `run_all_tests` then exercises the full consensus contract
+    /// against this backend too, keying its results
+    /// `"{profile}::{name}::{test_name}"`.
+    pub fn register_consensus_engine(&mut self, name: impl Into<String>, engine: Box<dyn ConsensusLayer>) {
+        self.consensus_engines.insert(name.into(), engine);
+    }
+
+    /// Run every integration test under each network profile in `profiles`,
+    /// keying results per profile so the summary reports which combinations
+    /// passed. An empty slice defaults to the public-internet profile.
+    pub fn run_all_tests(&mut self, profiles: &[NetworkProfile]) -> Result<IntegrationTestSummary, String> {
         println!("🚀 Starting Cross-Team Integration Tests");
-        
-        // Test 1: Network-Security Integration
-        self.test_network_security_integration()?;
-        
-        // Test 2: Network-Consensus Integration  
-        self.test_network_consensus_integration()?;
-        
-        // Test 3: Consensus-Security Integration
-        self.test_consensus_security_integration()?;
-        
-        // Test 4: Three-Way Integration
-        self.test_three_way_integration()?;
-        
-        // Test 5: Performance Integration
-        self.test_performance_integration()?;
-        
-        // Test 6: Enterprise Entity Integration
-        self.test_enterprise_integration()?;
-        
-        // Test 7: HyperMesh Asset Integration
-        self.test_hypermesh_asset_integration()?;
-        
+
+        let default_profiles = [NetworkProfile::PublicInternet];
+        let profiles = if profiles.is_empty() { &default_profiles[..] } else { profiles };
+
+        for &profile in profiles {
+            self.active_profile = profile;
+            println!("🌎 Profile: {}", profile.name());
+
+            // Test 1: Network-Security Integration
+            self.test_network_security_integration()?;
+
+            // Test 2: Network-Consensus Integration
+            self.test_network_consensus_integration()?;
+
+            // Test 3: Consensus-Security Integration
+            self.test_consensus_security_integration()?;
+
+            // Test 4: Three-Way Integration
+            self.test_three_way_integration()?;
+
+            // Test 5: Performance Integration
+            self.test_performance_integration()?;
+
+            // Test 6: Enterprise Entity Integration
+            self.test_enterprise_integration()?;
+
+            // Test 7: HyperMesh Asset Integration
+            self.test_hypermesh_asset_integration()?;
+        }
+
         Ok(self.generate_summary())
     }
 
     /// Test 1: Network-Security Integration (Team 1 ↔ Team 3)
     fn test_network_security_integration(&mut self) -> Result<(), String> {
-        let start_time = Instant::now();
+        let probe = PerfProbe::start();
         
         println!("🔒 Testing Network-Security Integration...");
         
@@ -117,211 +337,271 @@ impl CrossTeamIntegrationTestSuite {
             return Err("Insufficient security level for production use".to_string());
         }
         
-        self.record_test_result("network_security_integration", start_time, true, None);
+        self.record_test_result("network_security_integration", probe, 1, true, None);
         println!("✅ Network-Security Integration: PASSED");
         Ok(())
     }
 
     /// Test 2: Network-Consensus Integration (Team 1 ↔ Team 2)  
     fn test_network_consensus_integration(&mut self) -> Result<(), String> {
-        let start_time = Instant::now();
-        
         println!("⚖️ Testing Network-Consensus Integration...");
-        
-        // Test asset address resolution through network layer
-        let test_asset_id = [2u8; 32];
-        let network_address = self.network_layer
-            .resolve_asset_address(test_asset_id)
-            .map_err(|e| format!("Failed to resolve asset address: {:?}", e))?;
-        
-        // Test memory address resolution through consensus layer
-        let memory_address = self.consensus_layer
-            .resolve_asset_memory_address(test_asset_id)
-            .map_err(|e| format!("Failed to resolve memory address: {:?}", e))?;
-        
-        // Verify connectivity supports consensus requirements
-        let connectivity = self.network_layer.get_connectivity_status();
-        if connectivity.internet_reachability < TARGET_INTERNET_REACHABILITY {
-            return Err(format!(
-                "Insufficient internet reachability: {:.2}% < {:.2}%",
-                connectivity.internet_reachability * 100.0,
-                TARGET_INTERNET_REACHABILITY * 100.0
-            ));
-        }
-        
-        // Test cross-chain synchronization
-        let test_chain_state = self.create_test_chain_state();
-        let sync_result = self.consensus_layer
-            .cross_chain_sync(test_chain_state)
-            .map_err(|e| format!("Failed cross-chain sync: {:?}", e))?;
-        
-        if !sync_result.success {
-            return Err("Cross-chain synchronization failed".to_string());
+
+        for engine_name in self.engine_names() {
+            let probe = PerfProbe::start();
+            {
+                let consensus = self.engine(&engine_name);
+
+                // Test asset address resolution through network layer
+                let test_asset_id = [2u8; 32];
+                let network_address = self.network_layer
+                    .resolve_asset_address(test_asset_id)
+                    .map_err(|e| format!("Failed to resolve asset address: {:?}", e))?;
+
+                // Test memory address resolution through consensus layer
+                let memory_address = consensus
+                    .resolve_asset_memory_address(test_asset_id)
+                    .map_err(|e| format!("Failed to resolve memory address: {:?}", e))?;
+
+                // Verify connectivity supports consensus requirements, against
+                // the active profile's reachability floor.
+                let min_reachability = self.active_profile.thresholds().min_internet_reachability;
+                let connectivity = self.network_layer.get_connectivity_status();
+                if connectivity.internet_reachability < min_reachability {
+                    return Err(format!(
+                        "Insufficient internet reachability on {}: {:.2}% < {:.2}%",
+                        self.active_profile.name(),
+                        connectivity.internet_reachability * 100.0,
+                        min_reachability * 100.0
+                    ));
+                }
+
+                // Test cross-chain synchronization
+                let test_chain_state = self.create_test_chain_state();
+                let sync_result = consensus
+                    .cross_chain_sync(test_chain_state)
+                    .map_err(|e| format!("Failed cross-chain sync: {:?}", e))?;
+
+                if !sync_result.success {
+                    return Err(format!("Cross-chain synchronization failed for {}", engine_name));
+                }
+            }
+
+            self.record_test_result(
+                &format!("{}::network_consensus_integration", engine_name),
+                probe,
+                1,
+                true,
+                None,
+            );
         }
-        
-        self.record_test_result("network_consensus_integration", start_time, true, None);
+
         println!("✅ Network-Consensus Integration: PASSED");
         Ok(())
     }
 
     /// Test 3: Consensus-Security Integration (Team 2 ↔ Team 3)
     fn test_consensus_security_integration(&mut self) -> Result<(), String> {
-        let start_time = Instant::now();
-        
         println!("🔐 Testing Consensus-Security Integration...");
-        
-        // Generate asset keys through security layer
-        let test_asset_id = [3u8; 32];
-        let asset_keys = self.security_layer
-            .generate_asset_keys(test_asset_id)
-            .map_err(|e| format!("Failed to generate asset keys: {:?}", e))?;
-        
-        // Create test four-proof with security validation
-        let four_proof = self.create_test_four_proof();
-        
-        // Validate four proofs through consensus layer
-        let validation_result = self.consensus_layer
-            .validate_four_proofs(four_proof.clone())
-            .map_err(|e| format!("Failed to validate four proofs: {:?}", e))?;
-        
-        if !validation_result.is_valid {
-            return Err("Four-proof validation failed".to_string());
-        }
-        
-        // Test privacy-aware resource allocation
-        let privacy_level = PrivacyLevel::PublicNetwork;
-        let computational_resources = ComputationalResources {
-            cpu_cores: 4,
-            gpu_compute_units: 2,
-            memory_gb: 8,
-            storage_gb: 100,
-            bandwidth_mbps: 1000,
-        };
-        
-        let allocation_result = self.consensus_layer
-            .allocate_privacy_resources(privacy_level.clone(), computational_resources)
-            .map_err(|e| format!("Failed privacy resource allocation: {:?}", e))?;
-        
-        // Validate security compliance
-        let security_validation = self.security_layer
-            .validate_security_compliance("consensus_layer")
-            .map_err(|e| format!("Failed security compliance validation: {:?}", e))?;
-        
-        if !security_validation.is_secure {
-            return Err("Security compliance validation failed".to_string());
+
+        for engine_name in self.engine_names() {
+            let probe = PerfProbe::start();
+            {
+                let consensus = self.engine(&engine_name);
+
+                // Generate asset keys through security layer
+                let test_asset_id = [3u8; 32];
+                let asset_keys = self.security_layer
+                    .generate_asset_keys(test_asset_id)
+                    .map_err(|e| format!("Failed to generate asset keys: {:?}", e))?;
+
+                // Create test four-proof with security validation
+                let four_proof = self.create_test_four_proof();
+
+                // Validate four proofs through consensus layer
+                let validation_result = consensus
+                    .validate_four_proofs(four_proof.clone())
+                    .map_err(|e| format!("Failed to validate four proofs: {:?}", e))?;
+
+                if !validation_result.is_valid {
+                    return Err(format!("Four-proof validation failed for {}", engine_name));
+                }
+
+                // Test privacy-aware resource allocation
+                let privacy_level = PrivacyLevel::PublicNetwork;
+                let computational_resources = ComputationalResources {
+                    cpu_cores: 4,
+                    gpu_compute_units: 2,
+                    memory_gb: 8,
+                    storage_gb: 100,
+                    bandwidth_mbps: 1000,
+                };
+
+                let allocation_result = consensus
+                    .allocate_privacy_resources(privacy_level.clone(), computational_resources)
+                    .map_err(|e| format!("Failed privacy resource allocation: {:?}", e))?;
+
+                // Validate security compliance
+                let security_validation = self.security_layer
+                    .validate_security_compliance("consensus_layer")
+                    .map_err(|e| format!("Failed security compliance validation: {:?}", e))?;
+
+                if !security_validation.is_secure {
+                    return Err("Security compliance validation failed".to_string());
+                }
+            }
+
+            self.record_test_result(
+                &format!("{}::consensus_security_integration", engine_name),
+                probe,
+                1,
+                true,
+                None,
+            );
         }
-        
-        self.record_test_result("consensus_security_integration", start_time, true, None);
+
         println!("✅ Consensus-Security Integration: PASSED");
         Ok(())
     }
 
     /// Test 4: Three-Way Integration (All Teams)
     fn test_three_way_integration(&mut self) -> Result<(), String> {
-        let start_time = Instant::now();
-        
         println!("🌐 Testing Three-Way Integration (All Teams)...");
-        
-        // Simulate complete asset creation workflow
-        let asset_id = [4u8; 32];
-        
-        // 1. Network: Resolve asset address
-        let network_address = self.network_layer
-            .resolve_asset_address(asset_id)
-            .map_err(|e| format!("Network address resolution failed: {:?}", e))?;
-        
-        // 2. Security: Generate keys and configure adapter
-        let asset_keys = self.security_layer
-            .generate_asset_keys(asset_id)
-            .map_err(|e| format!("Asset key generation failed: {:?}", e))?;
-        
-        let adapter_security = self.security_layer
-            .configure_asset_adapter_security(AssetType::CPU)
-            .map_err(|e| format!("Asset adapter security configuration failed: {:?}", e))?;
-        
-        // 3. Consensus: Validate and record asset state
-        let four_proof = self.create_test_four_proof();
-        let validation_result = self.consensus_layer
-            .validate_four_proofs(four_proof.clone())
-            .map_err(|e| format!("Four-proof validation failed: {:?}", e))?;
-        
-        if !validation_result.is_valid {
-            return Err("Asset validation failed in three-way integration".to_string());
-        }
-        
-        let asset_state = self.create_test_asset_state(asset_id, four_proof.clone());
-        let state_hash = self.consensus_layer
-            .record_asset_state(asset_state, four_proof)
-            .map_err(|e| format!("Asset state recording failed: {:?}", e))?;
-        
-        // 4. Verify all systems are working together
-        if state_hash == [0u8; 32] {
-            return Err("Invalid state hash returned".to_string());
+
+        for engine_name in self.engine_names() {
+            let probe = PerfProbe::start();
+            {
+                let consensus = self.engine(&engine_name);
+
+                // Simulate complete asset creation workflow
+                let asset_id = [4u8; 32];
+
+                // 1. Network: Resolve asset address
+                let network_address = self.network_layer
+                    .resolve_asset_address(asset_id)
+                    .map_err(|e| format!("Network address resolution failed: {:?}", e))?;
+
+                // 2. Security: Generate keys and configure adapter
+                let asset_keys = self.security_layer
+                    .generate_asset_keys(asset_id)
+                    .map_err(|e| format!("Asset key generation failed: {:?}", e))?;
+
+                let adapter_security = self.security_layer
+                    .configure_asset_adapter_security(AssetType::CPU)
+                    .map_err(|e| format!("Asset adapter security configuration failed: {:?}", e))?;
+
+                // 3. Consensus: Validate and record asset state
+                let four_proof = self.create_test_four_proof();
+                let validation_result = consensus
+                    .validate_four_proofs(four_proof.clone())
+                    .map_err(|e| format!("Four-proof validation failed: {:?}", e))?;
+
+                if !validation_result.is_valid {
+                    return Err(format!("Asset validation failed in three-way integration for {}", engine_name));
+                }
+
+                let asset_state = self.create_test_asset_state(asset_id, four_proof.clone());
+                let state_hash = consensus
+                    .record_asset_state(asset_state, four_proof)
+                    .map_err(|e| format!("Asset state recording failed: {:?}", e))?;
+
+                // 4. Verify all systems are working together
+                if state_hash == [0u8; 32] {
+                    return Err("Invalid state hash returned".to_string());
+                }
+            }
+
+            self.record_test_result(
+                &format!("{}::three_way_integration", engine_name),
+                probe,
+                1,
+                true,
+                None,
+            );
         }
-        
-        self.record_test_result("three_way_integration", start_time, true, None);
+
         println!("✅ Three-Way Integration: PASSED");
         Ok(())
     }
 
     /// Test 5: Performance Integration
     fn test_performance_integration(&mut self) -> Result<(), String> {
-        let start_time = Instant::now();
-        
         println!("⚡ Testing Performance Integration...");
-        
-        // Test network performance targets
-        let transport_performance = self.network_layer.get_transport_performance();
-        if transport_performance.current_throughput_gbps < TARGET_THROUGHPUT_GBPS {
-            return Err(format!(
-                "Insufficient network throughput: {:.2} Gbps < {:.2} Gbps",
-                transport_performance.current_throughput_gbps,
-                TARGET_THROUGHPUT_GBPS
-            ));
-        }
-        
-        // Test consensus validation performance
-        let four_proof = self.create_test_four_proof();
-        let validation_start = Instant::now();
-        let validation_result = self.consensus_layer
-            .validate_four_proofs(four_proof)
-            .map_err(|e| format!("Performance validation failed: {:?}", e))?;
-        
-        if validation_result.validation_time_ms > TARGET_VALIDATION_TIME_MS {
-            return Err(format!(
-                "Consensus validation too slow: {} ms > {} ms",
-                validation_result.validation_time_ms,
-                TARGET_VALIDATION_TIME_MS
-            ));
-        }
-        
-        // Test security operation performance
-        let test_data = vec![0u8; 1024 * 1024]; // 1MB test data
-        let test_peer = [5u8; 32];
-        let channel = self.network_layer.establish_secure_channel(test_peer).unwrap();
-        
-        let encryption_start = Instant::now();
-        let _encrypted = self.security_layer
-            .encrypt_transport(&test_data, &channel)
-            .map_err(|e| format!("Performance encryption failed: {:?}", e))?;
-        let encryption_time = encryption_start.elapsed();
-        
-        // Security operations should complete within reasonable time
-        if encryption_time > Duration::from_millis(100) {
-            return Err(format!(
-                "Security encryption too slow: {} ms > 100 ms",
-                encryption_time.as_millis()
-            ));
+
+        for engine_name in self.engine_names() {
+            let probe = PerfProbe::start();
+            let mut perf_operations = 0u64;
+            {
+                let consensus = self.engine(&engine_name);
+
+                // Test network performance targets against the active profile.
+                let target_throughput = self.active_profile.thresholds().target_throughput_gbps;
+                let transport_performance = self.network_layer.get_transport_performance();
+                if transport_performance.current_throughput_gbps < target_throughput {
+                    return Err(format!(
+                        "Insufficient network throughput on {}: {:.2} Gbps < {:.2} Gbps",
+                        self.active_profile.name(),
+                        transport_performance.current_throughput_gbps,
+                        target_throughput
+                    ));
+                }
+
+                // Test consensus validation performance
+                let four_proof = self.create_test_four_proof();
+                let validation_start = Instant::now();
+                let validation_result = consensus
+                    .validate_four_proofs(four_proof)
+                    .map_err(|e| format!("Performance validation failed: {:?}", e))?;
+
+                if validation_result.validation_time_ms > TARGET_VALIDATION_TIME_MS {
+                    return Err(format!(
+                        "Consensus validation too slow for {}: {} ms > {} ms",
+                        engine_name,
+                        validation_result.validation_time_ms,
+                        TARGET_VALIDATION_TIME_MS
+                    ));
+                }
+
+                // Test security operation throughput against a realistically
+                // large, memory-mapped dataset streamed in chunks, so we measure
+                // encryption against multi-hundred-MB payloads without pinning
+                // the whole buffer in the heap.
+                let test_peer = [5u8; 32];
+                let channel = self.network_layer.establish_secure_channel(test_peer).unwrap();
+
+                let throughput = self.mmap_encryption_throughput(
+                    &channel,
+                    THROUGHPUT_DATASET_BYTES,
+                    THROUGHPUT_CHUNK_BYTES,
+                )?;
+
+                if throughput.gbps < target_throughput {
+                    return Err(format!(
+                        "Security encryption throughput on {} too low: {:.2} Gbps < {:.2} Gbps",
+                        self.active_profile.name(),
+                        throughput.gbps,
+                        target_throughput
+                    ));
+                }
+
+                perf_operations = throughput.chunks;
+            }
+
+            self.record_test_result(
+                &format!("{}::performance_integration", engine_name),
+                probe,
+                perf_operations,
+                true,
+                None,
+            );
         }
-        
-        self.record_test_result("performance_integration", start_time, true, None);
+
         println!("✅ Performance Integration: PASSED");
         Ok(())
     }
 
     /// Test 6: Enterprise Entity Integration
     fn test_enterprise_integration(&mut self) -> Result<(), String> {
-        let start_time = Instant::now();
+        let probe = PerfProbe::start();
         
         println!("🏢 Testing Enterprise Entity Integration...");
         
@@ -329,11 +609,14 @@ impl CrossTeamIntegrationTestSuite {
         
         // Test each enterprise entity type
         for entity_type in &enterprise_entities {
-            // Network: Test enterprise connectivity
+            // Network: Test enterprise connectivity. Firewall compatibility is
+            // only mandatory on profiles that run behind one.
             let connectivity_result = self.network_layer
                 .get_connectivity_status();
-            
-            if !connectivity_result.firewall_compatible {
+
+            if self.active_profile.thresholds().require_firewall_compatible
+                && !connectivity_result.firewall_compatible
+            {
                 return Err(format!("Enterprise firewall compatibility failed for {}", entity_type));
             }
             
@@ -347,46 +630,65 @@ impl CrossTeamIntegrationTestSuite {
             }
         }
         
-        self.record_test_result("enterprise_integration", start_time, true, None);
+        self.record_test_result("enterprise_integration", probe, 1, true, None);
         println!("✅ Enterprise Entity Integration: PASSED");
         Ok(())
     }
 
     /// Test 7: HyperMesh Asset Integration
     fn test_hypermesh_asset_integration(&mut self) -> Result<(), String> {
-        let start_time = Instant::now();
+        let probe = PerfProbe::start();
         
         println!("🔗 Testing HyperMesh Asset Integration...");
-        
+
         // Test all asset types with proper adapters
         let asset_types = vec![
             AssetType::CPU,
-            AssetType::GPU, 
+            AssetType::GPU,
             AssetType::Memory,
             AssetType::Storage,
             AssetType::Network,
         ];
-        
+
+        // Evaluate each adapter through the shared compliance policy, which
+        // subsumes the former standalone quantum-resistance check.
+        let four_proof = self.create_test_four_proof();
+        let channel = self.network_layer
+            .establish_secure_channel([6u8; 32])
+            .map_err(|e| format!("Asset integration channel setup failed: {:?}", e))?;
+
         for asset_type in asset_types {
             // Security: Configure asset adapter
             let adapter_security = self.security_layer
                 .configure_asset_adapter_security(asset_type.clone())
                 .map_err(|e| format!("Asset adapter configuration failed for {:?}: {:?}", asset_type, e))?;
-            
-            // Verify quantum resistance for production use
-            if !adapter_security.encryption_algorithm.is_quantum_resistant() {
-                return Err(format!("Asset adapter {:?} lacks quantum resistance", asset_type));
+
+            // Compliance: the adapter, proof, and channel must satisfy the policy.
+            let report = self.policy.evaluate(&four_proof, &channel, &adapter_security);
+            if !report.is_compliant() {
+                return Err(format!(
+                    "Asset adapter {:?} violates security policy: {}",
+                    asset_type,
+                    report.summary()
+                ));
             }
         }
-        
-        self.record_test_result("hypermesh_asset_integration", start_time, true, None);
+
+        self.record_test_result("hypermesh_asset_integration", probe, 1, true, None);
         println!("✅ HyperMesh Asset Integration: PASSED");
         Ok(())
     }
 
     /// Helper methods for test data creation
     fn create_test_four_proof(&self) -> FourProof {
-        // Create minimal valid four-proof for testing
+        // The built-in minimal valid four-proof is just the default scenario.
+        self.four_proof_from_scenario(&TestScenario::default())
+    }
+
+    /// Build a `FourProof` from a declarative scenario, overriding the spec'd
+    /// fields (capacity, stake, resources, difficulty, nonce) while keeping the
+    /// remaining structural defaults used by the built-in vectors.
+    fn four_proof_from_scenario(&self, scenario: &TestScenario) -> FourProof {
         FourProof {
             po_space: PoSpaceProof {
                 storage_location: StorageLocation {
@@ -401,13 +703,13 @@ impl CrossTeamIntegrationTestSuite {
                     nat_proxy_address: None,
                     reachability_score: 1.0,
                 },
-                capacity_commitment: 1000,
+                capacity_commitment: scenario.capacity_commitment,
                 access_proof: vec![1, 2, 3],
                 verification_challenge: [1u8; 32],
             },
             po_stake: PoStakeProof {
                 owner_identity: [1u8; 32],
-                stake_amount: 1000,
+                stake_amount: scenario.stake_amount,
                 access_rights: AccessRights {
                     read_permission: true,
                     write_permission: true,
@@ -416,7 +718,7 @@ impl CrossTeamIntegrationTestSuite {
                     privacy_level: PrivacyLevel::PublicNetwork,
                 },
                 economic_commitment: EconomicCommitment {
-                    staked_tokens: 1000,
+                    staked_tokens: scenario.stake_amount,
                     commitment_duration: Duration::from_secs(3600),
                     slashing_conditions: vec![],
                     reward_rate: 0.05,
@@ -425,11 +727,11 @@ impl CrossTeamIntegrationTestSuite {
             },
             po_work: PoWorkProof {
                 computational_resources: ComputationalResources {
-                    cpu_cores: 4,
-                    gpu_compute_units: 2,
-                    memory_gb: 8,
-                    storage_gb: 100,
-                    bandwidth_mbps: 1000,
+                    cpu_cores: scenario.cpu_cores,
+                    gpu_compute_units: scenario.gpu_compute_units,
+                    memory_gb: scenario.memory_gb,
+                    storage_gb: scenario.storage_gb,
+                    bandwidth_mbps: scenario.bandwidth_mbps,
                 },
                 processing_commitment: ProcessingCommitment {
                     max_execution_time: Duration::from_secs(3600),
@@ -438,8 +740,8 @@ impl CrossTeamIntegrationTestSuite {
                     quality_of_service: QualityOfService::BestEffort,
                 },
                 work_verification: [1u8; 32],
-                difficulty_target: 1000,
-                nonce: 12345,
+                difficulty_target: scenario.difficulty_target,
+                nonce: scenario.nonce,
             },
             po_time: PoTimeProof {
                 timestamp: std::time::SystemTime::now(),
@@ -449,6 +751,7 @@ impl CrossTeamIntegrationTestSuite {
                     time_server_attestation: [1u8; 64],
                     drift_tolerance_ms: 1000,
                     validation_authority: [1u8; 32],
+                    relative_lock: None,
                 },
                 sequence_number: 1,
                 temporal_signature: [1u8; 64],
@@ -456,6 +759,168 @@ impl CrossTeamIntegrationTestSuite {
         }
     }
 
+    /// Run every scenario described by a loaded [`TestSpec`] against the default
+    /// consensus engine, asserting each validates to its declared expected
+    /// outcome and meets the spec's validation-time threshold. Results are keyed
+    /// by scenario name so a spec file doubles as a library of regression
+    /// vectors that CI can grow without recompiling.
+    pub fn run_spec(&mut self, spec: &TestSpec) -> Result<IntegrationTestSummary, String> {
+        println!("🧪 Running test spec: {}", spec.name);
+
+        for scenario in &spec.scenarios {
+            let probe = PerfProbe::start();
+            let four_proof = self.four_proof_from_scenario(scenario);
+
+            let validation = self
+                .engine(DEFAULT_CONSENSUS_ENGINE)
+                .validate_four_proofs(four_proof)
+                .map_err(|e| format!("Spec scenario '{}' validation errored: {:?}", scenario.name, e))?;
+
+            let outcome_ok = validation.is_valid == scenario.expect_valid;
+            let within_budget = validation.validation_time_ms <= spec.thresholds.target_validation_time_ms;
+            let success = outcome_ok && within_budget;
+
+            let error = if !outcome_ok {
+                Some(format!(
+                    "expected valid={}, got valid={}",
+                    scenario.expect_valid, validation.is_valid
+                ))
+            } else if !within_budget {
+                Some(format!(
+                    "validation too slow: {} ms > {} ms",
+                    validation.validation_time_ms, spec.thresholds.target_validation_time_ms
+                ))
+            } else {
+                None
+            };
+
+            self.record_test_result(&format!("spec::{}", scenario.name), probe, 1, success, error);
+        }
+
+        Ok(self.generate_summary())
+    }
+
+    /// Adversarial (Byzantine) integration test. Wraps the honest layers in
+    /// fault-injecting shims and asserts the cross-team contract *fails closed*:
+    /// every injected fault must be rejected by validation rather than silently
+    /// accepted. Each check "passes" precisely when the system refuses the bad
+    /// input, so a regression that starts accepting corrupted proofs shows up as
+    /// a failing adversarial result.
+    pub fn test_adversarial_integration(&mut self) -> Result<(), String> {
+        println!("😈 Testing Adversarial (Byzantine) Integration...");
+
+        // (name, rejected?, error-if-not-rejected). Collected under immutable
+        // borrows of the honest layers, then recorded once the borrows end.
+        let mut outcomes: Vec<(String, bool, Option<String>)> = Vec::new();
+
+        {
+            let honest = self.engine(DEFAULT_CONSENSUS_ENGINE);
+            let base_proof = self.create_test_four_proof();
+
+            // 1. A tampered ownership signature must not validate.
+            let faulty = FaultyConsensus::new(
+                honest,
+                FaultInjection { tamper_ownership_signature: true, ..FaultInjection::none() },
+            );
+            outcomes.push(expect_proof_rejected(
+                "tampered_ownership_signature",
+                &faulty,
+                base_proof.clone(),
+            ));
+
+            // 2. A timestamp stale beyond `drift_tolerance_ms` must not validate.
+            let faulty = FaultyConsensus::new(
+                honest,
+                FaultInjection { stale_timestamp: true, ..FaultInjection::none() },
+            );
+            outcomes.push(expect_proof_rejected(
+                "stale_timestamp",
+                &faulty,
+                base_proof.clone(),
+            ));
+
+            // 3. An invalid state root must abort cross-chain sync.
+            let faulty = FaultyConsensus::new(
+                honest,
+                FaultInjection { invalid_state_root: true, ..FaultInjection::none() },
+            );
+            let rejected = match faulty.cross_chain_sync(self.create_test_chain_state()) {
+                Ok(result) => !result.success,
+                Err(_) => true,
+            };
+            outcomes.push((
+                "invalid_state_root".to_string(),
+                rejected,
+                (!rejected).then(|| "cross_chain_sync accepted an invalid state root".to_string()),
+            ));
+        }
+
+        {
+            let honest_network = self.network_layer.as_ref();
+            let honest_security = self.security_layer.as_ref();
+
+            let channel = honest_network
+                .establish_secure_channel([7u8; 32])
+                .map_err(|e| format!("adversarial setup channel failed: {:?}", e))?;
+            let ciphertext = honest_security
+                .encrypt_transport(b"adversarial integration payload", &channel)
+                .map_err(|e| format!("adversarial setup encrypt failed: {:?}", e))?;
+
+            // 4. Corrupted ciphertext must fail to decrypt.
+            let faulty = FaultySecurity::new(
+                honest_security,
+                FaultInjection { corrupt_ciphertext: true, ..FaultInjection::none() },
+            );
+            let rejected = faulty.decrypt_transport(&ciphertext, &channel).is_err();
+            outcomes.push((
+                "corrupted_ciphertext".to_string(),
+                rejected,
+                (!rejected).then(|| "decrypt_transport accepted corrupted ciphertext".to_string()),
+            ));
+
+            // 5. A dropped handshake must fail closed, not hand back a channel.
+            let faulty = FaultyNetwork::new(
+                honest_network,
+                FaultInjection { drop_messages: true, ..FaultInjection::none() },
+            );
+            let rejected = faulty.establish_secure_channel([8u8; 32]).is_err();
+            outcomes.push((
+                "dropped_handshake".to_string(),
+                rejected,
+                (!rejected).then(|| "establish_secure_channel succeeded despite dropped messages".to_string()),
+            ));
+
+            // 6. A tampered channel must fail channel-security validation.
+            let faulty = FaultyNetwork::new(
+                honest_network,
+                FaultInjection { tamper_channel: true, ..FaultInjection::none() },
+            );
+            let rejected = match faulty.establish_secure_channel([9u8; 32]) {
+                Ok(tampered) => honest_network.validate_channel_security(&tampered).is_err(),
+                Err(_) => true,
+            };
+            outcomes.push((
+                "tampered_channel".to_string(),
+                rejected,
+                (!rejected).then(|| "validate_channel_security accepted a tampered channel".to_string()),
+            ));
+        }
+
+        let probe = PerfProbe::start();
+        let mut all_rejected = true;
+        for (name, rejected, error) in outcomes {
+            all_rejected &= rejected;
+            self.record_test_result(&format!("adversarial::{}", name), probe, 1, rejected, error);
+        }
+
+        if all_rejected {
+            println!("✅ Adversarial Integration: PASSED (all faults rejected)");
+        } else {
+            println!("❌ Adversarial Integration: a fault was silently accepted");
+        }
+        Ok(())
+    }
+
     fn create_test_chain_state(&self) -> ChainState {
         ChainState {
             chain_id: 1,
@@ -476,45 +941,193 @@ impl CrossTeamIntegrationTestSuite {
         }
     }
 
-    fn record_test_result(&mut self, test_name: &str, start_time: Instant, success: bool, error: Option<String>) {
-        let execution_time = start_time.elapsed().as_millis() as u64;
-        
+    /// Registered engine names in a deterministic order, so repeated runs key
+    /// their results identically regardless of the map's iteration order.
+    fn engine_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.consensus_engines.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    /// Borrow a registered consensus engine by name. The name always comes from
+    /// `engine_names` or is `DEFAULT_CONSENSUS_ENGINE`, which `new` registers,
+    /// so the lookup cannot miss.
+    fn engine(&self, name: &str) -> &dyn ConsensusLayer {
+        self.consensus_engines
+            .get(name)
+            .expect("registered consensus engine")
+            .as_ref()
+    }
+
+    /// Stream a `dataset_bytes` memory-mapped buffer through the security
+    /// layer's `encrypt_transport` in `chunk_bytes` slices and measure the
+    /// sustained throughput. The dataset is backed by a temp file and mapped so
+    /// it is paged in on demand — encryption is exercised against realistic
+    /// payload sizes without allocating the whole buffer on the heap.
+    fn mmap_encryption_throughput(
+        &self,
+        channel: &SecureChannel,
+        dataset_bytes: usize,
+        chunk_bytes: usize,
+    ) -> Result<MmapThroughput, String> {
+        use std::fs::OpenOptions;
+
+        let path = std::env::temp_dir().join(format!("caesar_mmap_throughput_{}.bin", std::process::id()));
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&path)
+            .map_err(|e| format!("Failed to open mmap dataset: {e}"))?;
+        file.set_len(dataset_bytes as u64)
+            .map_err(|e| format!("Failed to size mmap dataset: {e}"))?;
+
+        // SAFETY: the file is owned exclusively for the duration of the map and
+        // is not mutated by another process while mapped.
+        let mmap = unsafe {
+            memmap2::Mmap::map(&file).map_err(|e| format!("Failed to mmap dataset: {e}"))?
+        };
+
+        let start = Instant::now();
+        let mut chunks = 0u64;
+        for chunk in mmap.chunks(chunk_bytes) {
+            self.security_layer
+                .encrypt_transport(chunk, channel)
+                .map_err(|e| format!("Performance encryption failed: {:?}", e))?;
+            chunks += 1;
+        }
+        let elapsed = start.elapsed().as_secs_f64().max(f64::MIN_POSITIVE);
+
+        // Best-effort cleanup; drop the map first so the file is no longer in use.
+        drop(mmap);
+        let _ = std::fs::remove_file(&path);
+
+        let gbps = (dataset_bytes as f64 * 8.0) / 1e9 / elapsed;
+        Ok(MmapThroughput { gbps, chunks })
+    }
+
+    fn record_test_result(&mut self, test_name: &str, probe: PerfProbe, operations: u64, success: bool, error: Option<String>) {
+        let elapsed = probe.start.elapsed();
+        let execution_time = elapsed.as_millis() as u64;
+
+        // Derive real performance metrics from sampled process state rather than
+        // fabricating them: throughput from the operation count over wall time,
+        // CPU% from the process CPU-time delta over wall time, and memory from
+        // the current resident set size.
+        let elapsed_secs = elapsed.as_secs_f64().max(f64::MIN_POSITIVE);
+        let throughput_ops_per_sec = operations as f64 / elapsed_secs;
+        let cpu_delta = read_cpu_time().saturating_sub(probe.cpu_start);
+        let cpu_usage_percent = ((cpu_delta.as_secs_f64() / elapsed_secs) * 100.0) as f32;
+
+        // Key every result with the active profile so the same test/engine pair
+        // is tracked independently for each environment in the matrix.
+        let key = format!("{}::{}", self.active_profile.name(), test_name);
+
         let result = IntegrationTestResult {
-            test_name: test_name.to_string(),
+            test_name: key.clone(),
             success,
             execution_time_ms: execution_time,
             error_message: error,
             performance_metrics: PerformanceMetrics {
-                throughput_ops_per_sec: if success { 100.0 } else { 0.0 },
+                throughput_ops_per_sec,
                 latency_ms: execution_time,
-                memory_usage_mb: 64, // Placeholder
-                cpu_usage_percent: 25.0, // Placeholder
+                memory_usage_mb: read_rss_mb(),
+                cpu_usage_percent,
             },
         };
-        
-        self.test_results.insert(test_name.to_string(), result);
+
+        self.test_results.insert(key, result);
     }
 
     fn generate_summary(&self) -> IntegrationTestSummary {
         let total_tests = self.test_results.len();
         let passed_tests = self.test_results.values().filter(|r| r.success).count();
         let failed_tests = total_tests - passed_tests;
-        
+
         let total_execution_time: u64 = self.test_results.values()
             .map(|r| r.execution_time_ms)
             .sum();
-        
+
+        // Roll results up per profile (the first `::`-delimited segment of each
+        // key) so callers can see which environments passed.
+        let mut results_by_profile: HashMap<String, ProfileResult> = HashMap::new();
+        for (key, result) in &self.test_results {
+            let profile = key.split("::").next().unwrap_or("unknown").to_string();
+            let entry = results_by_profile.entry(profile).or_default();
+            entry.total += 1;
+            if result.success {
+                entry.passed += 1;
+            } else {
+                entry.failed += 1;
+            }
+        }
+
         IntegrationTestSummary {
             total_tests,
             passed_tests,
             failed_tests,
             total_execution_time_ms: total_execution_time,
             success_rate: (passed_tests as f32 / total_tests as f32) * 100.0,
+            results_by_profile,
             test_results: self.test_results.clone(),
         }
     }
 }
 
+/// A wall-clock + CPU-time snapshot taken at the start of a test, so
+/// `record_test_result` can report the real CPU share a test consumed.
+#[derive(Clone, Copy)]
+struct PerfProbe {
+    start: Instant,
+    cpu_start: Duration,
+}
+
+impl PerfProbe {
+    fn start() -> Self {
+        Self {
+            start: Instant::now(),
+            cpu_start: read_cpu_time(),
+        }
+    }
+}
+
+/// Current resident set size in megabytes, read from `/proc/self/statm` on
+/// Linux. Returns 0 on platforms where the file is unavailable.
+fn read_rss_mb() -> u64 {
+    // statm fields are in pages; the second field is the resident set size.
+    if let Ok(contents) = std::fs::read_to_string("/proc/self/statm") {
+        if let Some(rss_pages) = contents.split_whitespace().nth(1) {
+            if let Ok(pages) = rss_pages.parse::<u64>() {
+                const PAGE_SIZE: u64 = 4096;
+                return pages * PAGE_SIZE / (1024 * 1024);
+            }
+        }
+    }
+    0
+}
+
+/// Accumulated process CPU time (user + system), read from `/proc/self/stat` on
+/// Linux. This is the portable-filesystem stand-in for `getrusage`; it returns
+/// zero where `/proc` is unavailable so callers degrade to wall-clock only.
+fn read_cpu_time() -> Duration {
+    if let Ok(contents) = std::fs::read_to_string("/proc/self/stat") {
+        // The `comm` field can contain spaces and parentheses, so parse the
+        // fields that follow the final ')'. After it, index 11 is utime and
+        // index 12 is stime, both in clock ticks.
+        if let Some(idx) = contents.rfind(')') {
+            let fields: Vec<&str> = contents[idx + 1..].split_whitespace().collect();
+            if fields.len() > 12 {
+                let utime = fields[11].parse::<u64>().unwrap_or(0);
+                let stime = fields[12].parse::<u64>().unwrap_or(0);
+                const CLOCK_TICKS_PER_SEC: f64 = 100.0;
+                return Duration::from_secs_f64((utime + stime) as f64 / CLOCK_TICKS_PER_SEC);
+            }
+        }
+    }
+    Duration::ZERO
+}
+
 /// Summary of integration test results
 #[derive(Debug)]
 pub struct IntegrationTestSummary {
@@ -523,11 +1136,254 @@ pub struct IntegrationTestSummary {
     pub failed_tests: usize,
     pub total_execution_time_ms: u64,
     pub success_rate: f32,
+    /// Pass/fail tallies broken down per network profile.
+    pub results_by_profile: HashMap<String, ProfileResult>,
     pub test_results: HashMap<String, IntegrationTestResult>,
 }
 
+/// Pass/fail tally for a single network profile within the coverage matrix.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ProfileResult {
+    pub passed: usize,
+    pub failed: usize,
+    pub total: usize,
+}
+
+impl ProfileResult {
+    /// True when every test under this profile passed.
+    pub fn all_passed(&self) -> bool {
+        self.total > 0 && self.failed == 0
+    }
+}
+
+/// Run a (possibly fault-injected) proof through an engine and report whether
+/// the engine *rejected* it — either by returning an error or a non-valid
+/// result. Used by the adversarial test so every proof-level fault shares the
+/// same fail-closed expectation.
+fn expect_proof_rejected(
+    name: &str,
+    engine: &dyn ConsensusLayer,
+    proof: FourProof,
+) -> (String, bool, Option<String>) {
+    let rejected = match engine.validate_four_proofs(proof) {
+        Ok(result) => !result.is_valid,
+        Err(_) => true,
+    };
+    let error = (!rejected).then(|| format!("validate_four_proofs accepted {}", name));
+    (name.to_string(), rejected, error)
+}
+
+/// Configurable misbehavior injected into the layer shims below. Every field
+/// defaults to "no fault", so a test enables exactly the one misbehavior it
+/// wants to prove the contract rejects.
+#[derive(Debug, Clone, Default)]
+pub struct FaultInjection {
+    /// Drop the handshake so `establish_secure_channel` fails closed.
+    pub drop_messages: bool,
+    /// Delay the handshake by this duration before delegating.
+    pub delay: Option<Duration>,
+    /// Corrupt ciphertext bytes before `decrypt_transport`.
+    pub corrupt_ciphertext: bool,
+    /// Flip bytes of `po_stake.ownership_signature`.
+    pub tamper_ownership_signature: bool,
+    /// Push `po_time.timestamp` beyond `drift_tolerance_ms` into the past.
+    pub stale_timestamp: bool,
+    /// Replace `ChainState.state_root` with a value the honest root never hashes to.
+    pub invalid_state_root: bool,
+    /// Zero out the channel's session keys to simulate a tampered handshake.
+    pub tamper_channel: bool,
+}
+
+impl FaultInjection {
+    /// A config that injects no faults.
+    pub fn none() -> Self {
+        Self::default()
+    }
+
+    /// Apply the enabled proof-level faults in place.
+    fn tamper_proof(&self, proof: &mut FourProof) {
+        if self.tamper_ownership_signature {
+            proof.po_stake.ownership_signature[0] ^= 0xFF;
+        }
+        if self.stale_timestamp {
+            let beyond = Duration::from_millis(proof.po_time.time_validation.drift_tolerance_ms)
+                + Duration::from_secs(1);
+            proof.po_time.timestamp = std::time::SystemTime::now()
+                .checked_sub(beyond)
+                .unwrap_or(std::time::UNIX_EPOCH);
+        }
+    }
+
+    /// Apply the enabled chain-state faults in place.
+    fn tamper_chain_state(&self, state: &mut ChainState) {
+        if self.invalid_state_root {
+            state.state_root = [0xFFu8; 32];
+        }
+    }
+
+    /// Apply the enabled channel faults in place.
+    fn tamper_channel(&self, channel: &mut SecureChannel) {
+        if self.tamper_channel {
+            channel.encryption_keys.send_key = [0u8; 32];
+            channel.encryption_keys.receive_key = [0u8; 32];
+        }
+    }
+
+    /// Return a corrupted copy of `data` (flipping the first byte, or appending
+    /// one when empty) so the honest decryptor sees a mutated ciphertext.
+    fn corrupt(&self, data: &[u8]) -> Vec<u8> {
+        let mut corrupted = data.to_vec();
+        match corrupted.first_mut() {
+            Some(byte) => *byte ^= 0xFF,
+            None => corrupted.push(0xFF),
+        }
+        corrupted
+    }
+}
+
+/// Fault-injecting shim over an honest [`NetworkLayer`]. Drops or delays the
+/// handshake and can hand back a channel with tampered session keys; every
+/// other method delegates unchanged.
+pub struct FaultyNetwork<'a> {
+    inner: &'a dyn NetworkLayer,
+    faults: FaultInjection,
+}
+
+impl<'a> FaultyNetwork<'a> {
+    pub fn new(inner: &'a dyn NetworkLayer, faults: FaultInjection) -> Self {
+        Self { inner, faults }
+    }
+}
+
+impl<'a> NetworkLayer for FaultyNetwork<'a> {
+    fn establish_secure_channel(&self, peer: PeerId) -> Result<SecureChannel, NetworkError> {
+        if self.faults.drop_messages {
+            return Err(NetworkError::ConnectionFailed);
+        }
+        if let Some(delay) = self.faults.delay {
+            std::thread::sleep(delay);
+        }
+        let mut channel = self.inner.establish_secure_channel(peer)?;
+        self.faults.tamper_channel(&mut channel);
+        Ok(channel)
+    }
+
+    fn resolve_asset_address(&self, asset_id: AssetId) -> Result<NetworkAddress, NetworkError> {
+        self.inner.resolve_asset_address(asset_id)
+    }
+
+    fn handle_nat_traversal(&self, local_addr: SocketAddr) -> Result<NetworkAddress, NetworkError> {
+        self.inner.handle_nat_traversal(local_addr)
+    }
+
+    fn get_connectivity_status(&self) -> ConnectivityStatus {
+        self.inner.get_connectivity_status()
+    }
+
+    fn resolve_hypermesh_address(&self, name: &str) -> Result<NetworkAddress, NetworkError> {
+        self.inner.resolve_hypermesh_address(name)
+    }
+
+    fn get_transport_performance(&self) -> TransportPerformance {
+        self.inner.get_transport_performance()
+    }
+
+    fn validate_channel_security(&self, channel: &SecureChannel) -> Result<SecurityLevel, NetworkError> {
+        self.inner.validate_channel_security(channel)
+    }
+}
+
+/// Fault-injecting shim over an honest [`ConsensusLayer`]. Tampers the proof
+/// before `validate_four_proofs` and the state root before `cross_chain_sync`;
+/// every other method delegates unchanged.
+pub struct FaultyConsensus<'a> {
+    inner: &'a dyn ConsensusLayer,
+    faults: FaultInjection,
+}
+
+impl<'a> FaultyConsensus<'a> {
+    pub fn new(inner: &'a dyn ConsensusLayer, faults: FaultInjection) -> Self {
+        Self { inner, faults }
+    }
+}
+
+impl<'a> ConsensusLayer for FaultyConsensus<'a> {
+    fn validate_four_proofs(&self, mut proofs: FourProof) -> Result<ValidationResult, ConsensusError> {
+        self.faults.tamper_proof(&mut proofs);
+        self.inner.validate_four_proofs(proofs)
+    }
+
+    fn record_asset_state(&self, asset: AssetState, proofs: FourProof) -> Result<StateHash, ConsensusError> {
+        self.inner.record_asset_state(asset, proofs)
+    }
+
+    fn cross_chain_sync(&self, mut chain_state: ChainState) -> Result<SyncResult, ConsensusError> {
+        self.faults.tamper_chain_state(&mut chain_state);
+        self.inner.cross_chain_sync(chain_state)
+    }
+
+    fn execute_vm_with_assets(&self, vm_code: &[u8], asset_resources: Vec<AssetId>) -> Result<ExecutionResult, ConsensusError> {
+        self.inner.execute_vm_with_assets(vm_code, asset_resources)
+    }
+
+    fn resolve_asset_memory_address(&self, asset_id: AssetId) -> Result<[u8; 32], ConsensusError> {
+        self.inner.resolve_asset_memory_address(asset_id)
+    }
+
+    fn allocate_privacy_resources(&self, privacy_level: PrivacyLevel, resources: ComputationalResources) -> Result<AllocationResult, ConsensusError> {
+        self.inner.allocate_privacy_resources(privacy_level, resources)
+    }
+}
+
+/// Fault-injecting shim over an honest [`SecurityLayer`]. Corrupts ciphertext
+/// before `decrypt_transport`; every other method delegates unchanged.
+pub struct FaultySecurity<'a> {
+    inner: &'a dyn SecurityLayer,
+    faults: FaultInjection,
+}
+
+impl<'a> FaultySecurity<'a> {
+    pub fn new(inner: &'a dyn SecurityLayer, faults: FaultInjection) -> Self {
+        Self { inner, faults }
+    }
+}
+
+impl<'a> SecurityLayer for FaultySecurity<'a> {
+    fn encrypt_transport(&self, data: &[u8], channel: &SecureChannel) -> Result<Vec<u8>, SecurityError> {
+        self.inner.encrypt_transport(data, channel)
+    }
+
+    fn decrypt_transport(&self, encrypted_data: &[u8], channel: &SecureChannel) -> Result<Vec<u8>, SecurityError> {
+        if self.faults.corrupt_ciphertext {
+            let corrupted = self.faults.corrupt(encrypted_data);
+            return self.inner.decrypt_transport(&corrupted, channel);
+        }
+        self.inner.decrypt_transport(encrypted_data, channel)
+    }
+
+    fn validate_certificates(&self, cert_chain: &CertificateChain) -> Result<TrustLevel, SecurityError> {
+        self.inner.validate_certificates(cert_chain)
+    }
+
+    fn generate_asset_keys(&self, asset_id: AssetId) -> Result<AssetKeyPair, SecurityError> {
+        self.inner.generate_asset_keys(asset_id)
+    }
+
+    fn configure_asset_adapter_security(&self, asset_type: AssetType) -> Result<AssetAdapterSecurity, SecurityError> {
+        self.inner.configure_asset_adapter_security(asset_type)
+    }
+
+    fn validate_security_compliance(&self, component: &str) -> Result<SecurityValidationResult, SecurityError> {
+        self.inner.validate_security_compliance(component)
+    }
+
+    fn enforce_privacy_access_control(&self, asset_id: AssetId, privacy_level: PrivacyLevel) -> Result<bool, SecurityError> {
+        self.inner.enforce_privacy_access_control(asset_id, privacy_level)
+    }
+}
+
 /// Extension trait for encryption algorithms
-trait QuantumResistant {
+pub trait QuantumResistant {
     fn is_quantum_resistant(&self) -> bool;
 }
 
@@ -537,6 +1393,157 @@ impl QuantumResistant for EncryptionAlgorithm {
     }
 }
 
+/// A configurable compliance policy evaluated against an entire `FourProof`,
+/// its transport channel, and the adapter crypto config. It generalizes the
+/// one-off `is_quantum_resistant` check into a reusable engine: each enterprise
+/// entity (DMV/Bank/Insurance) can hold a differently-tuned policy object, and
+/// every integration test routes its pass/fail thresholds through one of these.
+#[derive(Debug)]
+pub struct SecurityPolicy {
+    /// Weakest channel security level the policy will accept.
+    pub min_channel_security: SecurityLevel,
+    /// Encryption algorithms the adapter is permitted to use.
+    pub allowed_algorithms: Vec<EncryptionAlgorithm>,
+    /// Whether `ownership_signature`/`temporal_signature` must be protected by a
+    /// post-quantum scheme (i.e. the adapter algorithm must be PQ).
+    pub require_pq_signatures: bool,
+    /// Minimum economic stake the proof must commit.
+    pub min_stake_amount: u64,
+    /// Minimum storage capacity the proof must commit.
+    pub min_capacity_commitment: u64,
+}
+
+impl Default for SecurityPolicy {
+    fn default() -> Self {
+        // The production default: quantum-resistant channel and adapter crypto,
+        // mirroring the original `is_quantum_resistant` gate, with the minimal
+        // economic commitments used by the built-in test vectors.
+        SecurityPolicy {
+            min_channel_security: SecurityLevel::QuantumResistant,
+            allowed_algorithms: vec![EncryptionAlgorithm::FALCON1024, EncryptionAlgorithm::Kyber1024],
+            require_pq_signatures: true,
+            min_stake_amount: 1000,
+            min_capacity_commitment: 1000,
+        }
+    }
+}
+
+impl SecurityPolicy {
+    /// Evaluate the full proof/channel/adapter triple, returning *every*
+    /// violation rather than stopping at the first so callers can report the
+    /// complete compliance gap.
+    pub fn evaluate(
+        &self,
+        proof: &FourProof,
+        channel: &SecureChannel,
+        adapter: &AssetAdapterSecurity,
+    ) -> PolicyReport {
+        let mut violations = Vec::new();
+
+        let channel_level = channel_security_level(channel);
+        if channel_security_rank(&channel_level) < channel_security_rank(&self.min_channel_security) {
+            violations.push(PolicyViolation::ChannelTooWeak {
+                required: format!("{:?}", self.min_channel_security),
+                actual: format!("{:?}", channel_level),
+            });
+        }
+
+        if !self
+            .allowed_algorithms
+            .iter()
+            .any(|a| encryption_algorithm_eq(a, &adapter.encryption_algorithm))
+        {
+            violations.push(PolicyViolation::DisallowedAlgorithm(format!(
+                "{:?}",
+                adapter.encryption_algorithm
+            )));
+        }
+
+        if self.require_pq_signatures && !adapter.encryption_algorithm.is_quantum_resistant() {
+            violations.push(PolicyViolation::NonQuantumSignature { field: "po_stake.ownership_signature" });
+            violations.push(PolicyViolation::NonQuantumSignature { field: "po_time.temporal_signature" });
+        }
+
+        if proof.po_stake.stake_amount < self.min_stake_amount {
+            violations.push(PolicyViolation::InsufficientStake {
+                required: self.min_stake_amount,
+                actual: proof.po_stake.stake_amount,
+            });
+        }
+
+        if proof.po_space.capacity_commitment < self.min_capacity_commitment {
+            violations.push(PolicyViolation::InsufficientCapacity {
+                required: self.min_capacity_commitment,
+                actual: proof.po_space.capacity_commitment,
+            });
+        }
+
+        PolicyReport { violations }
+    }
+}
+
+/// The outcome of a [`SecurityPolicy`] evaluation: the exhaustive set of
+/// violations found (empty when compliant).
+#[derive(Debug, Default)]
+pub struct PolicyReport {
+    pub violations: Vec<PolicyViolation>,
+}
+
+impl PolicyReport {
+    /// True when the proof/channel/adapter met every policy threshold.
+    pub fn is_compliant(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// A human-readable one-line summary of the violations, for test errors.
+    pub fn summary(&self) -> String {
+        self.violations
+            .iter()
+            .map(|v| format!("{:?}", v))
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+}
+
+/// A single way a proof/channel/adapter failed the policy.
+#[derive(Debug)]
+pub enum PolicyViolation {
+    ChannelTooWeak { required: String, actual: String },
+    DisallowedAlgorithm(String),
+    NonQuantumSignature { field: &'static str },
+    InsufficientStake { required: u64, actual: u64 },
+    InsufficientCapacity { required: u64, actual: u64 },
+}
+
+/// Ordinal escalation rank for a channel security level, so policies can
+/// express a *minimum* acceptable level.
+fn channel_security_rank(level: &SecurityLevel) -> u8 {
+    match level {
+        SecurityLevel::Insecure => 0,
+        SecurityLevel::Basic => 1,
+        SecurityLevel::QuantumResistant => 2,
+        SecurityLevel::FullValidation => 3,
+    }
+}
+
+/// Classify a channel's security level from its session keys. Zeroed keys (as
+/// produced by a tampered handshake) downgrade it to `Insecure`; otherwise the
+/// honest transport layer negotiates quantum-resistant keys.
+fn channel_security_level(channel: &SecureChannel) -> SecurityLevel {
+    let zeroed = channel.encryption_keys.send_key == [0u8; 32]
+        && channel.encryption_keys.receive_key == [0u8; 32];
+    if zeroed {
+        SecurityLevel::Insecure
+    } else {
+        SecurityLevel::QuantumResistant
+    }
+}
+
+/// Compare two `EncryptionAlgorithm`s by discriminant (the enum is not `Eq`).
+fn encryption_algorithm_eq(a: &EncryptionAlgorithm, b: &EncryptionAlgorithm) -> bool {
+    std::mem::discriminant(a) == std::mem::discriminant(b)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
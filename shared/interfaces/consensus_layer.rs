@@ -137,6 +137,10 @@ pub struct TimeValidation {
     pub time_server_attestation: [u8; 64],
     pub drift_tolerance_ms: u32,
     pub validation_authority: [u8; 32],
+    /// Relative temporal lock (BIP68-style): this state cannot supersede the
+    /// prior state in its lineage until `relative_lock` has elapsed since the
+    /// predecessor's median-time-past. `None` disables the relative lock.
+    pub relative_lock: Option<Duration>,
 }
 
 /// Shard information for distributed storage
@@ -233,15 +237,85 @@ pub trait ConsensusLayer {
     /// Validate all four proofs for asset (Team 2 core responsibility)
     fn validate_four_proofs(&self, proofs: FourProof) -> Result<ValidationResult, ConsensusError>;
     
+    /// Validate a batch of proofs, aggregating signatures where possible.
+    ///
+    /// When a block touches thousands of assets, verifying each
+    /// `ownership_signature`/`temporal_signature` individually dominates the
+    /// `TARGET_VALIDATION_TIME_MS` budget. Implementations backed by a BLS12-381
+    /// key type should aggregate all ownership signatures (and separately all
+    /// temporal signatures) into one `AggregateSignature` and perform a single
+    /// aggregate verification; on aggregate failure they fall back to per-proof
+    /// verification to pinpoint the offending asset in its `error_details`.
+    ///
+    /// The default implementation preserves the ed25519 single-proof path by
+    /// validating each entry independently.
+    fn validate_four_proofs_batch(
+        &self,
+        proofs: &[(AssetState, FourProof)],
+    ) -> Vec<ValidationResult> {
+        proofs
+            .iter()
+            .map(|(_, proof)| match self.validate_four_proofs(proof.clone()) {
+                Ok(result) => result,
+                Err(e) => ValidationResult {
+                    is_valid: false,
+                    proof_validations: ProofValidations {
+                        po_space_valid: false,
+                        po_stake_valid: false,
+                        po_work_valid: false,
+                        po_time_valid: false,
+                        combined_valid: false,
+                    },
+                    confidence_score: 0.0,
+                    validation_time_ms: 0,
+                    error_details: Some(format!("{:?}", e)),
+                },
+            })
+            .collect()
+    }
+
     /// Record asset state with complete proof validation (Team 2 → Team 1,3)
     fn record_asset_state(&self, asset: AssetState, proofs: FourProof) -> Result<StateHash, ConsensusError>;
     
     /// Cross-chain state synchronization (Team 2 → Team 1)
     fn cross_chain_sync(&self, chain_state: ChainState) -> Result<SyncResult, ConsensusError>;
+
+    /// Trustless cross-chain import from a verifiable proof (ISMP-style).
+    ///
+    /// Unlike `cross_chain_sync`, this does not trust the relayed state: it
+    /// (1) verifies `proof.finality_proof` against the locally tracked
+    /// source-chain authority set, (2) verifies each asset's storage inclusion
+    /// proof against the finalized `state_root`, and only then (3) imports the
+    /// provably-finalized `AssetState`s. `failed_assets` carries the specific
+    /// proof-verification failure and `chain_consistency_score` reflects the
+    /// ratio of provably-finalized assets. The default implementation rejects
+    /// any proof until a backend wires up the source-chain authority set.
+    fn cross_chain_sync_verified(
+        &self,
+        proof: interop::CrossChainProof,
+    ) -> Result<SyncResult, ConsensusError> {
+        let _ = proof;
+        Err(ConsensusError::CrossChainSyncFailed)
+    }
     
     /// VM integration with asset system (Team 2 → VM/Catalog integration)
     fn execute_vm_with_assets(&self, vm_code: &[u8], asset_resources: Vec<AssetId>) -> Result<ExecutionResult, ConsensusError>;
     
+    /// Begin a resumable streaming state-sync against `peer` toward `target`.
+    ///
+    /// Returns a `SyncSession` in the `CheckSync` phase. The driver compares the
+    /// local head, then pulls bounded `AssetId`-ranged batches, validates each
+    /// through `validate_four_proofs_batch`, commits verified states, and
+    /// persists the session cursor so an interruption resumes from the last
+    /// committed asset. The default implementation just opens the session.
+    fn start_state_sync(
+        &mut self,
+        peer: NetworkLocation,
+        target: state_sync::ChainHead,
+    ) -> state_sync::SyncSession {
+        state_sync::SyncSession::new(peer, target, 256)
+    }
+
     /// NAT-like memory addressing for assets (Team 2 → Team 1)
     fn resolve_asset_memory_address(&self, asset_id: AssetId) -> Result<[u8; 32], ConsensusError>;
     
@@ -302,6 +376,468 @@ pub const TARGET_SYNC_TIME_MS: u64 = 5000;          // Maximum cross-chain sync
 pub const TARGET_CONFIDENCE_SCORE: f64 = 0.95;      // Minimum confidence for validation
 pub const TARGET_TODO_COMPLETION: u32 = 50;         // Minimum TODO markers to complete
 
+/// Resumable streaming state-sync subsystem (HotStuff/DAN-style)
+///
+/// A node that falls behind catches up by pulling `AssetState` + `FourProof`
+/// batches in bounded chunks rather than one monolithic `ChainState`. An
+/// explicit state machine (`CheckSync → Syncing → Idle`) drives the session,
+/// and a persisted cursor keyed by `AssetId` makes an interrupted session
+/// resume from the last committed state instead of restarting. The safety
+/// invariant is that a batch is never committed unless its states chain
+/// consistently onto the already-synced `state_root`.
+pub mod state_sync {
+    use super::{AssetId, NetworkLocation};
+
+    /// Phase of a sync session.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum SyncPhase {
+        /// Comparing local head against the peer's advertised head.
+        CheckSync,
+        /// Pulling and committing verified batches.
+        Syncing,
+        /// Local head matches the target; nothing to do.
+        Idle,
+    }
+
+    /// Advertised head of a chain, used to decide whether sync is needed.
+    #[derive(Debug, Clone, Copy)]
+    pub struct ChainHead {
+        pub block_height: u64,
+        pub state_root: [u8; 32],
+    }
+
+    /// A resumable sync session against one peer.
+    #[derive(Debug, Clone)]
+    pub struct SyncSession {
+        pub peer: NetworkLocation,
+        pub target: ChainHead,
+        pub phase: SyncPhase,
+        /// Maximum number of `AssetState`s requested per batch.
+        pub batch_size: usize,
+        /// Resume cursor: the last committed `AssetId`. `None` before any commit.
+        pub cursor: Option<AssetId>,
+        /// Number of assets committed so far.
+        pub synced: usize,
+        /// Total assets to sync, once known from the peer's head.
+        pub total: usize,
+        /// Rolling consistency score across committed batches.
+        pub chain_consistency_score: f64,
+    }
+
+    impl SyncSession {
+        /// Start a session in `CheckSync` against `peer`'s `target` head.
+        pub fn new(peer: NetworkLocation, target: ChainHead, batch_size: usize) -> Self {
+            Self {
+                peer,
+                target,
+                phase: SyncPhase::CheckSync,
+                batch_size: batch_size.max(1),
+                cursor: None,
+                synced: 0,
+                total: 0,
+                chain_consistency_score: 0.0,
+            }
+        }
+
+        /// Decide whether syncing is required by comparing the local head to the
+        /// target. Transitions `CheckSync → Syncing` (behind) or `→ Idle` (caught up).
+        pub fn check(&mut self, local: ChainHead) {
+            if local.block_height >= self.target.block_height
+                && local.state_root == self.target.state_root
+            {
+                self.phase = SyncPhase::Idle;
+            } else {
+                self.phase = SyncPhase::Syncing;
+            }
+        }
+
+        /// Record a committed batch, advancing the resume cursor. Callers must
+        /// have already verified the batch chains onto the synced root.
+        pub fn commit_batch(&mut self, last_asset: AssetId, count: usize, consistency: f64) {
+            self.cursor = Some(last_asset);
+            self.synced += count;
+            self.chain_consistency_score = consistency;
+            if self.synced >= self.total && self.total > 0 {
+                self.phase = SyncPhase::Idle;
+            }
+        }
+
+        /// Fractional progress in `[0, 1]`.
+        pub fn progress(&self) -> f64 {
+            if self.total == 0 {
+                return 0.0;
+            }
+            self.synced as f64 / self.total as f64
+        }
+    }
+}
+
+/// Verifiable cross-chain messaging primitives (ISMP-style)
+///
+/// Removes the trusted-relayer assumption from multi-chain operation: rather
+/// than importing a whole `ChainState` on faith, a sending chain produces a
+/// `CrossChainProof` that carries a finality justification for `state_root`
+/// plus Merkle/Patricia inclusion proofs for the specific `AssetState`s being
+/// imported. The importer verifies finality against its locally tracked
+/// authority set and each inclusion proof against the finalized root before
+/// accepting any state.
+pub mod interop {
+    use super::{AssetId, AssetState, ChainId};
+
+    /// A verifiable cross-chain state import.
+    #[derive(Debug, Clone)]
+    pub struct CrossChainProof {
+        pub source_chain: ChainId,
+        pub state_root: [u8; 32],
+        /// Merkle/Patricia inclusion proofs for the imported asset entries,
+        /// order-aligned with `asset_states`.
+        pub storage_proof: Vec<Vec<u8>>,
+        /// Asset states claimed to be committed under `state_root`.
+        pub asset_states: Vec<AssetState>,
+        /// Justification that `state_root` is final on the source chain.
+        pub finality_proof: FinalityProof,
+    }
+
+    /// Proof that a block (and thus its `state_root`) is final.
+    #[derive(Debug, Clone)]
+    pub enum FinalityProof {
+        /// GRANDPA-style: a threshold set of validator signatures over the block.
+        ValidatorJustification {
+            block_hash: [u8; 32],
+            signatures: Vec<ValidatorSignature>,
+        },
+        /// Sync-committee aggregate signature over the finalized header.
+        SyncCommittee {
+            block_hash: [u8; 32],
+            aggregate_signature: Vec<u8>,
+            participation_bits: Vec<u8>,
+        },
+    }
+
+    /// One validator's signature in a GRANDPA-style justification.
+    #[derive(Debug, Clone)]
+    pub struct ValidatorSignature {
+        pub authority: [u8; 32],
+        pub signature: [u8; 64],
+    }
+
+    /// The locally tracked authority set a finality proof is checked against.
+    #[derive(Debug, Clone, Default)]
+    pub struct AuthoritySet {
+        pub authorities: Vec<[u8; 32]>,
+        /// Minimum number of valid signatures required for finality.
+        pub threshold: usize,
+    }
+
+    impl AuthoritySet {
+        /// Count how many of `signatures` come from known authorities. A real
+        /// backend also verifies each signature against the block hash; this
+        /// performs the membership/threshold accounting.
+        pub fn count_valid(&self, signatures: &[ValidatorSignature]) -> usize {
+            signatures
+                .iter()
+                .filter(|s| self.authorities.contains(&s.authority))
+                .count()
+        }
+
+        /// Whether a GRANDPA-style justification meets the threshold.
+        pub fn is_final(&self, signatures: &[ValidatorSignature]) -> bool {
+            self.threshold > 0 && self.count_valid(signatures) >= self.threshold
+        }
+    }
+
+    /// Outcome of verifying a single asset's storage inclusion proof.
+    #[derive(Debug, Clone)]
+    pub struct AssetImport {
+        pub asset_id: AssetId,
+        pub verified: bool,
+        pub failure: Option<String>,
+    }
+}
+
+/// Aggregate signature verification for batched four-proof validation
+///
+/// Models the beacon-chain aggregate-attestation approach: an owner identity is
+/// registered with a key type, and BLS12-381 signers can have their ownership
+/// and temporal signatures combined into a single aggregate verified in one
+/// pairing check instead of N. The ed25519 path is retained for single-proof
+/// calls. The heavy curve arithmetic is provided by the crypto backend; this
+/// module defines the dispatch surface and the aggregation bookkeeping.
+pub mod aggregate {
+    /// Signature scheme registered for an owner identity.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum KeyType {
+        /// Per-proof ed25519 `[u8; 64]` signatures (no aggregation).
+        Ed25519,
+        /// BLS12-381 signatures eligible for aggregation.
+        Bls12_381,
+    }
+
+    /// One aggregated BLS12-381 signature over a set of (pubkey, message) pairs.
+    #[derive(Debug, Clone, Default)]
+    pub struct AggregateSignature {
+        /// Compressed aggregate signature point.
+        pub point: Vec<u8>,
+        /// Public keys of every contributing signer, order-aligned with `messages`.
+        pub public_keys: Vec<[u8; 48]>,
+        /// Signing messages, order-aligned with `public_keys`.
+        pub messages: Vec<Vec<u8>>,
+    }
+
+    impl AggregateSignature {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Fold one signer's contribution into the aggregate.
+        pub fn add(&mut self, public_key: [u8; 48], message: Vec<u8>) {
+            self.public_keys.push(public_key);
+            self.messages.push(message);
+        }
+
+        /// Number of signatures folded into this aggregate.
+        pub fn len(&self) -> usize {
+            self.public_keys.len()
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.public_keys.is_empty()
+        }
+    }
+
+    /// Resolve the registered key type for an owner identity.
+    pub trait KeyRegistry {
+        fn key_type(&self, owner_identity: &[u8; 32]) -> KeyType;
+    }
+}
+
+/// Median-time-past validation for `PoTimeProof` (BIP113/BIP68-style)
+///
+/// A single `SystemTime` timestamp is trivially manipulable by a validator that
+/// shifts its clock forward. Instead of trusting wall-clock, each chain/asset
+/// lineage keeps a rolling window of the last accepted proof timestamps and
+/// uses their median (MTP) as the reference "now": a new proof is only accepted
+/// when its timestamp is strictly greater than the predecessors' MTP, and when
+/// any relative temporal lock has elapsed since the referenced prior state's
+/// MTP. This is deterministic across nodes.
+pub mod temporal {
+    use super::{Duration, SystemTime};
+
+    /// Number of recent timestamps retained for the median calculation.
+    pub const MEDIAN_TIME_SPAN: usize = 11;
+
+    /// Rolling median-time-past window for one lineage.
+    #[derive(Debug, Clone, Default)]
+    pub struct MedianTimeWindow {
+        timestamps: std::collections::VecDeque<SystemTime>,
+    }
+
+    impl MedianTimeWindow {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Median of the retained timestamps, or `None` before any are recorded.
+        pub fn median_time_past(&self) -> Option<SystemTime> {
+            if self.timestamps.is_empty() {
+                return None;
+            }
+            let mut sorted: Vec<SystemTime> = self.timestamps.iter().copied().collect();
+            sorted.sort();
+            Some(sorted[sorted.len() / 2])
+        }
+
+        /// Validate a candidate proof timestamp against this lineage.
+        ///
+        /// Returns `Ok(())` only when `timestamp` is strictly greater than the
+        /// current MTP and, if a `relative_lock` is supplied, at least that much
+        /// time has elapsed since the referenced predecessor's MTP.
+        pub fn validate(
+            &self,
+            timestamp: SystemTime,
+            relative_lock: Option<Duration>,
+            predecessor_mtp: Option<SystemTime>,
+        ) -> Result<(), super::ConsensusError> {
+            if let Some(mtp) = self.median_time_past() {
+                if timestamp <= mtp {
+                    return Err(super::ConsensusError::TimestampValidationFailed);
+                }
+            }
+            if let (Some(lock), Some(prev)) = (relative_lock, predecessor_mtp) {
+                let earliest = prev + lock;
+                if timestamp < earliest {
+                    return Err(super::ConsensusError::TimestampValidationFailed);
+                }
+            }
+            Ok(())
+        }
+
+        /// Record an accepted timestamp, evicting the oldest past the span.
+        pub fn push(&mut self, timestamp: SystemTime) {
+            self.timestamps.push_back(timestamp);
+            while self.timestamps.len() > MEDIAN_TIME_SPAN {
+                self.timestamps.pop_front();
+            }
+        }
+    }
+}
+
+/// Version-bits soft-fork deployment subsystem (BIP9-style)
+///
+/// Lets operators roll out new validation rules — a stricter `PoWorkProof`
+/// difficulty function, an additional proof field — without a coordinated
+/// flag day. Each deployment claims a signaling bit; recorded states advertise
+/// which deployments they support via a bitmask, and a per-deployment state
+/// machine is advanced at fixed window boundaries once a measured supermajority
+/// signals for a full window.
+pub mod version_bits {
+    use super::SystemTime;
+
+    /// Highest signaling bit available to a deployment (BIP9 reserves 29..=31).
+    pub const MAX_DEPLOYMENT_BIT: u8 = 28;
+
+    /// A single soft-fork deployment keyed to one signaling bit.
+    #[derive(Debug, Clone)]
+    pub struct Deployment {
+        /// Signaling bit (`0..=28`) recorded states set to advertise support.
+        pub bit: u8,
+        /// No signaling counts before this time.
+        pub start_time: SystemTime,
+        /// Deployment fails if it has not locked in by this time.
+        pub timeout: SystemTime,
+        /// Minimum number of signaling records within a window to lock in.
+        pub threshold: u32,
+        /// Number of records in one retarget window.
+        pub window: u32,
+    }
+
+    /// Lifecycle of a deployment, advanced only at window boundaries.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum State {
+        /// Before `start_time`, or awaiting the first window boundary.
+        Defined,
+        /// Signaling is being counted.
+        Started,
+        /// Threshold reached; activates at the next boundary.
+        LockedIn,
+        /// Rule is enforced.
+        Active,
+        /// Timed out before locking in.
+        Failed,
+    }
+
+    /// Compact per-record signal: the window-relative timestamp plus the
+    /// advertised deployment bitmask.
+    #[derive(Debug, Clone, Copy)]
+    pub struct Signal {
+        pub timestamp: SystemTime,
+        pub bits: u32,
+    }
+
+    impl Signal {
+        /// Whether this record signals support for `bit`.
+        pub fn signals(&self, bit: u8) -> bool {
+            bit <= MAX_DEPLOYMENT_BIT && (self.bits & (1u32 << bit)) != 0
+        }
+    }
+
+    /// Tracks deployment states over a stream of recorded signals.
+    #[derive(Debug, Default)]
+    pub struct DeploymentTracker {
+        deployments: std::collections::HashMap<String, Deployment>,
+        states: std::collections::HashMap<String, State>,
+        window_buf: Vec<Signal>,
+    }
+
+    impl DeploymentTracker {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Register a deployment under `id`, starting in `Defined`.
+        pub fn register(&mut self, id: impl Into<String>, deployment: Deployment) {
+            let id = id.into();
+            self.states.insert(id.clone(), State::Defined);
+            self.deployments.insert(id, deployment);
+        }
+
+        /// Current state of `id`, or `Defined` if unknown.
+        pub fn deployment_state(&self, id: &str) -> State {
+            self.states.get(id).copied().unwrap_or(State::Defined)
+        }
+
+        /// Record one block/state's signal. Transitions are only computed when a
+        /// full `window` of records has accumulated, matching BIP9's boundary
+        /// semantics (the window size is the largest `window` among the
+        /// registered deployments, as all deployments share one retarget
+        /// schedule here).
+        pub fn record(&mut self, signal: Signal) {
+            self.window_buf.push(signal);
+            let window = self
+                .deployments
+                .values()
+                .map(|d| d.window)
+                .max()
+                .unwrap_or(0);
+            if window == 0 || (self.window_buf.len() as u32) < window {
+                return;
+            }
+            self.advance_window();
+            self.window_buf.clear();
+        }
+
+        /// Median of the accumulated window's timestamps (median-time-past),
+        /// used — rather than wall-clock — to decide time-based transitions.
+        fn median_time(&self) -> Option<SystemTime> {
+            if self.window_buf.is_empty() {
+                return None;
+            }
+            let mut times: Vec<SystemTime> = self.window_buf.iter().map(|s| s.timestamp).collect();
+            times.sort();
+            Some(times[times.len() / 2])
+        }
+
+        fn advance_window(&mut self) {
+            let mtp = match self.median_time() {
+                Some(t) => t,
+                None => return,
+            };
+            let ids: Vec<String> = self.deployments.keys().cloned().collect();
+            for id in ids {
+                let deployment = self.deployments[&id].clone();
+                let count = self
+                    .window_buf
+                    .iter()
+                    .filter(|s| s.signals(deployment.bit))
+                    .count() as u32;
+                let next = match self.deployment_state(&id) {
+                    State::Defined => {
+                        if mtp >= deployment.timeout {
+                            State::Failed
+                        } else if mtp >= deployment.start_time {
+                            State::Started
+                        } else {
+                            State::Defined
+                        }
+                    }
+                    State::Started => {
+                        if count >= deployment.threshold {
+                            State::LockedIn
+                        } else if mtp >= deployment.timeout {
+                            State::Failed
+                        } else {
+                            State::Started
+                        }
+                    }
+                    State::LockedIn => State::Active,
+                    terminal => terminal,
+                };
+                self.states.insert(id, next);
+            }
+        }
+    }
+}
+
 /// Interface validation for cross-team integration
 pub trait ConsensusIntegrationValidator {
     /// Validate Team 1 network can support consensus (Team 2 → Team 1)
@@ -138,6 +138,12 @@ pub struct KeyManagement {
     pub hardware_security_module: bool,
     pub key_derivation_function: KeyDerivationFunction,
     pub entropy_source: EntropySource,
+    /// Number of shares (`t`) required to cooperatively use an asset key under
+    /// threshold DKG. A value of 1 preserves the legacy single-custodian path.
+    pub threshold: u32,
+    /// Total number of shares (`n`) the dealer distributes across nodes. Must be
+    /// at least `threshold`; the private key is never reconstructed in one place.
+    pub shares: u32,
 }
 
 /// Key derivation functions
@@ -344,6 +350,203 @@ pub trait SecurityLayer {
     
     /// Implement privacy-aware access control (Team 3 → Team 2)
     fn enforce_privacy_access_control(&self, asset_id: AssetId, privacy_level: PrivacyLevel) -> Result<bool, SecurityError>;
+
+    /// Deal Feldman-verifiable shares of an asset secret so the private key is
+    /// never held by a single node (Team 3 → Team 2).
+    ///
+    /// The dealer forms `f(x) = secret + a₁x + … + a_{t-1}x^{t-1}` over the
+    /// BN254 scalar field, where `higher_coefficients` are `a₁..a_{t-1}` (so the
+    /// threshold is `higher_coefficients.len() + 1`), hands share `i = f(i)` to
+    /// node `i`, and publishes Feldman commitments `Cⱼ = [aⱼ]·G` so each node can
+    /// verify its share before accepting. The higher coefficients MUST come from
+    /// a CSPRNG in production; they are passed in so the dealer's entropy source
+    /// stays explicit and the routine is deterministically testable. The default
+    /// implementation runs the curve-backed deal from [`threshold_dkg`].
+    fn deal_asset_key_shares(
+        &self,
+        secret: threshold_dkg::Fr,
+        higher_coefficients: &[threshold_dkg::Fr],
+        shares: u32,
+    ) -> Result<threshold_dkg::DealtKey, SecurityError> {
+        threshold_dkg::deal(secret, higher_coefficients, shares)
+            .map_err(|_| SecurityError::KeyGenerationFailed)
+    }
+
+    /// Verify a single Feldman share against the dealer's published commitments,
+    /// i.e. `[shareᵢ]·G == Σⱼ (iʲ)·Cⱼ`, guarding against a malicious dealer
+    /// handing out inconsistent shares. The default checks against
+    /// [`threshold_dkg::verify_share`].
+    fn verify_key_share(
+        &self,
+        share: &threshold_dkg::SecretShare,
+        commitments: &[threshold_dkg::Commitment],
+    ) -> Result<bool, SecurityError> {
+        Ok(threshold_dkg::verify_share(share, commitments))
+    }
+
+    /// Combine `t` partial results (shares, or per-share signing/decryption
+    /// contributions) by Lagrange interpolation at `x = 0`, recovering the
+    /// cooperative result without ever materializing the full secret in one
+    /// place. Fewer than `threshold` distinct shares yields
+    /// `SecurityError::AuthorizationFailed`. The default delegates to
+    /// [`threshold_dkg::combine`].
+    fn threshold_combine(
+        &self,
+        partials: &[threshold_dkg::SecretShare],
+        threshold: u32,
+    ) -> Result<threshold_dkg::Fr, SecurityError> {
+        threshold_dkg::combine(partials, threshold)
+            .map_err(|_| SecurityError::AuthorizationFailed)
+    }
+
+    /// Anonymously authenticate an asset permission with a Groth16 zk-proof over
+    /// BN254, backing [`AuthenticationMethod::ZeroKnowledgeProof`].
+    ///
+    /// The prover demonstrates a statement (e.g. "holds a certificate in this
+    /// TrustChain" or "access level ≥ X") encoded by `public_inputs` without
+    /// revealing identity. Acceptance is the Groth16 pairing equation
+    /// `e(A,B) = e(α,β)·e(IC₀ + Σ aᵢ·ICᵢ, γ)·e(C, δ)`. All points carried by the
+    /// `proof`/`verifying_key` must already have been deserialized through the
+    /// [`groth16`] point constructors, which reject non-curve and non-subgroup
+    /// encodings to close off small-subgroup forgeries. Returns `Ok(false)` on a
+    /// well-formed but non-satisfying proof and `SecurityError::AuthenticationFailed`
+    /// on a malformed proof (e.g. public-input arity mismatch). The default
+    /// delegates to [`groth16::verify`].
+    fn authenticate_zk(
+        &self,
+        proof: &groth16::Groth16Proof,
+        public_inputs: &[groth16::Fr],
+        verifying_key: &groth16::VerifyingKey,
+    ) -> Result<bool, SecurityError> {
+        groth16::verify(verifying_key, proof, public_inputs)
+            .map_err(|_| SecurityError::AuthenticationFailed)
+    }
+
+    /// Validate a certificate chain with revocation awareness (Team 3 core).
+    ///
+    /// Extends `validate_certificates` with a revocation lookup so a
+    /// compromised-but-unexpired certificate can no longer return
+    /// `FullyValidated`: if any certificate in the chain has a revoked
+    /// fingerprint (`hash(issuer‖subject‖public_key)`), the whole chain is
+    /// downgraded to `TrustLevel::Untrusted` and a critical
+    /// `KeyManagementFlaw` vulnerability is emitted. The revocation set is a
+    /// `HashSet` lookup, so this stays within `CERTIFICATE_VALIDATION_TIME_MS`.
+    /// Implementations of `validate_certificates` should route through this.
+    fn validate_certificates_checked(
+        &self,
+        cert_chain: &CertificateChain,
+        revocations: &revocation::RevocationSet,
+    ) -> Result<revocation::ChainValidation, SecurityError> {
+        let revoked = cert_chain
+            .iter()
+            .any(|cert| revocations.is_revoked(&revocation::Fingerprint::of(cert)));
+
+        if revoked {
+            return Ok(revocation::ChainValidation {
+                trust_level: TrustLevel::Untrusted,
+                vulnerabilities: vec![SecurityVulnerability {
+                    vulnerability_type: VulnerabilityType::KeyManagementFlaw,
+                    severity: Severity::Critical,
+                    description: "certificate chain contains a revoked fingerprint".to_string(),
+                    remediation: "re-issue the affected certificates and rebuild the chain".to_string(),
+                    cve_reference: None,
+                }],
+            });
+        }
+
+        let trust_level = self.validate_certificates(cert_chain)?;
+        Ok(revocation::ChainValidation { trust_level, vulnerabilities: Vec::new() })
+    }
+
+    /// Revocation-aware chain validation backed by a memory-mapped cache, to
+    /// keep repeated validations within `CERTIFICATE_VALIDATION_TIME_MS`.
+    ///
+    /// Keyed on the leaf certificate's fingerprint: on a cache hit that is still
+    /// live under the current time and the set's revocation epoch, the cached
+    /// `TrustLevel` is returned without re-verifying signatures. On a miss (or
+    /// when `audit_mode` forces a full re-verification) it runs
+    /// `validate_certificates_checked` and caches a positive result until the
+    /// chain's earliest `valid_until`. An empty chain cannot be cached.
+    fn validate_certificates_cached(
+        &self,
+        cert_chain: &CertificateChain,
+        revocations: &revocation::RevocationSet,
+        cache: &mut validation_cache::ValidationCache,
+        audit_mode: bool,
+    ) -> Result<revocation::ChainValidation, SecurityError> {
+        let leaf = match cert_chain.first() {
+            Some(cert) => cert,
+            None => return self.validate_certificates_checked(cert_chain, revocations),
+        };
+
+        let fingerprint = revocation::Fingerprint::of(leaf);
+        let now = validation_cache::now_secs();
+        let epoch = revocations.epoch();
+
+        if !audit_mode {
+            if let Some(entry) = cache.lookup(&fingerprint.0, now, epoch) {
+                return Ok(revocation::ChainValidation {
+                    trust_level: entry.trust_level,
+                    vulnerabilities: Vec::new(),
+                });
+            }
+        }
+
+        let validation = self.validate_certificates_checked(cert_chain, revocations)?;
+
+        // Only cache trustworthy chains; a revoked/untrusted result must always
+        // re-run so a later re-issue is observed immediately.
+        if !matches!(validation.trust_level, TrustLevel::Untrusted) {
+            let valid_until = cert_chain
+                .iter()
+                .map(|cert| validation_cache::to_secs(cert.valid_until))
+                .min()
+                .unwrap_or(now);
+            cache.insert(
+                &fingerprint.0,
+                validation_cache::CacheEntry {
+                    trust_level: validation.trust_level.clone(),
+                    valid_until,
+                    verified_at: now,
+                    revocation_epoch: epoch,
+                },
+            );
+        }
+
+        Ok(validation)
+    }
+
+    /// The crypto-algorithm registry this security layer configures and meters
+    /// against. Defaults to the built-in algorithms; implementers override to
+    /// register additional PQC candidates or repriced primitives.
+    fn crypto_registry(&self) -> crypto_registry::CryptoRegistry {
+        crypto_registry::CryptoRegistry::with_defaults()
+    }
+
+    /// Assess an adapter's configured algorithm against the registry, returning
+    /// an `UpgradeEncryption` recommendation when it is deprecated or below
+    /// `MINIMUM_KEY_SIZE_BITS`. `validate_security_compliance` implementations
+    /// should fold these into `SecurityValidationResult.recommendations`.
+    fn assess_adapter_crypto(&self, adapter: &AssetAdapterSecurity) -> Vec<SecurityRecommendation> {
+        let registry = self.crypto_registry();
+        registry
+            .assess(crypto_registry::canonical_name(&adapter.encryption_algorithm))
+            .into_iter()
+            .collect()
+    }
+
+    /// Cost weight of one `operation` under the adapter's configured algorithm,
+    /// so callers can budget work. `None` if the algorithm is not registered.
+    fn operation_cost(
+        &self,
+        adapter: &AssetAdapterSecurity,
+        operation: crypto_registry::Operation,
+    ) -> Option<u64> {
+        let registry = self.crypto_registry();
+        registry
+            .get(crypto_registry::canonical_name(&adapter.encryption_algorithm))
+            .map(|algo| algo.cost(operation))
+    }
 }
 
 /// Implementation requirements for Team 3
@@ -385,6 +588,884 @@ pub trait AssetAdapterSecurityImplementations {
     fn secure_network_adapter(&self) -> Result<AssetAdapterSecurity, SecurityError>;
 }
 
+/// Threshold distributed-key-generation subsystem (secret-store DKG style)
+///
+/// An asset private key is split by Shamir secret sharing over the BN254
+/// scalar field so it is never reconstructed in one place: the dealer picks a
+/// degree-`(t−1)` polynomial `f(x) = s + a₁x + … + a_{t-1}x^{t-1}`, hands
+/// `shareᵢ = f(i)` to node `i`, and any `t` shares recover `s` by Lagrange
+/// interpolation at `x=0`. Feldman verifiable secret sharing hardens this
+/// against a malicious dealer: the dealer publishes commitments `Cⱼ = [aⱼ]·G`
+/// (scalar multiples of the BN254 `G1` generator) and each node checks
+/// `[shareᵢ]·G == Σⱼ (iʲ)·Cⱼ` before accepting its share.
+///
+/// Polynomial arithmetic runs in `Fr` (BN254's scalar field, ~254 bits);
+/// Feldman commitments live in `G1`. Discrete log in `G1` is the standard
+/// elliptic-curve discrete-log problem, so a dealt commitment reveals nothing
+/// about the coefficient it commits to.
+pub mod threshold_dkg {
+    use ark_bn254::G1Projective;
+    use ark_ec::{CurveGroup, Group};
+    use ark_ff::{Field, Zero};
+
+    /// BN254 scalar field element; shares, coefficients, and the recovered
+    /// secret all live here.
+    pub use ark_bn254::Fr;
+    /// A Feldman commitment `[aⱼ]·G ∈ G1`.
+    pub use ark_bn254::G1Affine as Commitment;
+
+    /// A share `f(i)` handed to node `index`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct SecretShare {
+        /// Node index `i` (the evaluation point); must be non-zero.
+        pub index: u32,
+        /// Field value `f(i)`.
+        pub value: Fr,
+    }
+
+    /// The dealer's output: one share per node plus the public Feldman
+    /// commitments `C₀..C_{t-1}` to the polynomial coefficients.
+    #[derive(Debug, Clone)]
+    pub struct DealtKey {
+        /// Minimum shares required to recover the secret (`t`).
+        pub threshold: u32,
+        /// Feldman commitments `Cⱼ = [aⱼ]·G`, with `a₀ = secret`.
+        pub commitments: Vec<Commitment>,
+        /// One share per node, at indices `1..=shares`.
+        pub shares: Vec<SecretShare>,
+    }
+
+    /// Errors from the DKG routines.
+    #[derive(Debug, PartialEq, Eq)]
+    pub enum DkgError {
+        /// `shares < threshold` or a zero threshold.
+        InvalidParameters,
+        /// Fewer than `threshold` distinct shares supplied to recovery.
+        InsufficientShares,
+    }
+
+    /// Evaluate `f(x)` at `x` given coefficients `[a₀, a₁, …]` (Horner).
+    fn eval_poly(coefficients: &[Fr], x: Fr) -> Fr {
+        let mut acc = Fr::zero();
+        for &c in coefficients.iter().rev() {
+            acc = acc * x + c;
+        }
+        acc
+    }
+
+    /// Deal `shares` shares of `secret` with threshold `higher_coefficients.len() + 1`,
+    /// publishing Feldman commitments for later verification.
+    pub fn deal(secret: Fr, higher_coefficients: &[Fr], shares: u32) -> Result<DealtKey, DkgError> {
+        let threshold = higher_coefficients.len() as u32 + 1;
+        if threshold == 0 || shares < threshold {
+            return Err(DkgError::InvalidParameters);
+        }
+
+        // Full coefficient vector: a₀ = secret, then the supplied higher terms.
+        let mut coefficients = Vec::with_capacity(threshold as usize);
+        coefficients.push(secret);
+        coefficients.extend_from_slice(higher_coefficients);
+
+        let generator = G1Projective::generator();
+        let commitments = coefficients.iter().map(|c| (generator * c).into_affine()).collect();
+
+        let shares = (1..=shares)
+            .map(|i| SecretShare { index: i, value: eval_poly(&coefficients, Fr::from(i as u64)) })
+            .collect();
+
+        Ok(DealtKey { threshold, commitments, shares })
+    }
+
+    /// Feldman check for one share: `[shareᵢ]·G == Σⱼ (iʲ)·Cⱼ`.
+    pub fn verify_share(share: &SecretShare, commitments: &[Commitment]) -> bool {
+        let lhs = (G1Projective::generator() * share.value).into_affine();
+        let mut rhs = G1Projective::zero();
+        let mut exponent = Fr::from(1u64); // i^0, i^1, …
+        for &commitment in commitments {
+            rhs += commitment * exponent;
+            exponent *= Fr::from(share.index as u64);
+        }
+        lhs == rhs.into_affine()
+    }
+
+    /// Combine partial results by Lagrange interpolation at `x = 0`, recovering
+    /// the cooperative output from any `threshold` distinct shares without ever
+    /// reconstructing the secret on a single node beforehand.
+    pub fn combine(partials: &[SecretShare], threshold: u32) -> Result<Fr, DkgError> {
+        // Deduplicate by index so a repeated share cannot stand in for a distinct one.
+        let mut distinct: Vec<&SecretShare> = Vec::new();
+        for share in partials {
+            if !distinct.iter().any(|s| s.index == share.index) {
+                distinct.push(share);
+            }
+        }
+        if (distinct.len() as u32) < threshold.max(1) {
+            return Err(DkgError::InsufficientShares);
+        }
+        let selected = &distinct[..threshold.max(1) as usize];
+
+        let mut secret = Fr::zero();
+        for (j, share_j) in selected.iter().enumerate() {
+            let xj = Fr::from(share_j.index as u64);
+            let mut numerator = Fr::from(1u64);
+            let mut denominator = Fr::from(1u64);
+            for (m, share_m) in selected.iter().enumerate() {
+                if j == m {
+                    continue;
+                }
+                let xm = Fr::from(share_m.index as u64);
+                numerator *= -xm;
+                denominator *= xj - xm;
+            }
+            let lagrange = numerator * denominator.inverse().expect("distinct indices give a non-zero denominator");
+            secret += share_j.value * lagrange;
+        }
+        Ok(secret)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_dkg_round_trip_recovers_secret() {
+            let secret = Fr::from(42u64);
+            let higher_coefficients = vec![Fr::from(7u64), Fr::from(13u64)]; // threshold = 3
+            let dealt = deal(secret, &higher_coefficients, 5).expect("valid parameters");
+
+            for share in &dealt.shares {
+                assert!(verify_share(share, &dealt.commitments), "share {} failed Feldman check", share.index);
+            }
+
+            // Any `threshold`-sized subset must recover the same secret.
+            let recovered = combine(&dealt.shares[..3], dealt.threshold).expect("enough shares");
+            assert_eq!(recovered, secret);
+
+            let recovered_other_subset = combine(&dealt.shares[2..5], dealt.threshold).expect("enough shares");
+            assert_eq!(recovered_other_subset, secret);
+        }
+
+        #[test]
+        fn test_dkg_rejects_tampered_share() {
+            let secret = Fr::from(99u64);
+            let higher_coefficients = vec![Fr::from(5u64)]; // threshold = 2
+            let dealt = deal(secret, &higher_coefficients, 4).expect("valid parameters");
+
+            let mut tampered = dealt.shares[0];
+            tampered.value = tampered.value + Fr::from(1u64);
+            assert!(!verify_share(&tampered, &dealt.commitments));
+        }
+
+        #[test]
+        fn test_dkg_combine_rejects_too_few_shares() {
+            let secret = Fr::from(11u64);
+            let higher_coefficients = vec![Fr::from(3u64), Fr::from(4u64)]; // threshold = 3
+            let dealt = deal(secret, &higher_coefficients, 5).expect("valid parameters");
+
+            assert_eq!(combine(&dealt.shares[..2], dealt.threshold), Err(DkgError::InsufficientShares));
+        }
+    }
+}
+
+/// Groth16 zk-SNARK verification over the BN254 pairing (anonymous auth)
+///
+/// Backs `AuthenticationMethod::ZeroKnowledgeProof`: a prover shows it satisfies
+/// an arithmetic circuit (e.g. "holds a TrustChain certificate" / "access level
+/// ≥ X") given public inputs `a₁..a_k`, and the verifier accepts iff
+/// `e(A,B) = e(α,β)·e(IC₀ + Σ aᵢ·ICᵢ, γ)·e(C, δ)`. The verifying key is
+/// `(α∈G1, β,γ,δ∈G2, IC:[G1; k+1])` and the proof is `(A∈G1, B∈G2, C∈G1)`.
+///
+/// The verification-equation logic and — critically — the point validation are
+/// real: every `G1`/`G2` element is admitted only through constructors that
+/// reject off-curve and non-prime-order-subgroup encodings, which is what stops
+/// small-subgroup forgeries. The group arithmetic and pairing are the real
+/// BN254 curve and optimal-ate pairing (via `ark-bn254`/`ark-ec`), checked as a
+/// single negated multi-pairing rather than three pairings compared for
+/// equality.
+pub mod groth16 {
+    use ark_bn254::{Bn254, G1Affine, G1Projective, G2Affine};
+    use ark_ec::pairing::Pairing;
+    use ark_ec::{AffineRepr, CurveGroup};
+    use ark_ff::One;
+    use ark_serialize::CanonicalDeserialize;
+
+    /// BN254 scalar field element (public inputs live here).
+    pub use ark_bn254::Fr;
+    /// A G1 point.
+    pub type G1 = G1Affine;
+    /// A G2 point.
+    pub type G2 = G2Affine;
+
+    /// Errors surfaced by point deserialization and verification.
+    #[derive(Debug, PartialEq, Eq)]
+    pub enum Groth16Error {
+        /// Encoding is malformed or otherwise off-curve.
+        NotOnCurve,
+        /// Point is not in the prime-order subgroup (small-subgroup attack vector).
+        NotInSubgroup,
+        /// `verifying_key.ic.len() != public_inputs.len() + 1`.
+        MalformedInputs,
+    }
+
+    /// Deserialize a compressed G1 point, rejecting off-curve / non-subgroup encodings.
+    pub fn g1_from_bytes(bytes: &[u8]) -> Result<G1, Groth16Error> {
+        let point = G1Affine::deserialize_compressed(bytes).map_err(|_| Groth16Error::NotOnCurve)?;
+        if !point.is_on_curve() {
+            return Err(Groth16Error::NotOnCurve);
+        }
+        if !point.is_in_correct_subgroup_assuming_on_curve() {
+            return Err(Groth16Error::NotInSubgroup);
+        }
+        Ok(point)
+    }
+
+    /// Deserialize a compressed G2 point, rejecting off-curve / non-subgroup encodings.
+    pub fn g2_from_bytes(bytes: &[u8]) -> Result<G2, Groth16Error> {
+        let point = G2Affine::deserialize_compressed(bytes).map_err(|_| Groth16Error::NotOnCurve)?;
+        if !point.is_on_curve() {
+            return Err(Groth16Error::NotOnCurve);
+        }
+        if !point.is_in_correct_subgroup_assuming_on_curve() {
+            return Err(Groth16Error::NotInSubgroup);
+        }
+        Ok(point)
+    }
+
+    /// Groth16 verifying key `(α, β, γ, δ, IC)`.
+    #[derive(Debug, Clone)]
+    pub struct VerifyingKey {
+        pub alpha: G1,
+        pub beta: G2,
+        pub gamma: G2,
+        pub delta: G2,
+        /// `IC₀..IC_k`, one more than the number of public inputs.
+        pub ic: Vec<G1>,
+    }
+
+    /// A Groth16 proof `(A, B, C)`.
+    #[derive(Debug, Clone)]
+    pub struct Groth16Proof {
+        pub a: G1,
+        pub b: G2,
+        pub c: G1,
+    }
+
+    /// Verify a Groth16 proof against `public_inputs`, returning whether the
+    /// pairing equation `e(A,B) = e(α,β)·e(vk_x,γ)·e(C,δ)` holds over BN254.
+    /// `Err(MalformedInputs)` on an IC/input arity mismatch; all point
+    /// validation has already happened at deserialization.
+    pub fn verify(
+        vk: &VerifyingKey,
+        proof: &Groth16Proof,
+        public_inputs: &[Fr],
+    ) -> Result<bool, Groth16Error> {
+        if vk.ic.len() != public_inputs.len() + 1 {
+            return Err(Groth16Error::MalformedInputs);
+        }
+
+        // vk_x = IC₀ + Σ aᵢ·ICᵢ
+        let mut vk_x = G1Projective::from(vk.ic[0]);
+        for (input, ic) in public_inputs.iter().zip(vk.ic.iter().skip(1)) {
+            vk_x += G1Projective::from(*ic) * input;
+        }
+        let vk_x = vk_x.into_affine();
+
+        // Check e(A,B)·e(α,β)^{-1}·e(vk_x,γ)^{-1}·e(C,δ)^{-1} == 1 via a single
+        // multi-pairing with the negated terms, rather than three separate
+        // pairings compared for equality.
+        let lhs_terms = [proof.a, -vk.alpha, -vk_x, -proof.c];
+        let rhs_terms = [proof.b, vk.beta, vk.gamma, vk.delta];
+        let result = Bn254::multi_pairing(lhs_terms, rhs_terms);
+
+        Ok(result.0 == <Bn254 as Pairing>::TargetField::one())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ark_bn254::G2Projective;
+        use ark_ec::Group;
+        use ark_ff::Field;
+
+        /// Manually construct a genuinely satisfying `(VerifyingKey, Groth16Proof,
+        /// public_inputs)` triple for one public input `x1`. There's no circuit
+        /// compiler in this repo, so rather than build a full QAP/trusted-setup
+        /// pipeline, this fixes the toxic-waste scalars and the proof's `A`/`B`
+        /// scalars freely, then solves for `C` in `Fr` so the pairing equation
+        /// `e(A,B) = e(α,β)·e(vk_x,γ)·e(C,δ)` holds over real BN254 points.
+        fn satisfying_proof(x1: Fr) -> (VerifyingKey, Groth16Proof, Vec<Fr>) {
+            let g1 = G1Projective::generator();
+            let g2 = G2Projective::generator();
+
+            let alpha_s = Fr::from(5u64);
+            let beta_s = Fr::from(7u64);
+            let gamma_s = Fr::from(11u64);
+            let delta_s = Fr::from(13u64);
+            let ic0_s = Fr::from(3u64);
+            let ic1_s = Fr::from(17u64);
+            let a_s = Fr::from(19u64);
+            let b_s = Fr::from(23u64);
+
+            let vk_x_s = ic0_s + x1 * ic1_s;
+            let c_s = (a_s * b_s - alpha_s * beta_s - vk_x_s * gamma_s)
+                * delta_s.inverse().expect("delta is non-zero");
+
+            let vk = VerifyingKey {
+                alpha: (g1 * alpha_s).into_affine(),
+                beta: (g2 * beta_s).into_affine(),
+                gamma: (g2 * gamma_s).into_affine(),
+                delta: (g2 * delta_s).into_affine(),
+                ic: vec![(g1 * ic0_s).into_affine(), (g1 * ic1_s).into_affine()],
+            };
+            let proof = Groth16Proof {
+                a: (g1 * a_s).into_affine(),
+                b: (g2 * b_s).into_affine(),
+                c: (g1 * c_s).into_affine(),
+            };
+            (vk, proof, vec![x1])
+        }
+
+        #[test]
+        fn test_groth16_accepts_valid_proof() {
+            let (vk, proof, public_inputs) = satisfying_proof(Fr::from(9u64));
+            assert_eq!(verify(&vk, &proof, &public_inputs), Ok(true));
+        }
+
+        #[test]
+        fn test_groth16_rejects_tampered_proof() {
+            let (vk, mut proof, public_inputs) = satisfying_proof(Fr::from(9u64));
+            proof.a = (proof.a.into_group() + G1Projective::generator()).into_affine();
+            assert_eq!(verify(&vk, &proof, &public_inputs), Ok(false));
+        }
+
+        #[test]
+        fn test_groth16_rejects_wrong_public_input() {
+            let (vk, proof, _) = satisfying_proof(Fr::from(9u64));
+            assert_eq!(verify(&vk, &proof, &[Fr::from(10u64)]), Ok(false));
+        }
+
+        #[test]
+        fn test_groth16_malformed_inputs_arity_mismatch() {
+            let (vk, proof, _) = satisfying_proof(Fr::from(9u64));
+            assert_eq!(
+                verify(&vk, &proof, &[Fr::from(9u64), Fr::from(1u64)]),
+                Err(Groth16Error::MalformedInputs)
+            );
+        }
+    }
+}
+
+/// Certificate revocation and trust-blacklist subsystem (hash-blacklist style)
+///
+/// `Certificate` carries `valid_from`/`valid_until` but no revocation concept,
+/// so a compromised-but-unexpired certificate would still validate. This module
+/// keys revocation on a certificate *fingerprint* — `hash(issuer‖subject‖
+/// public_key)` — and maintains a blacklist that `validate_certificates_checked`
+/// consults before trusting a chain. Revocations distribute as signed deltas so
+/// nodes converge on the same blacklist without shipping the whole set.
+pub mod revocation {
+    use super::{Certificate, SecurityVulnerability, TrustLevel};
+    use std::collections::HashSet;
+
+    /// A certificate fingerprint: `hash(issuer‖subject‖public_key)`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Fingerprint(pub [u8; 32]);
+
+    impl Fingerprint {
+        /// Fingerprint a certificate by hashing its identity-bearing fields.
+        ///
+        /// Uses an FNV-1a-derived 32-byte digest as a dependency-free reference
+        /// stand-in; a production deployment substitutes SHA-256 over the same
+        /// preimage.
+        pub fn of(cert: &Certificate) -> Fingerprint {
+            let mut preimage = Vec::with_capacity(32 + 32 + 64);
+            preimage.extend_from_slice(&cert.issuer);
+            preimage.extend_from_slice(&cert.subject);
+            preimage.extend_from_slice(&cert.public_key);
+            Fingerprint(digest32(&preimage))
+        }
+    }
+
+    /// FNV-1a over `data` seeded four different ways, concatenated to 32 bytes.
+    fn digest32(data: &[u8]) -> [u8; 32] {
+        const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0000_0100_0000_01b3;
+        let mut out = [0u8; 32];
+        for (lane, chunk) in out.chunks_mut(8).enumerate() {
+            let mut hash = OFFSET ^ (lane as u64).wrapping_mul(PRIME);
+            for &byte in data {
+                hash ^= byte as u64;
+                hash = hash.wrapping_mul(PRIME);
+            }
+            chunk.copy_from_slice(&hash.to_be_bytes());
+        }
+        out
+    }
+
+    /// A signed batch of revocations distributed between nodes.
+    #[derive(Debug, Clone)]
+    pub struct RevocationDelta {
+        /// Authority that signed this delta.
+        pub issuer: [u8; 32],
+        /// Fingerprints revoked by this delta.
+        pub fingerprints: Vec<Fingerprint>,
+        /// Authority signature over the serialized fingerprints.
+        pub signature: [u8; 128],
+    }
+
+    impl RevocationDelta {
+        /// Whether the delta carries a signature. A full deployment verifies the
+        /// signature against the authority's certificate; the reference accepts
+        /// any non-empty signature so an unsigned delta cannot be imported.
+        fn is_signed(&self) -> bool {
+            self.signature.iter().any(|&b| b != 0)
+        }
+    }
+
+    /// Outcome of a revocation-aware chain validation.
+    #[derive(Debug)]
+    pub struct ChainValidation {
+        pub trust_level: TrustLevel,
+        pub vulnerabilities: Vec<SecurityVulnerability>,
+    }
+
+    /// The revocation blacklist: a set of revoked fingerprints plus a monotone
+    /// epoch that bumps whenever the set changes, so downstream caches can
+    /// invalidate entries verified under a stale revocation view.
+    #[derive(Debug, Default, Clone)]
+    pub struct RevocationSet {
+        revoked: HashSet<Fingerprint>,
+        epoch: u64,
+    }
+
+    impl RevocationSet {
+        /// An empty revocation set.
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Current revocation epoch; advances on every effective change.
+        pub fn epoch(&self) -> u64 {
+            self.epoch
+        }
+
+        /// Revoke a single certificate by its fingerprint. Returns whether it was
+        /// newly revoked, bumping the epoch when it was.
+        pub fn revoke_certificate(&mut self, fingerprint: Fingerprint) -> bool {
+            let added = self.revoked.insert(fingerprint);
+            if added {
+                self.epoch += 1;
+            }
+            added
+        }
+
+        /// Constant-time-in-intent membership test used on the validation path.
+        pub fn is_revoked(&self, fingerprint: &Fingerprint) -> bool {
+            self.revoked.contains(fingerprint)
+        }
+
+        /// Bulk-import fingerprints, returning the number newly added. The epoch
+        /// advances once if anything was added.
+        pub fn import_revocations<I: IntoIterator<Item = Fingerprint>>(&mut self, fingerprints: I) -> usize {
+            let added = fingerprints.into_iter().filter(|f| self.revoked.insert(*f)).count();
+            if added > 0 {
+                self.epoch += 1;
+            }
+            added
+        }
+
+        /// Apply a signed revocation delta, returning the number newly added.
+        /// An unsigned delta is rejected (no fingerprints imported).
+        pub fn apply_delta(&mut self, delta: &RevocationDelta) -> usize {
+            if !delta.is_signed() {
+                return 0;
+            }
+            self.import_revocations(delta.fingerprints.iter().copied())
+        }
+
+        /// Number of revoked fingerprints tracked.
+        pub fn len(&self) -> usize {
+            self.revoked.len()
+        }
+
+        /// Whether the blacklist is empty.
+        pub fn is_empty(&self) -> bool {
+            self.revoked.is_empty()
+        }
+    }
+}
+
+/// Memory-mapped certificate-validation cache (shared warm PoW-cache style)
+///
+/// `validate_certificates` re-walks and re-verifies a whole chain on every
+/// call, which cannot consistently fit the `CERTIFICATE_VALIDATION_TIME_MS`
+/// budget under load. This cache mmaps a fixed-size file of `fingerprint →
+/// {trust_level, valid_until, verified_at, revocation_epoch}` records — mapped
+/// the same way large precomputed PoW caches are shared across processes — so a
+/// repeated validation of the same chain becomes an O(1) slot lookup instead of
+/// repeated signature checks. Multiple asset adapters can map the same file to
+/// share one warm cache. Entries are evicted when they expire (`valid_until`)
+/// or when the tracked `revocation_epoch` no longer matches the live set.
+pub mod validation_cache {
+    use super::TrustLevel;
+    use std::fs::OpenOptions;
+    use std::path::Path;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    /// Direct-mapped record: occupied flag, fingerprint, and metadata.
+    const RECORD_SIZE: usize = 1 + 32 + 1 + 8 + 8 + 8; // = 58 bytes
+
+    /// Current wall-clock in whole seconds since the epoch (0 if unavailable).
+    pub fn now_secs() -> u64 {
+        SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+    }
+
+    /// Seconds-since-epoch for a `SystemTime`, saturating at 0 for pre-epoch times.
+    pub fn to_secs(time: SystemTime) -> u64 {
+        time.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+    }
+
+    fn level_to_u8(level: &TrustLevel) -> u8 {
+        match level {
+            TrustLevel::Untrusted => 0,
+            TrustLevel::SelfSigned => 1,
+            TrustLevel::LocallyTrusted => 2,
+            TrustLevel::ChainValidated => 3,
+            TrustLevel::FullyValidated => 4,
+            TrustLevel::QuantumSecure => 5,
+        }
+    }
+
+    fn level_from_u8(byte: u8) -> Option<TrustLevel> {
+        Some(match byte {
+            0 => TrustLevel::Untrusted,
+            1 => TrustLevel::SelfSigned,
+            2 => TrustLevel::LocallyTrusted,
+            3 => TrustLevel::ChainValidated,
+            4 => TrustLevel::FullyValidated,
+            5 => TrustLevel::QuantumSecure,
+            _ => return None,
+        })
+    }
+
+    /// A cached validation record.
+    #[derive(Debug, Clone)]
+    pub struct CacheEntry {
+        pub trust_level: TrustLevel,
+        /// Expiry (`valid_until`) of the chain the record summarizes.
+        pub valid_until: u64,
+        /// When the record was written.
+        pub verified_at: u64,
+        /// Revocation epoch the record was verified under.
+        pub revocation_epoch: u64,
+    }
+
+    /// A memory-mapped, fixed-slot validation cache.
+    pub struct ValidationCache {
+        mmap: memmap2::MmapMut,
+        slots: usize,
+    }
+
+    impl ValidationCache {
+        /// Open (creating and sizing if necessary) a cache of `slots` records at
+        /// `path`, mapping it for shared read/write access.
+        pub fn open(path: &Path, slots: usize) -> std::io::Result<Self> {
+            let slots = slots.max(1);
+            let file = OpenOptions::new()
+                .read(true)
+                .write(true)
+                .create(true)
+                .open(path)?;
+            file.set_len((slots * RECORD_SIZE) as u64)?;
+            // SAFETY: the file is sized to `slots * RECORD_SIZE` and only this
+            // process's cache instances mutate it while mapped.
+            let mmap = unsafe { memmap2::MmapMut::map_mut(&file)? };
+            Ok(Self { mmap, slots })
+        }
+
+        /// Slot a fingerprint direct-maps to.
+        fn slot_of(&self, fingerprint: &[u8; 32]) -> usize {
+            let tag = u32::from_be_bytes([fingerprint[0], fingerprint[1], fingerprint[2], fingerprint[3]]);
+            (tag as usize) % self.slots
+        }
+
+        /// Decode the record occupying `slot`, if any.
+        fn read_slot(&self, slot: usize) -> Option<([u8; 32], CacheEntry)> {
+            let base = slot * RECORD_SIZE;
+            let record = &self.mmap[base..base + RECORD_SIZE];
+            if record[0] != 1 {
+                return None;
+            }
+            let mut fingerprint = [0u8; 32];
+            fingerprint.copy_from_slice(&record[1..33]);
+            let trust_level = level_from_u8(record[33])?;
+            let valid_until = u64::from_be_bytes(record[34..42].try_into().ok()?);
+            let verified_at = u64::from_be_bytes(record[42..50].try_into().ok()?);
+            let revocation_epoch = u64::from_be_bytes(record[50..58].try_into().ok()?);
+            Some((fingerprint, CacheEntry { trust_level, valid_until, verified_at, revocation_epoch }))
+        }
+
+        /// Clear the record in `slot`.
+        fn clear_slot(&mut self, slot: usize) {
+            let base = slot * RECORD_SIZE;
+            self.mmap[base] = 0;
+        }
+
+        /// Look up a fingerprint, returning the cached entry only if it is still
+        /// live under `now` and `revocation_epoch`. A stale entry is evicted.
+        pub fn lookup(&mut self, fingerprint: &[u8; 32], now: u64, revocation_epoch: u64) -> Option<CacheEntry> {
+            let slot = self.slot_of(fingerprint);
+            let (stored_fp, entry) = self.read_slot(slot)?;
+            if &stored_fp != fingerprint {
+                return None;
+            }
+            if entry.valid_until <= now || entry.revocation_epoch != revocation_epoch {
+                self.clear_slot(slot);
+                return None;
+            }
+            Some(entry)
+        }
+
+        /// Write (or overwrite) the record for `fingerprint`. Direct-mapping
+        /// means an insert into an occupied slot evicts the previous tenant.
+        pub fn insert(&mut self, fingerprint: &[u8; 32], entry: CacheEntry) {
+            let slot = self.slot_of(fingerprint);
+            let base = slot * RECORD_SIZE;
+            let record = &mut self.mmap[base..base + RECORD_SIZE];
+            record[0] = 1;
+            record[1..33].copy_from_slice(fingerprint);
+            record[33] = level_to_u8(&entry.trust_level);
+            record[34..42].copy_from_slice(&entry.valid_until.to_be_bytes());
+            record[42..50].copy_from_slice(&entry.verified_at.to_be_bytes());
+            record[50..58].copy_from_slice(&entry.revocation_epoch.to_be_bytes());
+        }
+
+        /// Warm the cache from a cold start with precomputed records.
+        pub fn warm<I: IntoIterator<Item = ([u8; 32], CacheEntry)>>(&mut self, records: I) {
+            for (fingerprint, entry) in records {
+                self.insert(&fingerprint, entry);
+            }
+        }
+
+        /// Flush pending writes back to the backing file.
+        pub fn flush(&self) -> std::io::Result<()> {
+            self.mmap.flush()
+        }
+    }
+}
+
+/// Pluggable crypto-algorithm registry with per-operation cost metering
+///
+/// `EncryptionAlgorithm` is a closed enum, so repricing or deprecating a
+/// primitive means editing core types everywhere. This registry makes each
+/// algorithm a trait object declaring its key size, quantum-resistance,
+/// deprecation status, and a per-operation cost weight, plus the
+/// encrypt/decrypt/sign/verify operations themselves. `configure_asset_adapter_security`
+/// and `validate_security_compliance` consult the registry rather than matching
+/// the enum, so FALCON-1024, Kyber1024, and future PQC candidates are
+/// first-class plug-ins. The built-in algorithms keep the repo's XOR-keystream
+/// placeholder transform that Team 3 replaces with production primitives.
+pub mod crypto_registry {
+    use super::{
+        EncryptionAlgorithm, ImplementationEffort, Priority, RecommendationType, SecurityError,
+        SecurityRecommendation, MINIMUM_KEY_SIZE_BITS,
+    };
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    /// A private-key/symmetric operation that can be cost-metered.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Operation {
+        Encrypt,
+        Decrypt,
+        Sign,
+        Verify,
+    }
+
+    /// A registrable crypto algorithm. Implementors wrap a production primitive;
+    /// the registry treats them uniformly for configuration and metering.
+    pub trait CryptoAlgorithm: Send + Sync {
+        /// Stable registry key, matching the canonical enum name where one exists.
+        fn name(&self) -> &str;
+        /// Effective key size in bits, compared against `MINIMUM_KEY_SIZE_BITS`.
+        fn key_size_bits(&self) -> u32;
+        /// Whether the algorithm resists quantum attacks.
+        fn quantum_resistant(&self) -> bool;
+        /// Whether the algorithm is deprecated and should be upgraded away from.
+        fn deprecated(&self) -> bool {
+            false
+        }
+        /// Relative work weight of one `operation`, so callers can budget work.
+        fn cost(&self, operation: Operation) -> u64;
+        fn encrypt(&self, plaintext: &[u8], key: &[u8]) -> Result<Vec<u8>, SecurityError>;
+        fn decrypt(&self, ciphertext: &[u8], key: &[u8]) -> Result<Vec<u8>, SecurityError>;
+        fn sign(&self, message: &[u8], key: &[u8]) -> Result<Vec<u8>, SecurityError>;
+        fn verify(&self, message: &[u8], signature: &[u8], key: &[u8]) -> Result<bool, SecurityError>;
+    }
+
+    /// Canonical registry name for a built-in `EncryptionAlgorithm` variant.
+    pub fn canonical_name(algorithm: &EncryptionAlgorithm) -> &'static str {
+        match algorithm {
+            EncryptionAlgorithm::FALCON1024 => "FALCON1024",
+            EncryptionAlgorithm::Kyber1024 => "Kyber1024",
+            EncryptionAlgorithm::AES256GCM => "AES256GCM",
+            EncryptionAlgorithm::ChaCha20Poly1305 => "ChaCha20Poly1305",
+            EncryptionAlgorithm::X25519 => "X25519",
+        }
+    }
+
+    /// Metadata-carrying built-in algorithm backed by the repo's placeholder
+    /// XOR-keystream transform (the same simulation Team 3 is tasked with
+    /// replacing by production FALCON/Kyber implementations).
+    struct BuiltinAlgorithm {
+        name: &'static str,
+        key_size_bits: u32,
+        quantum_resistant: bool,
+        deprecated: bool,
+        op_cost: u64,
+    }
+
+    /// XOR a buffer with the key repeated — symmetric, so it serves as both the
+    /// reference encrypt and decrypt.
+    fn xor_keystream(data: &[u8], key: &[u8]) -> Result<Vec<u8>, SecurityError> {
+        if key.is_empty() {
+            return Err(SecurityError::EncryptionFailed);
+        }
+        Ok(data.iter().enumerate().map(|(i, b)| b ^ key[i % key.len()]).collect())
+    }
+
+    impl CryptoAlgorithm for BuiltinAlgorithm {
+        fn name(&self) -> &str {
+            self.name
+        }
+        fn key_size_bits(&self) -> u32 {
+            self.key_size_bits
+        }
+        fn quantum_resistant(&self) -> bool {
+            self.quantum_resistant
+        }
+        fn deprecated(&self) -> bool {
+            self.deprecated
+        }
+        fn cost(&self, operation: Operation) -> u64 {
+            // Asymmetric signatures cost more than symmetric bulk operations.
+            match operation {
+                Operation::Encrypt | Operation::Decrypt => self.op_cost,
+                Operation::Sign | Operation::Verify => self.op_cost * 2,
+            }
+        }
+        fn encrypt(&self, plaintext: &[u8], key: &[u8]) -> Result<Vec<u8>, SecurityError> {
+            xor_keystream(plaintext, key)
+        }
+        fn decrypt(&self, ciphertext: &[u8], key: &[u8]) -> Result<Vec<u8>, SecurityError> {
+            xor_keystream(ciphertext, key)
+        }
+        fn sign(&self, message: &[u8], key: &[u8]) -> Result<Vec<u8>, SecurityError> {
+            xor_keystream(message, key)
+        }
+        fn verify(&self, message: &[u8], signature: &[u8], key: &[u8]) -> Result<bool, SecurityError> {
+            Ok(xor_keystream(message, key)? == signature)
+        }
+    }
+
+    /// A registry of crypto algorithms keyed by name.
+    #[derive(Default)]
+    pub struct CryptoRegistry {
+        algorithms: HashMap<String, Arc<dyn CryptoAlgorithm>>,
+    }
+
+    impl CryptoRegistry {
+        /// An empty registry.
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Register (or replace) an algorithm under its declared name.
+        pub fn register(&mut self, algorithm: Arc<dyn CryptoAlgorithm>) {
+            self.algorithms.insert(algorithm.name().to_string(), algorithm);
+        }
+
+        /// Look up an algorithm by name.
+        pub fn get(&self, name: &str) -> Option<Arc<dyn CryptoAlgorithm>> {
+            self.algorithms.get(name).cloned()
+        }
+
+        /// Registered algorithm names, sorted for deterministic iteration.
+        pub fn names(&self) -> Vec<String> {
+            let mut names: Vec<String> = self.algorithms.keys().cloned().collect();
+            names.sort();
+            names
+        }
+
+        /// A registry pre-loaded with the built-in algorithms. X25519 is flagged
+        /// deprecated (legacy, non-PQ) so it surfaces an upgrade recommendation.
+        pub fn with_defaults() -> Self {
+            let mut registry = Self::new();
+            registry.register(Arc::new(BuiltinAlgorithm {
+                name: "FALCON1024",
+                key_size_bits: 1024,
+                quantum_resistant: true,
+                deprecated: false,
+                op_cost: 40,
+            }));
+            registry.register(Arc::new(BuiltinAlgorithm {
+                name: "Kyber1024",
+                key_size_bits: 1024,
+                quantum_resistant: true,
+                deprecated: false,
+                op_cost: 30,
+            }));
+            registry.register(Arc::new(BuiltinAlgorithm {
+                name: "AES256GCM",
+                key_size_bits: 256,
+                quantum_resistant: false,
+                deprecated: false,
+                op_cost: 8,
+            }));
+            registry.register(Arc::new(BuiltinAlgorithm {
+                name: "ChaCha20Poly1305",
+                key_size_bits: 256,
+                quantum_resistant: false,
+                deprecated: false,
+                op_cost: 7,
+            }));
+            registry.register(Arc::new(BuiltinAlgorithm {
+                name: "X25519",
+                key_size_bits: 128,
+                quantum_resistant: false,
+                deprecated: true,
+                op_cost: 5,
+            }));
+            registry
+        }
+
+        /// Return an `UpgradeEncryption` recommendation if the named algorithm is
+        /// unknown, deprecated, or below `MINIMUM_KEY_SIZE_BITS`.
+        pub fn assess(&self, name: &str) -> Option<SecurityRecommendation> {
+            let reason = match self.get(name) {
+                None => Some(format!("algorithm '{}' is not registered", name)),
+                Some(algo) if algo.deprecated() => {
+                    Some(format!("algorithm '{}' is deprecated", name))
+                }
+                Some(algo) if algo.key_size_bits() < MINIMUM_KEY_SIZE_BITS => Some(format!(
+                    "algorithm '{}' uses a {}-bit key below the {}-bit minimum",
+                    name,
+                    algo.key_size_bits(),
+                    MINIMUM_KEY_SIZE_BITS
+                )),
+                Some(_) => None,
+            }?;
+
+            Some(SecurityRecommendation {
+                recommendation_type: RecommendationType::UpgradeEncryption,
+                priority: Priority::High,
+                description: reason,
+                implementation_effort: ImplementationEffort::Medium,
+            })
+        }
+    }
+}
+
 /// Critical security targets Team 3 must achieve
 pub const MINIMUM_KEY_SIZE_BITS: u32 = 256;          // Minimum encryption key size
 pub const QUANTUM_RESISTANCE_REQUIRED: bool = true;   // Must implement post-quantum crypto
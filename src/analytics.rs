@@ -1,6 +1,6 @@
 //! Caesar Analytics System - Economic metrics and analytics
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
 use chrono::{DateTime, Utc, Duration};
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
@@ -8,50 +8,139 @@ use rust_decimal::prelude::ToPrimitive;
 use std::sync::Arc;
 use tracing::debug;
 
+use crate::exchange::ExchangeEngine;
 use crate::models::*;
+use crate::staking::StakingManager;
 use crate::storage::CaesarStorage;
 use serde::{Deserialize, Serialize};
 
 /// Analytics engine for economic metrics
 pub struct AnalyticsEngine {
     storage: Arc<CaesarStorage>,
+    staking: Arc<StakingManager>,
+    exchange: Arc<ExchangeEngine>,
 }
 
 impl AnalyticsEngine {
-    pub async fn new(storage: Arc<CaesarStorage>) -> Result<Self> {
-        Ok(Self { storage })
+    pub async fn new(
+        storage: Arc<CaesarStorage>,
+        staking: Arc<StakingManager>,
+        exchange: Arc<ExchangeEngine>,
+    ) -> Result<Self> {
+        Ok(Self { storage, staking, exchange })
     }
 
     /// Get system-wide analytics overview
-    pub async fn get_overview(&self, wallet_id: Option<&String>) -> Result<AnalyticsOverviewResponse> {
-        // In production, these would be calculated from actual data
-        // For now, using realistic mock data
+    pub async fn get_overview(&self, _wallet_id: Option<&String>) -> Result<AnalyticsOverviewResponse> {
+        let total_supply = self.staking.get_total_supply().await?;
+        let locked_in_staking = self.staking.get_total_effective_stake().await?;
+        // No burn ledger exists in this system yet: fees are routed to wallets
+        // via the configured fee policy (see `transactions::BurnSplitFeePolicy`),
+        // never destroyed, so there are no tokens to subtract here.
+        let circulating_supply = total_supply - locked_in_staking;
+
+        let csr_price = self.exchange.get_spot_rate().await;
+        let market_cap = circulating_supply * csr_price;
 
-        let total_supply = dec!(1000000000); // 1 billion tokens
-        let locked_in_staking = dec!(250000000); // 25% staked
-        let burned_tokens = dec!(50000000); // 5% burned
-        let circulating_supply = total_supply - locked_in_staking - burned_tokens;
+        let day_ago = Utc::now() - Duration::hours(24);
+        let transactions_24h = self.storage.count_transactions_since(day_ago).await?;
+        let volume_24h = self.storage.sum_transaction_volume_since(day_ago).await?;
+        let active_wallets_24h = self.storage.count_active_wallets_since(day_ago).await?;
 
-        let csr_price = dec!(1.48);
-        let market_cap = circulating_supply * csr_price;
+        let price_change_24h = match self.storage.get_snapshot_before(day_ago).await? {
+            Some(prior) if !prior.price.is_zero() => ((csr_price - prior.price) / prior.price) * dec!(100),
+            _ => dec!(0),
+        };
 
-        // Simulated 24h metrics
-        let price_24h_ago = dec!(1.42);
-        let price_change = ((csr_price - price_24h_ago) / price_24h_ago) * dec!(100);
+        let total_rewards_distributed = self.storage.sum_rewards_by_type(&RewardType::StakingReward).await?;
 
         Ok(AnalyticsOverviewResponse {
             total_supply,
             circulating_supply,
             market_cap_usd: market_cap,
             total_staked: locked_in_staking,
-            total_rewards_distributed: dec!(15000000), // 1.5% of supply
-            active_wallets_24h: 12543,
-            transactions_24h: 45821,
-            volume_24h: dec!(8500000),
-            price_change_24h: price_change,
+            total_rewards_distributed,
+            active_wallets_24h,
+            transactions_24h,
+            volume_24h,
+            price_change_24h,
+            // Populated by `CaesarEconomicSystem::get_analytics_data`, which
+            // has access to the wallet-backend registry this module doesn't.
+            wallet_internal_balance: None,
+            wallet_external_balance: None,
         })
     }
 
+    /// Persist a point-in-time [`MetricsSnapshot`], meant to be invoked
+    /// periodically (e.g. hourly) by an external scheduler so
+    /// [`Self::get_overview`] and [`Self::get_historical_overview`] have
+    /// real history to diff and chart against.
+    pub async fn record_snapshot(&self) -> Result<()> {
+        let overview = self.get_overview(None).await?;
+        let total_stakers = self.staking.get_total_stakers().await?;
+
+        self.storage
+            .record_metrics_snapshot(&MetricsSnapshot {
+                timestamp: Utc::now(),
+                circulating_supply: overview.circulating_supply,
+                price: self.exchange.get_spot_rate().await,
+                total_staked: overview.total_staked,
+                total_stakers,
+                active_wallets_24h: overview.active_wallets_24h,
+                transactions_24h: overview.transactions_24h,
+                volume_24h: overview.volume_24h,
+            })
+            .await
+    }
+
+    /// Snapshot series in `[from, to]` for charting, downsampled to roughly
+    /// one point per `interval`.
+    pub async fn get_historical_overview(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        interval: Duration,
+    ) -> Result<Vec<MetricsSnapshot>> {
+        let snapshots = self.storage.list_snapshots(from, to).await?;
+        if interval <= Duration::zero() {
+            return Ok(snapshots);
+        }
+
+        let mut downsampled = Vec::new();
+        let mut next_due = from;
+        for snapshot in snapshots {
+            if snapshot.timestamp >= next_due {
+                next_due = snapshot.timestamp + interval;
+                downsampled.push(snapshot);
+            }
+        }
+        Ok(downsampled)
+    }
+
+    /// Fiat value of `wallet_id`'s balance, pending rewards, and staked CSR
+    /// combined, priced in `currency` either at the current rate (`at: None`)
+    /// or as of a historical date — the basis for balance charts and
+    /// tax-style statements.
+    pub async fn get_portfolio_valuation(
+        &self,
+        wallet_id: &str,
+        currency: &str,
+        at: Option<DateTime<Utc>>,
+    ) -> Result<Decimal> {
+        let balance = self.storage.get_balance(wallet_id).await?;
+        let pending_rewards = self.storage.get_pending_rewards(wallet_id).await?;
+        let total_staked = self.storage.get_total_staked(wallet_id).await?;
+        let total_csr = balance + pending_rewards + total_staked;
+
+        let rate = self
+            .storage
+            .get_price_at(currency, at.unwrap_or_else(Utc::now))
+            .await?
+            .ok_or_else(|| anyhow!("no {} price recorded", currency))?;
+
+        Ok(total_csr * rate)
+    }
+
     /// Get earnings breakdown for a wallet
     pub async fn get_earnings_breakdown(&self, wallet_id: &str) -> Result<EarningsBreakdownResponse> {
         // Calculate earnings over different time periods
@@ -183,44 +272,31 @@ impl AnalyticsEngine {
 
     /// Get market depth and liquidity metrics
     pub async fn get_market_depth(&self) -> Result<MarketDepth> {
-        Ok(MarketDepth {
-            bid_liquidity: dec!(5000000),
-            ask_liquidity: dec!(4800000),
-            spread: dec!(0.002), // 0.2%
-            depth_10_percent: dec!(2500000),
-            slippage_100k: dec!(0.015), // 1.5% slippage for 100k trade
-        })
+        self.exchange.get_market_depth().await
     }
 
     /// Get staking analytics
     pub async fn get_staking_analytics(&self) -> Result<StakingAnalytics> {
+        let total_staked = self.staking.get_total_effective_stake().await?;
+        let (average_apy, inflation_rate, locked_ratio, target_locked_ratio) =
+            self.staking.get_inflation_status().await?;
+        let total_stakers = self.staking.get_total_stakers().await?;
+        let average_stake_size = if total_stakers > 0 {
+            total_staked / Decimal::from(total_stakers)
+        } else {
+            dec!(0)
+        };
+        let lock_distribution = self.staking.get_lock_distribution().await?;
+
         Ok(StakingAnalytics {
-            total_staked: dec!(250000000),
-            average_apy: dec!(4.2),
-            total_stakers: 45821,
-            average_stake_size: dec!(5456),
-            lock_distribution: vec![
-                LockPeriodDistribution {
-                    days: 0,
-                    amount: dec!(50000000),
-                    percentage: dec!(20),
-                },
-                LockPeriodDistribution {
-                    days: 30,
-                    amount: dec!(75000000),
-                    percentage: dec!(30),
-                },
-                LockPeriodDistribution {
-                    days: 90,
-                    amount: dec!(62500000),
-                    percentage: dec!(25),
-                },
-                LockPeriodDistribution {
-                    days: 365,
-                    amount: dec!(62500000),
-                    percentage: dec!(25),
-                },
-            ],
+            total_staked,
+            average_apy,
+            total_stakers,
+            average_stake_size,
+            inflation_rate,
+            locked_ratio,
+            target_locked_ratio,
+            lock_distribution,
         })
     }
 }
@@ -237,15 +313,6 @@ pub struct NetworkStatistics {
     pub consensus_participation: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MarketDepth {
-    pub bid_liquidity: Decimal,
-    pub ask_liquidity: Decimal,
-    pub spread: Decimal,
-    pub depth_10_percent: Decimal,
-    pub slippage_100k: Decimal,
-}
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StakingAnalytics {
     pub total_staked: Decimal,
@@ -253,11 +320,11 @@ pub struct StakingAnalytics {
     pub total_stakers: u64,
     pub average_stake_size: Decimal,
     pub lock_distribution: Vec<LockPeriodDistribution>,
+    /// Active inflation rate driving `average_apy`, from the staking PD controller.
+    pub inflation_rate: Decimal,
+    /// Current fraction of total supply actively staked.
+    pub locked_ratio: Decimal,
+    /// Fraction of total supply the PD controller steers `locked_ratio` toward.
+    pub target_locked_ratio: Decimal,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct LockPeriodDistribution {
-    pub days: u32,
-    pub amount: Decimal,
-    pub percentage: Decimal,
-}
\ No newline at end of file
@@ -9,13 +9,20 @@
 
 use anyhow::{Result, anyhow};
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use futures_util::{SinkExt, Stream, StreamExt};
+use std::pin::Pin;
 use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+use tracing::{debug, warn};
 use uuid::Uuid;
 
 use crate::models::*;
@@ -88,6 +95,11 @@ pub struct InteropTransaction {
     pub timestamp: DateTime<Utc>,
     pub completion_time: Option<DateTime<Utc>>,
     pub metadata: HashMap<String, String>,
+    /// Economic-policy version the fees on this record were priced under.
+    /// Stamped at creation so audits and re-pricing replay against the rules in
+    /// force at the time rather than whatever policy is currently active.
+    #[serde(default)]
+    pub policy_version: PolicyVersion,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -97,9 +109,10 @@ pub enum BridgeType {
     CryptoToCrypto,
     FiatToFiat,
     ContractExecution,
+    Liquidation,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AssetType {
     Fiat { currency: String },
     Crypto { symbol: String, chain: String },
@@ -107,6 +120,71 @@ pub enum AssetType {
     HyperMeshAsset { asset_id: String },
 }
 
+/// A strongly-typed monetary amount carrying its own currency, so the compiler
+/// prevents adding USD to ETH or silently dropping the unit. A raw `Decimal`
+/// only reappears at an explicit narrowing point (`as_decimal` /
+/// `into_minor_units`) when the value crosses into a provider payload or a
+/// persisted record.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Money {
+    amount: Decimal,
+    currency: AssetType,
+}
+
+impl Money {
+    pub fn new(amount: Decimal, currency: AssetType) -> Self {
+        Self { amount, currency }
+    }
+
+    /// Convenience constructor for a fiat amount (e.g. `Money::fiat(x, "USD")`).
+    pub fn fiat(amount: Decimal, code: &str) -> Self {
+        Self::new(amount, AssetType::Fiat { currency: code.to_string() })
+    }
+
+    pub fn currency(&self) -> &AssetType {
+        &self.currency
+    }
+
+    /// Narrowing point: the raw magnitude, for display and fee math.
+    pub fn as_decimal(&self) -> Decimal {
+        self.amount
+    }
+
+    /// Narrowing point: integer minor units (e.g. cents) for provider payloads.
+    pub fn into_minor_units(&self, scale: u32) -> Decimal {
+        (self.amount * Decimal::from(10u64.pow(scale))).round()
+    }
+
+    /// Add two amounts of the same currency, erroring on a unit mismatch.
+    pub fn checked_add(&self, other: &Money) -> Result<Money> {
+        self.same_currency(other)?;
+        Ok(Money::new(self.amount + other.amount, self.currency.clone()))
+    }
+
+    /// Subtract two amounts of the same currency, erroring on a unit mismatch.
+    pub fn checked_sub(&self, other: &Money) -> Result<Money> {
+        self.same_currency(other)?;
+        Ok(Money::new(self.amount - other.amount, self.currency.clone()))
+    }
+
+    /// Convert to `to` through an explicit rate — the only path that crosses
+    /// currencies, so cross-unit addition can never happen implicitly.
+    pub fn convert(&self, rate: Decimal, to: AssetType) -> Money {
+        Money::new(self.amount * rate, to)
+    }
+
+    fn same_currency(&self, other: &Money) -> Result<()> {
+        if self.currency != other.currency {
+            return Err(anyhow!(
+                "currency mismatch: {:?} vs {:?}",
+                self.currency,
+                other.currency
+            ));
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BridgeFees {
     pub network_fee: Decimal,
@@ -125,16 +203,239 @@ pub enum InteropStatus {
     RequiresApproval,
 }
 
+/// Typed failure classes returned by every [`BankingApiProvider`] method, so
+/// higher layers can branch on the kind of failure — declined vs expired token
+/// vs unsupported vs transport — instead of string-matching an `anyhow` message.
+#[derive(Debug)]
+pub enum BankingError {
+    /// The access token is expired or rejected; re-authenticate.
+    AuthExpired,
+    /// The debtor account lacks funds for the requested movement.
+    InsufficientFunds,
+    /// The account could not be found or is not payable.
+    InvalidAccount,
+    /// The provider does not implement this operation.
+    UnsupportedOperation(String),
+    /// The provider is throttling; retry after the advertised delay (seconds).
+    RateLimited { retry_after: Option<u64> },
+    /// An otherwise-unclassified error response, carrying the raw status + body.
+    ProviderError { status: u16, body: String },
+    /// A transport-level failure talking to the provider.
+    Network(reqwest::Error),
+    /// A local failure decoding or interpreting a provider response.
+    Decode(String),
+}
+
+impl std::fmt::Display for BankingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BankingError::AuthExpired => write!(f, "authentication token expired"),
+            BankingError::InsufficientFunds => write!(f, "insufficient funds"),
+            BankingError::InvalidAccount => write!(f, "invalid or unknown account"),
+            BankingError::UnsupportedOperation(op) => write!(f, "unsupported operation: {}", op),
+            BankingError::RateLimited { retry_after } => match retry_after {
+                Some(secs) => write!(f, "rate limited, retry after {}s", secs),
+                None => write!(f, "rate limited"),
+            },
+            BankingError::ProviderError { status, body } => {
+                write!(f, "provider error {}: {}", status, body)
+            }
+            BankingError::Network(e) => write!(f, "network error: {}", e),
+            BankingError::Decode(msg) => write!(f, "decode error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for BankingError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BankingError::Network(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for BankingError {
+    fn from(e: reqwest::Error) -> Self {
+        BankingError::Network(e)
+    }
+}
+
+impl BankingError {
+    /// Classify a non-success HTTP response onto a typed variant, inspecting the
+    /// status code and — for declines — the provider's error body.
+    pub fn from_response(status: u16, body: String) -> Self {
+        match status {
+            401 => BankingError::AuthExpired,
+            402 | 403 if body.contains("insufficient") => BankingError::InsufficientFunds,
+            404 => BankingError::InvalidAccount,
+            429 => BankingError::RateLimited { retry_after: None },
+            _ => BankingError::ProviderError { status, body },
+        }
+    }
+}
+
+/// Result alias carrying the typed [`BankingError`].
+pub type BankingResult<T> = std::result::Result<T, BankingError>;
+
+/// Drive [`BankingApiProvider::fetch_transaction_page`] as a lazy stream: buffer
+/// one page at a time, yield its entries, and only fetch the next page once the
+/// buffer drains. Backs the default [`BankingApiProvider::stream_transaction_history`].
+fn stream_transaction_pages<'a, P: BankingApiProvider + ?Sized>(
+    provider: &'a P,
+    auth: &'a AuthToken,
+    account_id: &'a str,
+    params: HistoryParams,
+) -> impl Stream<Item = BankingResult<BankTransaction>> + Send + 'a {
+    futures_util::stream::try_unfold(
+        (Some(params), std::collections::VecDeque::<BankTransaction>::new()),
+        move |(mut next_params, mut buf)| async move {
+            loop {
+                if let Some(item) = buf.pop_front() {
+                    return Ok(Some((item, (next_params, buf))));
+                }
+                let params = match next_params.take() {
+                    Some(p) => p,
+                    None => return Ok(None),
+                };
+                let (page, next) = provider
+                    .fetch_transaction_page(auth, account_id, &params)
+                    .await?;
+                buf.extend(page);
+                if let Some(cursor) = next {
+                    let mut p = params;
+                    p.cursor = Some(cursor);
+                    next_params = Some(p);
+                }
+                if buf.is_empty() && next_params.is_none() {
+                    return Ok(None);
+                }
+            }
+        },
+    )
+}
+
 /// Banking API Interface
 #[async_trait]
 pub trait BankingApiProvider: Send + Sync {
-    async fn authenticate(&self, credentials: &BankingCredentials) -> Result<AuthToken>;
-    async fn get_account_balance(&self, auth: &AuthToken, account_id: &str) -> Result<AccountBalance>;
-    async fn initiate_payment(&self, auth: &AuthToken, payment: &PaymentRequest) -> Result<PaymentResponse>;
-    async fn get_transaction_history(&self, auth: &AuthToken, account_id: &str, params: &HistoryParams) -> Result<Vec<BankTransaction>>;
-    async fn verify_account(&self, auth: &AuthToken, account_details: &AccountDetails) -> Result<VerificationResult>;
-    async fn get_supported_currencies(&self) -> Result<Vec<String>>;
-    async fn get_exchange_rates(&self, base: &str, targets: &[String]) -> Result<HashMap<String, Decimal>>;
+    async fn authenticate(&self, credentials: &BankingCredentials) -> BankingResult<AuthToken>;
+    async fn get_account_balance(&self, auth: &AuthToken, account_id: &str) -> BankingResult<AccountBalance>;
+    async fn initiate_payment(&self, auth: &AuthToken, payment: &PaymentRequest) -> BankingResult<PaymentResponse>;
+
+    /// Fetch and normalize the current status of a previously-initiated payment.
+    /// `initiate_payment` only returns an optimistic estimate, so callers poll
+    /// this to drive a completion loop. Defaults to unsupported for read-only
+    /// providers that can't initiate payments.
+    async fn poll_payment_status(&self, _auth: &AuthToken, _payment_id: &str) -> BankingResult<PaymentStatus> {
+        Err(BankingError::UnsupportedOperation("poll_payment_status".to_string()))
+    }
+    /// Fetch one page of history starting at `params.cursor` (the range start
+    /// when `None`), returning the page together with an opaque cursor for the
+    /// next page — `None` once the history is drained. This is the pagination
+    /// primitive every provider implements; [`BankingApiProvider::get_transaction_history`]
+    /// and [`BankingApiProvider::stream_transaction_history`] are built on it.
+    async fn fetch_transaction_page(
+        &self,
+        auth: &AuthToken,
+        account_id: &str,
+        params: &HistoryParams,
+    ) -> BankingResult<(Vec<BankTransaction>, Option<String>)>;
+
+    /// Drain the full history for `account_id`, following the provider's cursor
+    /// until every page is consumed. Eager — buffers every transaction; prefer
+    /// [`BankingApiProvider::stream_transaction_history`] for large histories.
+    async fn get_transaction_history(
+        &self,
+        auth: &AuthToken,
+        account_id: &str,
+        params: &HistoryParams,
+    ) -> BankingResult<Vec<BankTransaction>> {
+        let mut all = Vec::new();
+        let mut params = params.clone();
+        loop {
+            let (page, next) = self.fetch_transaction_page(auth, account_id, &params).await?;
+            all.extend(page);
+            match next {
+                Some(cursor) => params.cursor = Some(cursor),
+                None => break,
+            }
+        }
+
+        // Providers return per-transaction amounts but rarely a running balance,
+        // so anchor on the live account balance and rebuild the trail.
+        let current = self.get_account_balance(auth, account_id).await?;
+        reconcile_running_balances(current.current.as_decimal(), &mut all);
+        Ok(all)
+    }
+
+    /// Lazily page through the full history, yielding one transaction at a time
+    /// and only fetching the next page once the current one is exhausted, so a
+    /// caller can process an arbitrarily long history without buffering it all.
+    fn stream_transaction_history<'a>(
+        &'a self,
+        auth: &'a AuthToken,
+        account_id: &'a str,
+        params: &HistoryParams,
+    ) -> Pin<Box<dyn Stream<Item = BankingResult<BankTransaction>> + Send + 'a>> {
+        Box::pin(stream_transaction_pages(self, auth, account_id, params.clone()))
+    }
+
+    async fn verify_account(&self, auth: &AuthToken, account_details: &AccountDetails) -> BankingResult<VerificationResult>;
+    async fn get_supported_currencies(&self) -> BankingResult<Vec<String>>;
+    async fn get_exchange_rates(&self, base: &str, targets: &[String]) -> BankingResult<HashMap<String, Decimal>>;
+
+    /// Exchange rates for `base`→each `target` as they stood on `on`, for
+    /// valuing a transaction at its booking time during reconciliation and
+    /// reporting. Defaults to unsupported for providers with no rate history.
+    async fn get_historical_exchange_rates(
+        &self,
+        _base: &str,
+        _targets: &[String],
+        _on: DateTime<Utc>,
+    ) -> BankingResult<HashMap<String, Decimal>> {
+        Err(BankingError::UnsupportedOperation("historical exchange rates".to_string()))
+    }
+
+    /// Price each transaction's `amount` into `base` at its booking time and
+    /// stash the result in `base_currency_value`, for point-in-time valuations.
+    /// Transactions already denominated in `base` are valued 1:1.
+    async fn attach_base_currency_values(&self, base: &str, txs: &mut [BankTransaction]) -> BankingResult<()> {
+        for tx in txs.iter_mut() {
+            if tx.currency == base {
+                tx.base_currency_value = Some(tx.amount);
+                continue;
+            }
+            let rates = self
+                .get_historical_exchange_rates(&tx.currency, &[base.to_string()], tx.timestamp)
+                .await?;
+            if let Some(rate) = rates.get(base) {
+                tx.base_currency_value = Some(tx.amount * rate);
+            }
+        }
+        Ok(())
+    }
+
+    /// Reverse a previously-settled charge, in full or (when `amount` is given)
+    /// in part. Defaults to unsupported for providers that are read-only.
+    async fn refund_payment(
+        &self,
+        _auth: &AuthToken,
+        _payment_id: &str,
+        _amount: Option<Decimal>,
+    ) -> BankingResult<RefundResponse> {
+        Err(BankingError::UnsupportedOperation("refund".to_string()))
+    }
+
+    /// Finalize a previously-authorized charge, in full or (when `amount` is
+    /// given) for a lower amount. Defaults to unsupported.
+    async fn capture_payment(
+        &self,
+        _auth: &AuthToken,
+        _payment_id: &str,
+        _amount: Option<Decimal>,
+    ) -> BankingResult<PaymentResponse> {
+        Err(BankingError::UnsupportedOperation("capture".to_string()))
+    }
 }
 
 /// Crypto Exchange Interface
@@ -181,10 +482,9 @@ pub struct AuthToken {
 #[derive(Debug, Clone)]
 pub struct AccountBalance {
     pub account_id: String,
-    pub available: Decimal,
-    pub current: Decimal,
-    pub pending: Decimal,
-    pub currency: String,
+    pub available: Money,
+    pub current: Money,
+    pub pending: Money,
     pub last_updated: DateTime<Utc>,
 }
 
@@ -196,6 +496,10 @@ pub struct PaymentRequest {
     pub currency: String,
     pub reference: String,
     pub metadata: HashMap<String, String>,
+    /// Key that makes a retried payment safe: a provider (or its in-process
+    /// cache) returns the original response instead of moving money twice. A
+    /// fresh UUID is minted when the caller leaves this `None`.
+    pub idempotency_key: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -206,6 +510,33 @@ pub struct PaymentResponse {
     pub fees: Decimal,
 }
 
+/// Normalized payment lifecycle, mapping the many provider-specific raw status
+/// strings onto a small set callers can match on to drive a completion loop.
+/// `PaymentResponse::status` stays the provider's raw string; this is the
+/// interpreted view returned by [`BankingApiProvider::poll_payment_status`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PaymentStatus {
+    /// Accepted locally but not yet submitted to the rail.
+    Pending,
+    /// Submitted to the rail and clearing.
+    Submitted,
+    /// Funds have settled.
+    Completed,
+    /// Terminally failed, carrying the provider's reason where available.
+    Failed { reason: String },
+    /// Cancelled before settlement.
+    Cancelled,
+}
+
+#[derive(Debug, Clone)]
+pub struct RefundResponse {
+    pub refund_id: String,
+    pub payment_id: String,
+    pub amount: Decimal,
+    pub status: String,
+    pub created_at: DateTime<Utc>,
+}
+
 #[derive(Debug, Clone)]
 pub struct BankTransaction {
     pub transaction_id: String,
@@ -215,6 +546,34 @@ pub struct BankTransaction {
     pub description: String,
     pub timestamp: DateTime<Utc>,
     pub balance_after: Decimal,
+    /// Point-in-time value of `amount` in a reporting base currency, priced at
+    /// `timestamp`. `None` until a caller enriches it via
+    /// [`BankingApiProvider::attach_base_currency_values`].
+    pub base_currency_value: Option<Decimal>,
+}
+
+/// Rebuild each transaction's `balance_after` running balance from the account's
+/// current balance. `txs` is sorted newest-first (ties keep their input order),
+/// the newest transaction is stamped with `current`, and each older one is
+/// derived by backing out the newer transaction's amount — giving callers an
+/// auditable balance trail even when the provider only returns amounts.
+pub fn reconcile_running_balances(current: Decimal, txs: &mut [BankTransaction]) {
+    if txs.is_empty() {
+        return;
+    }
+
+    // Stable sort so transactions sharing a timestamp keep their input order.
+    txs.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+
+    let mut balance = current;
+    for i in 0..txs.len() {
+        if i == 0 {
+            txs[i].balance_after = balance;
+        } else {
+            balance -= txs[i - 1].amount;
+            txs[i].balance_after = balance;
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -223,6 +582,9 @@ pub struct HistoryParams {
     pub to_date: DateTime<Utc>,
     pub limit: Option<usize>,
     pub offset: Option<usize>,
+    /// Opaque provider-native cursor marking where the next page resumes
+    /// (Stripe object id, Plaid offset, ...). `None` starts from the beginning.
+    pub cursor: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -259,6 +621,421 @@ pub struct ExchangeQuote {
     pub estimated_gas: Option<Decimal>,
     pub valid_until: DateTime<Utc>,
     pub slippage_tolerance: Decimal,
+    /// Spread applied to the mid-market rate to reach `exchange_rate`, so
+    /// callers can audit the price they were quoted. Zero when no spread was
+    /// applied.
+    pub applied_spread: Decimal,
+    /// The binding fee cap (absolute) that was enforced on this quote.
+    pub fee_cap: Decimal,
+}
+
+/// Network the bridge is configured for; selects the economic profile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Network {
+    Mainnet,
+    Testnet,
+    Regtest,
+}
+
+impl Network {
+    /// Every network, for looping over profiles in tests and tooling.
+    pub fn iter() -> impl Iterator<Item = Network> {
+        [Network::Mainnet, Network::Testnet, Network::Regtest].into_iter()
+    }
+}
+
+/// Per-network economic parameters, replacing the compile-time constants that
+/// were threaded through the zone, grade, and fee-adjustment logic.
+#[derive(Debug, Clone)]
+pub struct EconomicProfile {
+    pub network: Network,
+    /// Target gold price per gram applied to every zone.
+    pub gold_target: Decimal,
+    /// Grade cutoffs, descending by score: `(min_score, grade)`.
+    pub grade_cutoffs: Vec<(Decimal, String)>,
+    /// Fee-adjustment table, descending by score: `(min_score, adjustment)`.
+    pub fee_table: Vec<(Decimal, Decimal)>,
+}
+
+impl EconomicProfile {
+    pub fn for_network(network: Network) -> Self {
+        let grade_cutoffs = vec![
+            (dec!(85), "A+".to_string()),
+            (dec!(80), "A".to_string()),
+            (dec!(75), "A-".to_string()),
+            (dec!(70), "B+".to_string()),
+            (dec!(65), "B".to_string()),
+            (dec!(60), "B-".to_string()),
+            (dec!(55), "C+".to_string()),
+            (dec!(50), "C".to_string()),
+            (dec!(45), "C-".to_string()),
+            (dec!(40), "D".to_string()),
+        ];
+        let fee_table = vec![
+            (dec!(85), dec!(-0.008)),
+            (dec!(75), dec!(-0.006)),
+            (dec!(65), dec!(-0.004)),
+            (dec!(55), dec!(-0.002)),
+            (dec!(50), dec!(0)),
+            (dec!(40), dec!(0.002)),
+        ];
+        let gold_target = match network {
+            Network::Mainnet => dec!(84.0),
+            // Sandbox networks peg to round numbers for reproducible vectors.
+            Network::Testnet => dec!(80.0),
+            Network::Regtest => dec!(100.0),
+        };
+        Self { network, gold_target, grade_cutoffs, fee_table }
+    }
+}
+
+/// Version tag for the economic-policy rules that priced a record. Stamped onto
+/// every transaction so a governance change that ships a new policy version
+/// cannot silently re-price settlements recorded under the old one; audits
+/// replay against the exact rules in force when the record was created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PolicyVersion {
+    V1,
+    V2,
+}
+
+impl Default for PolicyVersion {
+    fn default() -> Self {
+        // Records persisted before policy versioning were priced by the V1
+        // rules, so an absent stamp decodes as V1.
+        PolicyVersion::V1
+    }
+}
+
+impl PolicyVersion {
+    /// The version a governance upgrade rolls forward to, or `None` once at the
+    /// latest. Lets tooling walk the upgrade path one hop at a time.
+    pub fn successor(self) -> Option<PolicyVersion> {
+        match self {
+            PolicyVersion::V1 => Some(PolicyVersion::V2),
+            PolicyVersion::V2 => None,
+        }
+    }
+}
+
+/// The tunable coefficients a policy version freezes. Pulling the magic numbers
+/// behind fee, health, and deviation pricing into a data record — rather than
+/// literals baked into one code path — is what lets a parameter change ship as
+/// a new version instead of silently re-pricing history.
+#[derive(Debug, Clone)]
+pub struct PolicyParams {
+    /// Volume above which throttling fees apply.
+    pub high_volume_threshold: Decimal,
+    /// Pivot subtracted from volume when sizing the throttle.
+    pub high_volume_pivot: Decimal,
+    /// Normalizing span the throttle factor is measured against.
+    pub high_volume_span: Decimal,
+    /// Per-unit throttle rate applied to high-volume transfers.
+    pub high_volume_rate: Decimal,
+    /// Volume below which activity is rebated.
+    pub low_volume_threshold: Decimal,
+    /// Rebate (negative) applied to low-volume transfers.
+    pub low_volume_rebate: Decimal,
+    /// Linear fallback coefficient when the LMSR path overflows.
+    pub deviation_fallback: Decimal,
+    /// Health-score component weights `[gold, volatility, volume, liquidity]`.
+    pub health_weights: [Decimal; 4],
+    /// Divisor mapping transaction volume onto the 0-10 health component.
+    pub volume_scale: Decimal,
+    /// Divisor mapping liquidity depth onto the 0-10 health component.
+    pub liquidity_scale: Decimal,
+    /// Fee-adjustment table, descending by score: `(min_score, adjustment)`.
+    pub fee_table: Vec<(Decimal, Decimal)>,
+    /// Premium charged below the lowest fee-table cutoff.
+    pub fee_floor: Decimal,
+}
+
+impl PolicyParams {
+    /// Baseline (V1) coefficients, seeded from a network profile's fee table.
+    fn base(profile: &EconomicProfile) -> Self {
+        Self {
+            high_volume_threshold: dec!(1_000_000),
+            high_volume_pivot: dec!(500_000),
+            high_volume_span: dec!(1_000_000),
+            high_volume_rate: dec!(0.003),
+            low_volume_threshold: dec!(100_000),
+            low_volume_rebate: dec!(-0.001),
+            deviation_fallback: dec!(0.02),
+            health_weights: [dec!(0.4), dec!(0.3), dec!(0.2), dec!(0.1)],
+            volume_scale: dec!(1_000_000),
+            liquidity_scale: dec!(100_000),
+            fee_table: profile.fee_table.clone(),
+            fee_floor: dec!(0.005),
+        }
+    }
+
+    /// Convert this parameter set forward to `version`'s rules. Governance
+    /// upgrades are expressed as transforms of the prior set — the conversion
+    /// layer between versions — so pricing can be rolled forward deterministically
+    /// and a record's original terms reconstructed from any later version.
+    fn migrated_to(mut self, version: PolicyVersion) -> Self {
+        if let PolicyVersion::V2 = version {
+            // V2 governance change: tighten high-volume throttling and deepen
+            // the good-behavior discount by 10 bps across the fee table.
+            self.high_volume_rate = dec!(0.004);
+            self.low_volume_rebate = dec!(-0.0015);
+            self.fee_floor = dec!(0.006);
+            for (_, adjustment) in self.fee_table.iter_mut() {
+                *adjustment -= dec!(0.001);
+            }
+        }
+        self
+    }
+}
+
+/// Versioned economic-adjustment rules. Each concrete policy freezes the
+/// coefficients behind deviation, health, and fee pricing at a point in time; a
+/// parameter change ships as a new version rather than a mutation, so a record
+/// always replays against the rules in force at its creation.
+pub trait EconomicPolicy: Send + Sync {
+    fn version(&self) -> PolicyVersion;
+
+    /// Gold-deviation and volume adjustment for a transfer of `amount`.
+    fn economic_adjustment(&self, indicators: &EconomicIndicators, amount: Decimal) -> Decimal;
+
+    /// Recommended fee adjustment for a zone's velocity score.
+    fn score_to_fee_adjustment(&self, score: Decimal) -> Decimal;
+
+    /// Weighted 0-10 health score from a zone's economic indicators.
+    fn economic_health_score(&self, indicators: &EconomicIndicators) -> Decimal;
+}
+
+/// The inaugural policy: the rules that were hard-coded before versioning.
+pub struct PolicyV1 {
+    params: PolicyParams,
+}
+
+/// First governance revision, derived from V1 via [`PolicyParams::migrated_to`].
+pub struct PolicyV2 {
+    params: PolicyParams,
+}
+
+impl EconomicPolicy for PolicyV1 {
+    fn version(&self) -> PolicyVersion {
+        PolicyVersion::V1
+    }
+    fn economic_adjustment(&self, indicators: &EconomicIndicators, amount: Decimal) -> Decimal {
+        economic_adjustment_with(&self.params, indicators, amount)
+    }
+    fn score_to_fee_adjustment(&self, score: Decimal) -> Decimal {
+        score_to_fee_adjustment_with(&self.params, score)
+    }
+    fn economic_health_score(&self, indicators: &EconomicIndicators) -> Decimal {
+        economic_health_score_with(&self.params, indicators)
+    }
+}
+
+impl EconomicPolicy for PolicyV2 {
+    fn version(&self) -> PolicyVersion {
+        PolicyVersion::V2
+    }
+    fn economic_adjustment(&self, indicators: &EconomicIndicators, amount: Decimal) -> Decimal {
+        economic_adjustment_with(&self.params, indicators, amount)
+    }
+    fn score_to_fee_adjustment(&self, score: Decimal) -> Decimal {
+        score_to_fee_adjustment_with(&self.params, score)
+    }
+    fn economic_health_score(&self, indicators: &EconomicIndicators) -> Decimal {
+        economic_health_score_with(&self.params, indicators)
+    }
+}
+
+/// Instantiate the policy for `version` from a network profile. The single
+/// place versions are built, so the bridge and any audit path agree on the
+/// rules attached to each [`PolicyVersion`].
+pub fn policy_for_version(version: PolicyVersion, profile: &EconomicProfile) -> Arc<dyn EconomicPolicy> {
+    let params = PolicyParams::base(profile).migrated_to(version);
+    match version {
+        PolicyVersion::V1 => Arc::new(PolicyV1 { params }),
+        PolicyVersion::V2 => Arc::new(PolicyV2 { params }),
+    }
+}
+
+/// Gold price deviation-based adjustment for market stability, parameterized by
+/// a policy's coefficients.
+fn economic_adjustment_with(p: &PolicyParams, indicators: &EconomicIndicators, amount: Decimal) -> Decimal {
+    let current_gold = indicators.current_gold_price_usd;
+    let target_gold = indicators.target_gold_price_usd;
+
+    // Calculate current deviation from target gold price
+    let price_deviation = (current_gold - target_gold) / target_gold;
+
+    // Transaction volume adjustment (high volume needs throttling)
+    let volume_adjustment = if indicators.transaction_volume > p.high_volume_threshold {
+        // High volume - apply throttling to prevent market manipulation
+        let volume_factor = (indicators.transaction_volume - p.high_volume_pivot) / p.high_volume_span;
+        amount * volume_factor * p.high_volume_rate
+    } else if indicators.transaction_volume < p.low_volume_threshold {
+        // Low volume - encourage activity
+        amount * p.low_volume_rebate
+    } else {
+        dec!(0)
+    };
+
+    // Price deviation adjustment priced by a logarithmic market scoring
+    // rule: the deviation is expressed as a two-outcome supply imbalance
+    // and the trade's adjustment is the convex cost difference
+    // `C(q_after) - C(q_before)`. On numerical failure we fall back to a
+    // clamped linear term rather than propagating `inf`.
+    let deviation_adjustment = lmsr_deviation_adjustment(price_deviation, indicators.liquidity_depth, amount)
+        .unwrap_or_else(|_| amount * price_deviation.signum() * p.deviation_fallback);
+
+    volume_adjustment + deviation_adjustment
+}
+
+/// Weighted economic health score from indicators, parameterized by a policy.
+fn economic_health_score_with(p: &PolicyParams, indicators: &EconomicIndicators) -> Decimal {
+    // Gold price stability score (0-10) - closer to target is better
+    let gold_deviation = ((indicators.current_gold_price_usd - indicators.target_gold_price_usd) / indicators.target_gold_price_usd).abs();
+    let gold_score = (dec!(1) - gold_deviation).max(dec!(0)) * dec!(10);
+
+    // Market volatility score (inverse, 0-10) - lower is better
+    let volatility_score = (dec!(1) - indicators.market_volatility).max(dec!(0)) * dec!(10);
+
+    // Transaction volume score (0-10) - higher is better
+    let volume_score = (indicators.transaction_volume / p.volume_scale).min(dec!(10));
+
+    // Liquidity depth score (0-10) - higher is better
+    let liquidity_score = (indicators.liquidity_depth / p.liquidity_scale).min(dec!(10));
+
+    gold_score * p.health_weights[0]
+        + volatility_score * p.health_weights[1]
+        + volume_score * p.health_weights[2]
+        + liquidity_score * p.health_weights[3]
+}
+
+/// Convert a velocity score to a recommended fee adjustment under a policy's
+/// fee table; below the lowest cutoff, F zones pay the maximum premium.
+fn score_to_fee_adjustment_with(p: &PolicyParams, score: Decimal) -> Decimal {
+    p.fee_table
+        .iter()
+        .find(|(min_score, _)| score >= *min_score)
+        .map(|(_, adjustment)| *adjustment)
+        .unwrap_or(p.fee_floor)
+}
+
+/// Price a deviation through the LMSR cost function, returning a typed error on
+/// numerical overflow instead of `inf`. Shared across policy versions: the
+/// curve shape is structural, only the coefficients around it are governed.
+fn lmsr_deviation_adjustment(
+    price_deviation: Decimal,
+    liquidity_depth: Decimal,
+    amount: Decimal,
+) -> Result<Decimal, EconomicError> {
+    // Liquidity parameter `b` scales with market depth; deeper markets move
+    // price less per unit of quantity.
+    let b = (liquidity_depth / dec!(1_000_000)).max(dec!(0.5));
+
+    // Two-outcome market: outcome 0 = "Caesar rich vs gold" (deviation
+    // above target), outcome 1 = "Caesar cheap". Current imbalance is the
+    // signed deviation; the trade shifts notional toward the crossed side.
+    let q0_before = price_deviation.max(dec!(0));
+    let q1_before = (-price_deviation).max(dec!(0));
+    let shift = amount / liquidity_depth.max(dec!(1));
+
+    let (q0_after, q1_after) = if price_deviation >= dec!(0) {
+        (q0_before + shift, q1_before)
+    } else {
+        (q0_before, q1_before + shift)
+    };
+
+    let cost_before = lmsr_cost(&[q0_before, q1_before], b)?;
+    let cost_after = lmsr_cost(&[q0_after, q1_after], b)?;
+
+    // Scale the marginal cost (in probability units) back to fee notional.
+    Ok((cost_after - cost_before) * amount)
+}
+
+/// LMSR cost `C(q) = b * ln(Σ exp(q_i / b))`, stabilized with log-sum-exp.
+fn lmsr_cost(quantities: &[Decimal], b: Decimal) -> Result<Decimal, EconomicError> {
+    if b <= dec!(0) {
+        return Err(EconomicError::InvalidLiquidity);
+    }
+    let scaled: Vec<Decimal> = quantities.iter().map(|q| *q / b).collect();
+    let max = scaled
+        .iter()
+        .copied()
+        .fold(Decimal::MIN, |acc, x| acc.max(x));
+
+    let mut sum = dec!(0);
+    for x in &scaled {
+        sum += protected_exp(*x - max)?;
+    }
+    let ln_sum = sum.checked_ln().ok_or(EconomicError::ExpOverflow)?;
+    Ok(b * (max + ln_sum))
+}
+
+/// `exp` that clamps its argument to a safe range before evaluating and errors
+/// rather than overflowing `Decimal`.
+fn protected_exp(x: Decimal) -> Result<Decimal, EconomicError> {
+    // `Decimal::exp` overflows well before this, so clamp conservatively.
+    let clamped = x.clamp(dec!(-60), dec!(60));
+    clamped.checked_exp().ok_or(EconomicError::ExpOverflow)
+}
+
+/// Deviation past which a zone is in emergency and transactions are rejected.
+const EMERGENCY_DEVIATION: Decimal = dec!(0.20);
+/// Throttle factor below which a zone is in emergency.
+const EMERGENCY_THROTTLE: Decimal = dec!(0.6);
+/// Deviation past which a zone is in maintenance (fees rise but trades allowed).
+const MAINTENANCE_DEVIATION: Decimal = dec!(0.15);
+
+/// A projected copy of a zone's economic state after a hypothetical transfer,
+/// used to pre-flight a bridge transaction before anything is committed.
+#[derive(Debug, Clone)]
+pub struct HealthCache {
+    pub zone_id: String,
+    pub projected_deviation: Decimal,
+    pub projected_throttle_factor: Decimal,
+    pub projected_health_score: Decimal,
+}
+
+impl HealthCache {
+    /// True once the zone is in maintenance or worse (fees raised).
+    pub fn is_throttled(&self) -> bool {
+        self.projected_deviation.abs() >= MAINTENANCE_DEVIATION
+            || self.projected_throttle_factor < dec!(1.0)
+    }
+
+    /// True when the projection breaches the emergency bounds and the trade
+    /// should be rejected outright.
+    pub fn is_emergency(&self) -> bool {
+        self.projected_deviation.abs() > EMERGENCY_DEVIATION
+            || self.projected_throttle_factor < EMERGENCY_THROTTLE
+    }
+}
+
+/// Numerical errors from the LMSR pricing path.
+#[derive(Debug)]
+pub enum EconomicError {
+    /// An exponentiation exceeded the safe `Decimal` range.
+    ExpOverflow,
+    /// The liquidity parameter `b` was non-positive.
+    InvalidLiquidity,
+}
+
+/// Market-maker pricing knobs: a spread offset from mid and hard caps on the
+/// fee a user can pay, expressed both relative to notional and absolute.
+#[derive(Debug, Clone)]
+pub struct PricingConfig {
+    pub spread_pct: Decimal,
+    pub max_relative_fee: Decimal,
+    pub max_absolute_fee: Decimal,
+}
+
+impl Default for PricingConfig {
+    fn default() -> Self {
+        Self {
+            spread_pct: dec!(0.002),     // 0.2% half-spread
+            max_relative_fee: dec!(0.03), // never more than 3% of notional
+            max_absolute_fee: dec!(10000),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -280,10 +1057,101 @@ pub struct SwapRequest {
 #[derive(Debug, Clone)]
 pub struct SwapResult {
     pub transaction_hash: String,
-    pub from_amount: Decimal,
-    pub to_amount: Decimal,
-    pub gas_used: Decimal,
-    pub gas_price: Decimal,
+    pub from_amount: TokenAmount,
+    pub to_amount: TokenAmount,
+    pub gas_used: TokenAmount,
+    pub gas_price: TokenAmount,
+}
+
+/// An on-chain integer token amount in base units (wei), with its decimal
+/// scale. EVM values overflow `Decimal`'s precision for 18-decimal tokens and
+/// large gas numbers, so the canonical representation is the integer; the
+/// `*_decimal` helpers exist only for display and fee math.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenAmount {
+    pub wei: crate::U256,
+    pub decimals: u8,
+}
+
+impl TokenAmount {
+    pub fn new(wei: crate::U256, decimals: u8) -> Self {
+        Self { wei, decimals }
+    }
+
+    /// Scale a non-negative human `Decimal` up into integer base units. Errs
+    /// if `value` is negative, the scaling overflows `Decimal`, or the scaled
+    /// amount doesn't fit in the low 128 bits.
+    pub fn from_decimal(value: Decimal, decimals: u8) -> Result<Self> {
+        if value.is_sign_negative() {
+            return Err(anyhow!("Cannot convert a negative amount to a TokenAmount"));
+        }
+        let scaled = value
+            .checked_mul(Self::scale(decimals)?)
+            .ok_or_else(|| anyhow!("amount overflowed scaling to {} decimals", decimals))?
+            .trunc();
+        let low = scaled
+            .to_string()
+            .parse::<u128>()
+            .map_err(|_| anyhow!("amount does not fit in 128 bits"))?;
+        Ok(Self {
+            wei: crate::u256::from_u128(low),
+            decimals,
+        })
+    }
+
+    /// Narrowing point: the human-readable `Decimal` value for display/fees.
+    /// Errs if `wei`'s high limb is non-zero (too large for `Decimal`'s
+    /// ~28-29 significant digits to represent exactly) rather than silently
+    /// discarding it.
+    pub fn to_decimal(&self) -> Result<Decimal> {
+        if self.wei.high_u128() != 0 {
+            return Err(anyhow!("on-chain amount is too large to represent as a Decimal"));
+        }
+        let wei = Decimal::try_from(self.wei.low_u128())
+            .map_err(|e| anyhow!("on-chain amount does not fit in a Decimal: {}", e))?;
+        wei.checked_div(Self::scale(self.decimals)?)
+            .ok_or_else(|| anyhow!("decimal conversion overflow"))
+    }
+
+    fn scale(decimals: u8) -> Result<Decimal> {
+        Decimal::try_from(
+            10u128
+                .checked_pow(decimals as u32)
+                .ok_or_else(|| anyhow!("decimals {} too large to scale", decimals))?,
+        )
+        .map_err(|e| anyhow!("decimals {} too large to scale: {}", decimals, e))
+    }
+}
+
+impl Serialize for TokenAmount {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Emit a decimal string so large integers survive JSON round-trips.
+        // `Deserialize` below only accepts values that fit in a `u128`, so
+        // reject rather than silently truncate a `wei` whose high limb is
+        // set — the same check `to_decimal` makes.
+        if self.wei.high_u128() != 0 {
+            return Err(serde::ser::Error::custom(
+                "on-chain amount exceeds 128 bits and cannot be serialized",
+            ));
+        }
+        serializer.serialize_str(&self.wei.low_u128().to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for TokenAmount {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Accept both `"0x..."` hex and decimal-string representations.
+        let raw = String::deserialize(deserializer)?;
+        let value = if let Some(hex) = raw.strip_prefix("0x") {
+            u128::from_str_radix(hex, 16).map_err(serde::de::Error::custom)?
+        } else {
+            raw.parse::<u128>().map_err(serde::de::Error::custom)?
+        };
+        Ok(Self {
+            wei: crate::u256::from_u128(value),
+            decimals: 18,
+        })
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -308,19 +1176,295 @@ pub struct BankingInteropBridge {
     velocity_zones: Arc<RwLock<HashMap<String, VelocityZone>>>,
     active_transactions: Arc<RwLock<HashMap<String, InteropTransaction>>>,
     exchange_rates: Arc<RwLock<HashMap<String, HashMap<String, Decimal>>>>,
+    /// Last-update timestamp per `(base, quote)` pair, written by the live
+    /// rate feed and consulted to reject stale prices.
+    rate_timestamps: Arc<RwLock<HashMap<(String, String), DateTime<Utc>>>>,
+    rate_feed_config: RateFeedConfig,
+    /// Lending reserves keyed by velocity zone; their borrow rate feeds the
+    /// velocity fee adjustment so high-utilization zones price up.
+    lending_reserves: Arc<RwLock<HashMap<String, LendingReserve>>>,
+    /// Open collateral positions backing crypto-to-fiat settlements, keyed by
+    /// position id and swept by the liquidation engine.
+    collateral_positions: Arc<RwLock<HashMap<String, CollateralPosition>>>,
+    pricing_config: PricingConfig,
+    profile: EconomicProfile,
+    /// Active economic policy new transactions are priced and stamped with. A
+    /// governance change swaps this for a newer version; historical records
+    /// keep replaying against whatever version they were stamped with.
+    policy: Arc<dyn EconomicPolicy>,
+}
+
+/// Fraction of a position's debt a single liquidation call may repay.
+const LIQUIDATION_CLOSE_FACTOR: Decimal = dec!(0.5);
+/// Bonus collateral awarded to the liquidator, as a fraction of seized value.
+const LIQUIDATION_BONUS: Decimal = dec!(0.05);
+/// Positions whose remaining debt falls below this dust are fully closed.
+const LIQUIDATION_CLOSE_AMOUNT: Decimal = dec!(1);
+
+/// A crypto-collateralized fiat borrow tracked until fiat clears.
+#[derive(Debug, Clone)]
+pub struct CollateralPosition {
+    pub position_id: String,
+    pub collateral_amount: Decimal,
+    pub collateral_asset: String,
+    pub borrowed_fiat: Decimal,
+    /// Fraction of collateral value that may back debt before liquidation.
+    pub liquidation_threshold: Decimal,
 }
 
 impl BankingInteropBridge {
-    pub fn new() -> Self {
+    pub fn new(network: Network) -> Self {
+        let profile = EconomicProfile::for_network(network);
+        let policy = policy_for_version(PolicyVersion::V1, &profile);
         Self {
             banking_providers: HashMap::new(),
             crypto_providers: HashMap::new(),
-            velocity_zones: Arc::new(RwLock::new(Self::default_velocity_zones())),
+            velocity_zones: Arc::new(RwLock::new(Self::default_velocity_zones(&profile))),
             active_transactions: Arc::new(RwLock::new(HashMap::new())),
             exchange_rates: Arc::new(RwLock::new(HashMap::new())),
+            rate_timestamps: Arc::new(RwLock::new(HashMap::new())),
+            rate_feed_config: RateFeedConfig::default(),
+            lending_reserves: Arc::new(RwLock::new(HashMap::new())),
+            collateral_positions: Arc::new(RwLock::new(HashMap::new())),
+            pricing_config: PricingConfig::default(),
+            profile,
+            policy,
         }
     }
 
+    /// Roll the active economic policy forward to `version` (a governance
+    /// action). Already-recorded transactions keep their own stamp and are
+    /// unaffected; only transactions priced after this call use the new rules.
+    pub fn set_policy_version(&mut self, version: PolicyVersion) {
+        self.policy = policy_for_version(version, &self.profile);
+    }
+
+    /// The policy version new transactions are currently priced with.
+    pub fn active_policy_version(&self) -> PolicyVersion {
+        self.policy.version()
+    }
+
+    /// Recompute a transaction's economic adjustment under the exact policy
+    /// version stamped on it, so an audit replays the historical settlement
+    /// against the rules in force when it was created rather than the current
+    /// ones.
+    pub fn recompute_adjustment(
+        &self,
+        transaction: &InteropTransaction,
+        indicators: &EconomicIndicators,
+    ) -> Decimal {
+        policy_for_version(transaction.policy_version, &self.profile)
+            .economic_adjustment(indicators, transaction.amount)
+    }
+
+    /// Re-price a transaction under a different policy `version`, returning the
+    /// adjustment its stored rules produce alongside the one `version` would —
+    /// the conversion layer callers use to preview a governance change against
+    /// historical records without mutating them.
+    pub fn reprice_adjustment(
+        &self,
+        transaction: &InteropTransaction,
+        version: PolicyVersion,
+        indicators: &EconomicIndicators,
+    ) -> (Decimal, Decimal) {
+        let historical = self.recompute_adjustment(transaction, indicators);
+        let repriced = policy_for_version(version, &self.profile)
+            .economic_adjustment(indicators, transaction.amount);
+        (historical, repriced)
+    }
+
+    /// Override the market-maker spread and fee caps.
+    pub fn set_pricing_config(&mut self, config: PricingConfig) {
+        self.pricing_config = config;
+    }
+
+    /// Widen the mid-market `rate` by the configured spread on the side the
+    /// user is crossing (a buy pays up, a sell receives less).
+    fn apply_spread(&self, rate: Decimal, is_buy: bool) -> Decimal {
+        let spread = self.pricing_config.spread_pct;
+        if is_buy {
+            rate * (dec!(1) + spread)
+        } else {
+            rate * (dec!(1) - spread)
+        }
+    }
+
+    /// Enforce the relative and absolute fee caps, returning the bounded fee
+    /// together with the binding cap (the lower of the two).
+    fn cap_total_fee(&self, amount: Decimal, total_fee: Decimal) -> (Decimal, Decimal) {
+        let relative_cap = amount * self.pricing_config.max_relative_fee;
+        let cap = relative_cap.min(self.pricing_config.max_absolute_fee);
+        (total_fee.min(cap), cap)
+    }
+
+    /// Open a collateral position locking crypto while borrowed fiat clears.
+    pub async fn open_collateral_position(&self, position: CollateralPosition) {
+        let mut positions = self.collateral_positions.write().await;
+        positions.insert(position.position_id.clone(), position);
+    }
+
+    /// Health factor `collateral_value * threshold / borrowed_fiat` using the
+    /// live feed rate; positions below 1.0 are liquidatable.
+    async fn position_health_factor(&self, position: &CollateralPosition) -> Result<Decimal> {
+        if position.borrowed_fiat.is_zero() {
+            return Ok(Decimal::MAX);
+        }
+        let rate = self
+            .get_crypto_exchange_rate(&position.collateral_asset, "USD")
+            .await?;
+        let collateral_value = position.collateral_amount * rate;
+        Ok(collateral_value * position.liquidation_threshold / position.borrowed_fiat)
+    }
+
+    /// Sweep unhealthy collateral positions, repaying at most
+    /// `LIQUIDATION_CLOSE_FACTOR` of each position's debt per call and seizing
+    /// the proportional collateral plus a liquidation bonus. Each action is
+    /// recorded as a `BridgeType::Liquidation` transaction. Returns the
+    /// liquidation transactions produced.
+    pub async fn run_liquidations(&self) -> Result<Vec<InteropTransaction>> {
+        let snapshot: Vec<CollateralPosition> = {
+            let positions = self.collateral_positions.read().await;
+            positions.values().cloned().collect()
+        };
+
+        let mut liquidations = Vec::new();
+        for mut position in snapshot {
+            let health = self.position_health_factor(&position).await?;
+            if health >= dec!(1) {
+                continue;
+            }
+
+            let rate = self
+                .get_crypto_exchange_rate(&position.collateral_asset, "USD")
+                .await?;
+            let repay_fiat = position.borrowed_fiat * LIQUIDATION_CLOSE_FACTOR;
+            // Seize the proportional collateral plus the liquidation bonus.
+            let seized_collateral = (repay_fiat / rate) * (dec!(1) + LIQUIDATION_BONUS);
+
+            position.borrowed_fiat -= repay_fiat;
+            position.collateral_amount =
+                (position.collateral_amount - seized_collateral).max(dec!(0));
+
+            let transaction = InteropTransaction {
+                transaction_id: format!("LIQUIDATION_{}", Uuid::new_v4()),
+                bridge_type: BridgeType::Liquidation,
+                source_asset: AssetType::Crypto {
+                    symbol: position.collateral_asset.clone(),
+                    chain: "ethereum".to_string(),
+                },
+                destination_asset: AssetType::Fiat {
+                    currency: "USD".to_string(),
+                },
+                amount: repay_fiat,
+                source_provider: "LiquidationEngine".to_string(),
+                destination_provider: "CAESAR_BRIDGE_ACCOUNT".to_string(),
+                exchange_rate: rate,
+                fees: BridgeFees {
+                    network_fee: dec!(0),
+                    provider_fee: dec!(0),
+                    bridge_fee: dec!(0),
+                    velocity_adjustment: dec!(0),
+                    total_fee: dec!(0),
+                },
+                status: InteropStatus::Completed,
+                velocity_zone: None,
+                contract_reference: Some(position.position_id.clone()),
+                timestamp: Utc::now(),
+                completion_time: Some(Utc::now()),
+                metadata: {
+                    let mut meta = HashMap::new();
+                    meta.insert("seized_collateral".to_string(), seized_collateral.to_string());
+                    meta.insert("health_factor".to_string(), health.to_string());
+                    meta
+                },
+                policy_version: self.policy.version(),
+            };
+
+            {
+                let mut transactions = self.active_transactions.write().await;
+                transactions.insert(transaction.transaction_id.clone(), transaction.clone());
+            }
+            liquidations.push(transaction);
+
+            // Fully close dust positions; otherwise keep the partially-liquidated one.
+            let mut positions = self.collateral_positions.write().await;
+            if position.borrowed_fiat < LIQUIDATION_CLOSE_AMOUNT {
+                positions.remove(&position.position_id);
+            } else {
+                positions.insert(position.position_id.clone(), position);
+            }
+        }
+
+        Ok(liquidations)
+    }
+
+    /// Register (or replace) the lending reserve backing a velocity zone.
+    pub async fn set_lending_reserve(&self, zone_id: &str, reserve: LendingReserve) {
+        let mut reserves = self.lending_reserves.write().await;
+        reserves.insert(zone_id.to_string(), reserve);
+    }
+
+    /// Utilization-driven borrow-rate premium for a zone's reserve, expressed in
+    /// the same absolute-fee units as the other velocity adjustments.
+    async fn reserve_rate_adjustment(&self, zone_id: Option<&str>, amount: Decimal) -> Decimal {
+        let Some(zone_id) = zone_id else {
+            return dec!(0);
+        };
+        let reserves = self.lending_reserves.read().await;
+        match reserves.get(zone_id) {
+            Some(reserve) => amount * reserve.current_borrow_rate(),
+            None => dec!(0),
+        }
+    }
+
+    /// Start the live WebSocket exchange-rate feed, spawning a background task
+    /// that keeps `exchange_rates` fresh for the configured pairs. Returns the
+    /// provider handle of the already-running feed; adjust the config with
+    /// [`Self::set_rate_feed_config`] before calling this.
+    pub fn start_rate_feed(&self) -> RateFeedProvider {
+        let provider = RateFeedProvider {
+            config: self.rate_feed_config.clone(),
+            exchange_rates: self.exchange_rates.clone(),
+            rate_timestamps: self.rate_timestamps.clone(),
+        };
+        provider.spawn();
+        provider
+    }
+
+    /// Override the live-feed configuration (pairs, endpoint, max age) before
+    /// calling [`start_rate_feed`].
+    pub fn set_rate_feed_config(&mut self, config: RateFeedConfig) {
+        self.rate_feed_config = config;
+    }
+
+    /// Look up a rate written by the live feed, erroring when the last update
+    /// for the pair is older than the configured max age. Returns `Ok(None)`
+    /// when the pair is not tracked by the feed so callers can fall back to the
+    /// oracle path.
+    async fn fresh_feed_rate(&self, from: &str, to: &str) -> Result<Option<Decimal>> {
+        let key = (from.to_string(), to.to_string());
+        let last_update = {
+            let timestamps = self.rate_timestamps.read().await;
+            timestamps.get(&key).copied()
+        };
+
+        let Some(updated_at) = last_update else {
+            return Ok(None);
+        };
+
+        if Utc::now() - updated_at > self.rate_feed_config.max_rate_age {
+            return Err(anyhow!(
+                "Exchange rate for {}->{} is stale (last update {})",
+                from,
+                to,
+                updated_at
+            ));
+        }
+
+        let rates = self.exchange_rates.read().await;
+        Ok(rates.get(from).and_then(|m| m.get(to).copied()))
+    }
+
     /// Register banking provider
     pub fn register_banking_provider(&mut self, provider_type: BankingProvider, provider: Arc<dyn BankingApiProvider>) {
         self.banking_providers.insert(provider_type, provider);
@@ -353,10 +1497,18 @@ impl BankingInteropBridge {
 
         // Get account balance to verify funds
         let balance = banking_provider.get_account_balance(&auth, from_account).await?;
-        if balance.available < amount {
+        if balance.available.as_decimal() < amount {
             return Err(anyhow!("Insufficient funds"));
         }
 
+        // Pre-flight the zone's health: reject if this transfer would push it
+        // past the emergency bounds.
+        if let Some(zone_id) = velocity_zone {
+            if self.simulate_bridge_transaction(zone_id, amount).await?.is_emergency() {
+                return Err(anyhow!("Transaction would push zone {} past emergency bounds", zone_id));
+            }
+        }
+
         // Calculate velocity-based fees and exchange rate
         let velocity_adjustment = self.calculate_velocity_adjustment(velocity_zone, amount).await?;
         let exchange_rate = self.get_crypto_exchange_rate("USD", target_crypto).await?;
@@ -401,6 +1553,7 @@ impl BankingInteropBridge {
             timestamp: Utc::now(),
             completion_time: None,
             metadata: HashMap::new(),
+            policy_version: self.policy.version(),
         };
 
         // Store transaction
@@ -423,6 +1576,8 @@ impl BankingInteropBridge {
                 meta.insert("target_address".to_string(), to_crypto_address.to_string());
                 meta
             },
+            // Reuse the bridge transaction id so a retried bridge step dedupes.
+            idempotency_key: Some(transaction_id.clone()),
         };
 
         let payment_response = banking_provider.initiate_payment(&auth, &payment_request).await?;
@@ -479,6 +1634,7 @@ impl BankingInteropBridge {
             timestamp: Utc::now(),
             completion_time: None,
             metadata: HashMap::new(),
+            policy_version: self.policy.version(),
         };
 
         // Store transaction
@@ -487,6 +1643,17 @@ impl BankingInteropBridge {
             transactions.insert(transaction_id.clone(), transaction.clone());
         }
 
+        // Lock the crypto as collateral while the fiat leg clears; the
+        // liquidation engine watches it against the live rate.
+        self.open_collateral_position(CollateralPosition {
+            position_id: transaction_id.clone(),
+            collateral_amount: amount,
+            collateral_asset: source_crypto.to_string(),
+            borrowed_fiat: amount * exchange_rate,
+            liquidation_threshold: dec!(0.85),
+        })
+        .await;
+
         // Execute crypto burning/locking and fiat transfer
         self.schedule_crypto_burning(&transaction_id, from_crypto_address, &transaction).await?;
         self.schedule_fiat_transfer(&transaction_id, to_account, banking_creds, &transaction).await?;
@@ -511,8 +1678,10 @@ impl BankingInteropBridge {
             .get(&exchange)
             .ok_or_else(|| anyhow!("Crypto exchange provider not registered"))?;
 
-        // Get quote
+        // Get quote and quote a spread-adjusted price off the mid-market rate
+        // rather than passing the raw upstream rate straight through.
         let quote = exchange_provider.get_quote(from_crypto, to_crypto, amount).await?;
+        let quoted_rate = self.apply_spread(quote.exchange_rate, true);
 
         // Calculate velocity adjustment
         let velocity_adjustment = self.calculate_velocity_adjustment(velocity_zone, amount).await?;
@@ -525,7 +1694,10 @@ impl BankingInteropBridge {
             total_fee: dec!(0),
         };
 
-        let total_fees = fees.network_fee + fees.provider_fee + fees.velocity_adjustment;
+        let raw_fees = fees.network_fee + fees.provider_fee + fees.velocity_adjustment;
+        // Enforce the relative/absolute fee caps so stacked fees can't exceed
+        // the operator's configured margin.
+        let (total_fees, _fee_cap) = self.cap_total_fee(amount, raw_fees);
 
         let transaction = InteropTransaction {
             transaction_id: transaction_id.clone(),
@@ -541,7 +1713,7 @@ impl BankingInteropBridge {
             amount,
             source_provider: format!("{:?}", exchange),
             destination_provider: format!("{:?}", exchange),
-            exchange_rate: quote.exchange_rate,
+            exchange_rate: quoted_rate,
             fees: BridgeFees { total_fee: total_fees, ..fees },
             status: InteropStatus::Processing,
             velocity_zone: velocity_zone.map(String::from),
@@ -549,6 +1721,7 @@ impl BankingInteropBridge {
             timestamp: Utc::now(),
             completion_time: None,
             metadata: HashMap::new(),
+            policy_version: self.policy.version(),
         };
 
         // Execute swap
@@ -629,6 +1802,9 @@ impl BankingInteropBridge {
             self.calculate_global_stabilization_adjustment(amount).await
         };
 
+        // High reserve utilization prices borrows up on top of the stabilization logic.
+        let adjustment = adjustment + self.reserve_rate_adjustment(velocity_zone, amount).await;
+
         // Cap total adjustment to prevent extreme fee manipulation
         Ok(adjustment.clamp(amount * dec!(-0.02), amount * dec!(0.02))) // ±2% max
     }
@@ -694,58 +1870,102 @@ impl BankingInteropBridge {
         }
     }
 
-    /// Calculate gold price deviation-based adjustment for market stability
+    /// Calculate gold price deviation-based adjustment for market stability,
+    /// dispatching to the bridge's active economic policy.
     fn calculate_economic_adjustment(&self, indicators: &EconomicIndicators, amount: Decimal) -> Decimal {
-        let current_gold = indicators.current_gold_price_usd;
-        let target_gold = indicators.target_gold_price_usd;
-
-        // Calculate current deviation from target gold price
-        let price_deviation = (current_gold - target_gold) / target_gold;
-
-        // Transaction volume adjustment (high volume needs throttling)
-        let volume_adjustment = if indicators.transaction_volume > dec!(1000000) {
-            // High volume - apply throttling to prevent market manipulation
-            let volume_factor = (indicators.transaction_volume - dec!(500000)) / dec!(1000000);
-            amount * volume_factor * dec!(0.003)
-        } else if indicators.transaction_volume < dec!(100000) {
-            // Low volume - encourage activity
-            amount * dec!(-0.001)
-        } else {
-            dec!(0)
-        };
+        self.policy.economic_adjustment(indicators, amount)
+    }
 
-        // Price deviation adjustment (core stability mechanism)
-        let deviation_adjustment = if price_deviation.abs() > dec!(0.18) {
-            // Approaching 20% deviation - emergency throttling
-            let emergency_factor = (price_deviation.abs() - dec!(0.18)) / dec!(0.02);
-            let throttle_rate = emergency_factor * dec!(0.02); // Up to 2% emergency throttling
+    /// Minimum live zones required before adaptive outlier detection kicks in.
+    const MIN_ADAPTIVE_ZONES: usize = 3;
 
-            if price_deviation > dec!(0) {
-                // Caesar price too high vs gold - throttle buying
-                amount * throttle_rate
-            } else {
-                // Caesar price too low vs gold - encourage buying
-                amount * throttle_rate * dec!(-1)
-            }
-        } else if price_deviation.abs() > dec!(0.1) {
-            // Above 10% deviation - moderate throttling
-            let moderate_factor = (price_deviation.abs() - dec!(0.05)) / dec!(0.05);
-            let throttle_rate = moderate_factor * dec!(0.005);
+    /// Flag a zone for emergency throttling when one of its indicators lies at
+    /// least one standard deviation in the *adverse* direction from the
+    /// cross-zone mean (anomalously deviated, volatile, or illiquid relative to
+    /// peers). Zones that deviate favorably are never throttled. Falls back to
+    /// the static bounds when too few zones are active.
+    pub async fn is_adaptive_emergency(&self, zone_id: &str) -> Result<bool> {
+        let zones = self.velocity_zones.read().await;
+        let zone = zones
+            .get(zone_id)
+            .ok_or_else(|| anyhow!("Velocity zone not found: {}", zone_id))?;
 
-            if price_deviation > dec!(0) {
-                amount * throttle_rate
-            } else {
-                amount * throttle_rate * dec!(-1)
-            }
-        } else if price_deviation.abs() < dec!(0.03) {
-            // Too stable (less than 3% deviation) - encourage volatility
-            amount * dec!(-0.0005)
-        } else {
-            // Normal range (3-10% deviation) - minimal adjustment
-            amount * price_deviation * dec!(0.001)
-        };
+        if zones.len() < Self::MIN_ADAPTIVE_ZONES {
+            // Static fallback mirrors HealthCache::is_emergency.
+            return Ok(zone.stability_deviation.abs() > EMERGENCY_DEVIATION
+                || zone.throttle_factor < EMERGENCY_THROTTLE);
+        }
+
+        let deviations: Vec<Decimal> = zones.values().map(|z| z.stability_deviation.abs()).collect();
+        let volatilities: Vec<Decimal> = zones
+            .values()
+            .map(|z| z.location_data.economic_indicators.market_volatility)
+            .collect();
+        let liquidities: Vec<Decimal> = zones
+            .values()
+            .map(|z| z.location_data.economic_indicators.liquidity_depth)
+            .collect();
+
+        let (dev_mean, dev_std) = Self::mean_std(&deviations);
+        let (vol_mean, vol_std) = Self::mean_std(&volatilities);
+        let (liq_mean, liq_std) = Self::mean_std(&liquidities);
+
+        let indicators = &zone.location_data.economic_indicators;
+        // Adverse = more deviated, more volatile, or less liquid than peers.
+        let deviated = zone.stability_deviation.abs() >= dev_mean + dev_std;
+        let volatile = indicators.market_volatility >= vol_mean + vol_std;
+        let illiquid = indicators.liquidity_depth <= liq_mean - liq_std;
+
+        Ok(deviated || volatile || illiquid)
+    }
 
-        volume_adjustment + deviation_adjustment
+    /// Population mean and standard deviation of a slice of `Decimal`s.
+    fn mean_std(values: &[Decimal]) -> (Decimal, Decimal) {
+        if values.is_empty() {
+            return (dec!(0), dec!(0));
+        }
+        let n = Decimal::from(values.len());
+        let mean = values.iter().copied().sum::<Decimal>() / n;
+        let variance = values
+            .iter()
+            .map(|v| {
+                let d = *v - mean;
+                d * d
+            })
+            .sum::<Decimal>()
+            / n;
+        (mean, variance.sqrt().unwrap_or(dec!(0)))
+    }
+
+    /// Simulate a proposed transfer through a zone and return a projected copy
+    /// of its economic state, without mutating the live zone. The projection
+    /// moves `amount` through the zone relative to its liquidity depth and
+    /// recomputes deviation, throttle factor, and health score.
+    pub async fn simulate_bridge_transaction(&self, zone_id: &str, amount: Decimal) -> Result<HealthCache> {
+        let zones = self.velocity_zones.read().await;
+        let zone = zones
+            .get(zone_id)
+            .ok_or_else(|| anyhow!("Velocity zone not found: {}", zone_id))?;
+
+        // A transfer's price impact scales inversely with liquidity depth.
+        let depth = zone.location_data.economic_indicators.liquidity_depth.max(dec!(1));
+        let impact = amount / depth;
+
+        // Buying pressure nudges deviation up and tightens throttling.
+        let projected_deviation = zone.stability_deviation + impact;
+        let projected_throttle_factor = zone.throttle_factor + impact;
+
+        let mut projected_indicators = zone.location_data.economic_indicators.clone();
+        projected_indicators.transaction_volume += amount;
+        projected_indicators.liquidity_depth = (depth - amount).max(dec!(0));
+        let projected_health_score = self.calculate_economic_health_score(&projected_indicators);
+
+        Ok(HealthCache {
+            zone_id: zone_id.to_string(),
+            projected_deviation,
+            projected_throttle_factor,
+            projected_health_score,
+        })
     }
 
     /// Calculate comprehensive velocity economics score for a zone
@@ -785,67 +2005,36 @@ impl BankingInteropBridge {
         })
     }
 
-    /// Calculate economic health score from indicators
+    /// Calculate economic health score from indicators, dispatching to the
+    /// bridge's active economic policy.
     fn calculate_economic_health_score(&self, indicators: &EconomicIndicators) -> Decimal {
-        // Gold price stability score (0-10) - closer to target is better
-        let gold_deviation = ((indicators.current_gold_price_usd - indicators.target_gold_price_usd) / indicators.target_gold_price_usd).abs();
-        let gold_score = (dec!(1) - gold_deviation).max(dec!(0)) * dec!(10);
-
-        // Market volatility score (inverse, 0-10) - lower is better
-        let volatility_score = (dec!(1) - indicators.market_volatility).max(dec!(0)) * dec!(10);
-
-        // Transaction volume score (0-10) - higher is better
-        let volume_score = (indicators.transaction_volume / dec!(1000000)).min(dec!(10));
-
-        // Liquidity depth score (0-10) - higher is better
-        let liquidity_score = (indicators.liquidity_depth / dec!(100000)).min(dec!(10));
-
-        // Weighted average (use existing fields)
-        let col_score = dec!(10); // Placeholder for cost of living
-
-        // Weighted average using available metrics
-        (gold_score * dec!(0.4) + volatility_score * dec!(0.3) + volume_score * dec!(0.2) + liquidity_score * dec!(0.1))
+        self.policy.economic_health_score(indicators)
     }
 
     /// Convert velocity score to letter grade
     fn score_to_grade(&self, score: Decimal) -> String {
-        if score >= dec!(85) { "A+".to_string() }
-        else if score >= dec!(80) { "A".to_string() }
-        else if score >= dec!(75) { "A-".to_string() }
-        else if score >= dec!(70) { "B+".to_string() }
-        else if score >= dec!(65) { "B".to_string() }
-        else if score >= dec!(60) { "B-".to_string() }
-        else if score >= dec!(55) { "C+".to_string() }
-        else if score >= dec!(50) { "C".to_string() }
-        else if score >= dec!(45) { "C-".to_string() }
-        else if score >= dec!(40) { "D".to_string() }
-        else { "F".to_string() }
-    }
-
-    /// Convert velocity score to recommended fee adjustment
+        // Grade cutoffs come from the active network profile.
+        self.profile
+            .grade_cutoffs
+            .iter()
+            .find(|(min_score, _)| score >= *min_score)
+            .map(|(_, grade)| grade.clone())
+            .unwrap_or_else(|| "F".to_string())
+    }
+
+    /// Convert velocity score to recommended fee adjustment, dispatching to the
+    /// active economic policy's fee table.
     fn score_to_fee_adjustment(&self, score: Decimal) -> Decimal {
-        // A+ zones get maximum discounts, F zones get premiums
-        if score >= dec!(85) { dec!(-0.008) }      // 0.8% discount
-        else if score >= dec!(75) { dec!(-0.006) } // 0.6% discount
-        else if score >= dec!(65) { dec!(-0.004) } // 0.4% discount
-        else if score >= dec!(55) { dec!(-0.002) } // 0.2% discount
-        else if score >= dec!(50) { dec!(0) }      // No adjustment
-        else if score >= dec!(40) { dec!(0.002) }  // 0.2% premium
-        else { dec!(0.005) }                       // 0.5% premium for F zones
+        self.policy.score_to_fee_adjustment(score)
     }
 
     /// Get dynamic exchange rate from oracles (NOT static pricing)
     async fn get_crypto_exchange_rate(&self, from: &str, to: &str) -> Result<Decimal> {
-        // Check cache first (with expiration check)
-        {
-            let rates = self.exchange_rates.read().await;
-            if let Some(from_rates) = rates.get(from) {
-                if let Some(&rate) = from_rates.get(to) {
-                    // In production, check if cached rate is still fresh (< 60 seconds)
-                    // For now, always fetch fresh rates to ensure dynamic pricing
-                    // return Ok(rate);
-                }
-            }
+        // Prefer the live feed: a fresh quote is authoritative, a stale one is an
+        // error (never serve a stale price), and an untracked pair falls through
+        // to the oracle derivation below.
+        if let Some(rate) = self.fresh_feed_rate(from, to).await? {
+            return Ok(rate);
         }
 
         // DYNAMIC ORACLE INTEGRATION (Production Implementation Required)
@@ -911,8 +2100,9 @@ impl BankingInteropBridge {
         Err(anyhow!("Caesar market price not implemented - requires DEX/CEX integration"))
     }
 
-    /// Default market stabilization zones for global economy
-    fn default_velocity_zones() -> HashMap<String, VelocityZone> {
+    /// Default market stabilization zones for global economy, with the gold
+    /// price target drawn from the active network profile.
+    fn default_velocity_zones(profile: &EconomicProfile) -> HashMap<String, VelocityZone> {
         let mut zones = HashMap::new();
 
         // Global Market Zones for Caesar-Gold Stabilization
@@ -1021,6 +2211,11 @@ impl BankingInteropBridge {
             },
         });
 
+        // Apply the network profile's gold target uniformly across zones.
+        for zone in zones.values_mut() {
+            zone.location_data.economic_indicators.target_gold_price_usd = profile.gold_target;
+        }
+
         zones
     }
 
@@ -1090,7 +2285,9 @@ impl BankingInteropBridge {
             .ok_or_else(|| anyhow!("Transaction not found"))
     }
 
-    /// List all active transactions
+    /// List all active transactions. Each record carries the `policy_version`
+    /// it was priced under, so callers auditing or recomputing a settlement can
+    /// dispatch to the exact rules in force at its creation.
     pub async fn list_active_transactions(&self) -> Result<Vec<InteropTransaction>> {
         let transactions = self.active_transactions.read().await;
         Ok(transactions.values().cloned().collect())
@@ -1111,13 +2308,305 @@ impl BankingInteropBridge {
     }
 }
 
+/// Configuration for the streaming exchange-rate feed.
+#[derive(Debug, Clone)]
+pub struct RateFeedConfig {
+    /// Upstream ticker WebSocket endpoint (Kraken-style public feed).
+    pub ws_url: String,
+    /// Pairs to subscribe to, in the upstream's `BASE/QUOTE` notation.
+    pub pairs: Vec<String>,
+    /// Reject rates whose last update is older than this.
+    pub max_rate_age: ChronoDuration,
+    /// Initial reconnect backoff after a socket close.
+    pub reconnect_base_delay: Duration,
+    /// Upper bound on the exponential reconnect backoff.
+    pub reconnect_max_delay: Duration,
+}
+
+impl Default for RateFeedConfig {
+    fn default() -> Self {
+        Self {
+            ws_url: "wss://ws.kraken.com".to_string(),
+            pairs: vec!["XBT/USD".to_string(), "ETH/USD".to_string()],
+            max_rate_age: ChronoDuration::seconds(60),
+            reconnect_base_delay: Duration::from_secs(1),
+            reconnect_max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Streaming exchange-rate feed that keeps the bridge's shared rate map fresh.
+///
+/// Subscribes to an upstream ticker channel and, for every ticker frame,
+/// derives the mid price from the best ask/bid and writes it into
+/// `exchange_rates` tagged with a timestamp. The socket auto-reconnects with
+/// exponential backoff, and heartbeat / status events are ignored.
+pub struct RateFeedProvider {
+    config: RateFeedConfig,
+    exchange_rates: Arc<RwLock<HashMap<String, HashMap<String, Decimal>>>>,
+    rate_timestamps: Arc<RwLock<HashMap<(String, String), DateTime<Utc>>>>,
+}
+
+impl RateFeedProvider {
+    /// Spawn the reconnecting read loop on the current Tokio runtime.
+    pub fn spawn(&self) {
+        let config = self.config.clone();
+        let exchange_rates = self.exchange_rates.clone();
+        let rate_timestamps = self.rate_timestamps.clone();
+
+        tokio::spawn(async move {
+            let mut backoff = config.reconnect_base_delay;
+            loop {
+                match Self::run_connection(&config, &exchange_rates, &rate_timestamps).await {
+                    Ok(()) => {
+                        debug!("Rate feed socket closed cleanly, reconnecting");
+                    }
+                    Err(e) => {
+                        warn!("Rate feed connection error: {}", e);
+                    }
+                }
+
+                tokio::time::sleep(backoff).await;
+                backoff = (backoff * 2).min(config.reconnect_max_delay);
+            }
+        });
+    }
+
+    /// Open a single connection, subscribe, and pump frames until the socket
+    /// closes or errors.
+    async fn run_connection(
+        config: &RateFeedConfig,
+        exchange_rates: &Arc<RwLock<HashMap<String, HashMap<String, Decimal>>>>,
+        rate_timestamps: &Arc<RwLock<HashMap<(String, String), DateTime<Utc>>>>,
+    ) -> Result<()> {
+        let (mut ws, _) = connect_async(&config.ws_url).await?;
+
+        // Subscribe to the ticker channel for the configured pairs.
+        let subscribe = serde_json::json!({
+            "event": "subscribe",
+            "pair": config.pairs,
+            "subscription": { "name": "ticker" },
+        });
+        ws.send(Message::Text(subscribe.to_string())).await?;
+
+        while let Some(msg) = ws.next().await {
+            let msg = msg?;
+            let text = match msg {
+                Message::Text(text) => text,
+                Message::Ping(payload) => {
+                    ws.send(Message::Pong(payload)).await?;
+                    continue;
+                }
+                Message::Close(_) => return Ok(()),
+                _ => continue,
+            };
+
+            if let Some((base, quote, mid)) = Self::parse_ticker(&text) {
+                Self::record_rate(exchange_rates, rate_timestamps, &base, &quote, mid).await;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parse a Kraken ticker frame `[channelId, {"a":[ask,..],"b":[bid,..]}, "ticker", "PAIR"]`
+    /// into `(base, quote, mid_price)`. Heartbeat and status events (which are
+    /// JSON objects, not arrays) return `None`.
+    fn parse_ticker(text: &str) -> Option<(String, String, Decimal)> {
+        let value: serde_json::Value = serde_json::from_str(text).ok()?;
+        let array = value.as_array()?;
+        if array.len() < 4 || array[2].as_str() != Some("ticker") {
+            return None;
+        }
+
+        let payload = array[1].as_object()?;
+        let ask = Self::first_level(payload.get("a")?)?;
+        let bid = Self::first_level(payload.get("b")?)?;
+        let mid = (ask + bid) / dec!(2);
+
+        let pair = array[3].as_str()?;
+        let (base, quote) = pair.split_once('/')?;
+        Some((base.to_string(), quote.to_string(), mid))
+    }
+
+    /// Extract the price (first element) from a `[price, ...]` ticker level.
+    fn first_level(level: &serde_json::Value) -> Option<Decimal> {
+        let price = level.as_array()?.first()?.as_str()?;
+        Decimal::from_str(price).ok()
+    }
+
+    async fn record_rate(
+        exchange_rates: &Arc<RwLock<HashMap<String, HashMap<String, Decimal>>>>,
+        rate_timestamps: &Arc<RwLock<HashMap<(String, String), DateTime<Utc>>>>,
+        base: &str,
+        quote: &str,
+        mid: Decimal,
+    ) {
+        {
+            let mut rates = exchange_rates.write().await;
+            rates
+                .entry(base.to_string())
+                .or_insert_with(HashMap::new)
+                .insert(quote.to_string(), mid);
+        }
+        let mut timestamps = rate_timestamps.write().await;
+        timestamps.insert((base.to_string(), quote.to_string()), Utc::now());
+    }
+}
+
+/// Parameters of a lending reserve's kinked utilization curve, mirroring the
+/// Solana token-lending reserve model.
+#[derive(Debug, Clone)]
+pub struct ReserveConfig {
+    pub min_borrow_rate: Decimal,
+    pub optimal_borrow_rate: Decimal,
+    pub max_borrow_rate: Decimal,
+    pub optimal_utilization: Decimal,
+    /// Accrual period the `cumulative_borrow_rate` compounds over (annualised).
+    pub accrual_period: ChronoDuration,
+}
+
+impl Default for ReserveConfig {
+    fn default() -> Self {
+        Self {
+            min_borrow_rate: dec!(0.01),
+            optimal_borrow_rate: dec!(0.08),
+            max_borrow_rate: dec!(0.30),
+            optimal_utilization: dec!(0.80),
+            accrual_period: ChronoDuration::days(365),
+        }
+    }
+}
+
+/// A lending reserve holding deposited liquidity and outstanding borrows, with
+/// a utilization-driven borrow rate and a compounding cumulative index.
+#[derive(Debug, Clone)]
+pub struct LendingReserve {
+    pub config: ReserveConfig,
+    pub available_liquidity: Decimal,
+    pub borrowed_amount: Decimal,
+    /// Compounding interest index; the amount owed on a position (principal
+    /// plus accrued interest) equals
+    /// `principal * (cumulative_now / cumulative_at_borrow)`.
+    pub cumulative_borrow_rate: Decimal,
+    last_accrued: DateTime<Utc>,
+}
+
+impl LendingReserve {
+    pub fn new(config: ReserveConfig) -> Self {
+        Self {
+            config,
+            available_liquidity: dec!(0),
+            borrowed_amount: dec!(0),
+            cumulative_borrow_rate: dec!(1),
+            last_accrued: Utc::now(),
+        }
+    }
+
+    /// Current utilization `borrowed / (available + borrowed)`, 0 when empty.
+    pub fn utilization(&self) -> Decimal {
+        let total = self.available_liquidity + self.borrowed_amount;
+        if total.is_zero() {
+            dec!(0)
+        } else {
+            self.borrowed_amount / total
+        }
+    }
+
+    /// Piecewise-linear current borrow rate along the kinked curve.
+    pub fn current_borrow_rate(&self) -> Decimal {
+        let util = self.utilization();
+        let cfg = &self.config;
+        if util <= cfg.optimal_utilization {
+            if cfg.optimal_utilization.is_zero() {
+                return cfg.min_borrow_rate;
+            }
+            let slope = (cfg.optimal_borrow_rate - cfg.min_borrow_rate) / cfg.optimal_utilization;
+            cfg.min_borrow_rate + slope * util
+        } else {
+            let span = dec!(1) - cfg.optimal_utilization;
+            if span.is_zero() {
+                return cfg.max_borrow_rate;
+            }
+            let slope = (cfg.max_borrow_rate - cfg.optimal_borrow_rate) / span;
+            cfg.optimal_borrow_rate + slope * (util - cfg.optimal_utilization)
+        }
+    }
+
+    /// Add deposited liquidity to the reserve.
+    pub fn deposit(&mut self, amount: Decimal) {
+        self.available_liquidity += amount;
+    }
+
+    /// Draw `amount` from available liquidity, returning the cumulative index at
+    /// which the borrow was opened for later interest settlement.
+    pub fn borrow(&mut self, amount: Decimal) -> Result<Decimal> {
+        if amount > self.available_liquidity {
+            return Err(anyhow!("Insufficient reserve liquidity"));
+        }
+        self.available_liquidity -= amount;
+        self.borrowed_amount += amount;
+        Ok(self.cumulative_borrow_rate)
+    }
+
+    /// Repay `amount` of outstanding principal back into available liquidity.
+    pub fn repay(&mut self, amount: Decimal) {
+        let repaid = amount.min(self.borrowed_amount);
+        self.borrowed_amount -= repaid;
+        self.available_liquidity += repaid;
+    }
+
+    /// Compound the cumulative index for the elapsed fraction of the accrual
+    /// period at the current borrow rate.
+    pub fn accrue_interest(&mut self) {
+        let now = Utc::now();
+        let elapsed = (now - self.last_accrued).num_seconds();
+        let period = self.config.accrual_period.num_seconds();
+        if elapsed <= 0 || period <= 0 {
+            return;
+        }
+        let fraction = Decimal::from(elapsed) / Decimal::from(period);
+        self.cumulative_borrow_rate *= dec!(1) + self.current_borrow_rate() * fraction;
+        self.last_accrued = now;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// A minimal crypto-to-fiat record stamped with a given policy version, for
+    /// exercising the audit/replay path without standing up providers.
+    fn make_transaction(amount: Decimal, policy_version: PolicyVersion) -> InteropTransaction {
+        InteropTransaction {
+            transaction_id: "TEST".to_string(),
+            bridge_type: BridgeType::CryptoToFiat,
+            source_asset: AssetType::Crypto { symbol: "BTC".to_string(), chain: "bitcoin".to_string() },
+            destination_asset: AssetType::Fiat { currency: "USD".to_string() },
+            amount,
+            source_provider: "test".to_string(),
+            destination_provider: "test".to_string(),
+            exchange_rate: dec!(1),
+            fees: BridgeFees {
+                network_fee: dec!(0),
+                provider_fee: dec!(0),
+                bridge_fee: dec!(0),
+                velocity_adjustment: dec!(0),
+                total_fee: dec!(0),
+            },
+            status: InteropStatus::Completed,
+            velocity_zone: None,
+            contract_reference: None,
+            timestamp: Utc::now(),
+            completion_time: None,
+            metadata: HashMap::new(),
+            policy_version,
+        }
+    }
+
     #[tokio::test]
     async fn test_market_stabilization_adjustment() {
-        let bridge = BankingInteropBridge::new();
+        let bridge = BankingInteropBridge::new(Network::Mainnet);
 
         // Test primary market (8% above gold) - should apply throttling
         let adjustment = bridge.calculate_velocity_adjustment(Some("global_primary"), dec!(1000)).await.unwrap();
@@ -1142,7 +2631,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_velocity_score_calculation() {
-        let bridge = BankingInteropBridge::new();
+        let bridge = BankingInteropBridge::new(Network::Mainnet);
 
         // Test San Francisco should get A+ grade
         let score = bridge.calculate_velocity_score("san_francisco").await.unwrap();
@@ -1159,7 +2648,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_gold_price_adjustment_calculation() {
-        let bridge = BankingInteropBridge::new();
+        let bridge = BankingInteropBridge::new(Network::Mainnet);
 
         // Market above gold price (should throttle)
         let above_gold_indicators = EconomicIndicators {
@@ -1197,7 +2686,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_time_velocity_bonus() {
-        let bridge = BankingInteropBridge::new();
+        let bridge = BankingInteropBridge::new(Network::Mainnet);
 
         // High velocity zones get time bonuses
         let bonus = bridge.calculate_time_velocity_bonus("san_francisco", dec!(1000)).await;
@@ -1210,7 +2699,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_network_effects_bonus() {
-        let bridge = BankingInteropBridge::new();
+        let bridge = BankingInteropBridge::new(Network::Mainnet);
 
         // High density areas get network bonuses
         let bonus = bridge.calculate_network_effects_bonus("san_francisco", dec!(1000)).await;
@@ -1221,9 +2710,104 @@ mod tests {
         assert_eq!(bonus, dec!(0), "Low density areas should not get network bonus");
     }
 
+    #[test]
+    fn test_parse_ticker_frame() {
+        // A Kraken ticker frame yields the mid of best ask and bid.
+        let frame = r#"[340,{"a":["30010.5","1","1.0"],"b":["29990.5","2","2.0"]},"ticker","XBT/USD"]"#;
+        let (base, quote, mid) = RateFeedProvider::parse_ticker(frame).unwrap();
+        assert_eq!(base, "XBT");
+        assert_eq!(quote, "USD");
+        assert_eq!(mid, dec!(30000.5));
+
+        // Status / heartbeat events are JSON objects, not ticker arrays.
+        assert!(RateFeedProvider::parse_ticker(r#"{"event":"heartbeat"}"#).is_none());
+        assert!(
+            RateFeedProvider::parse_ticker(r#"{"event":"systemStatus","status":"online"}"#)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_network_profiles_hold_invariants() {
+        // Invariants must hold for every network profile, not just mainnet.
+        for network in Network::iter() {
+            let bridge = BankingInteropBridge::new(network);
+
+            // Fee-to-grade mapping is monotonic: a higher score never pays more.
+            let mut last_fee = bridge.score_to_fee_adjustment(dec!(100));
+            for score in [dec!(90), dec!(70), dec!(50), dec!(30)] {
+                let fee = bridge.score_to_fee_adjustment(score);
+                assert!(fee >= last_fee, "fee must be non-decreasing as score drops");
+                last_fee = fee;
+            }
+
+            // The profile's gold target is applied to every default zone.
+            let profile = EconomicProfile::for_network(network);
+            let zones = BankingInteropBridge::default_velocity_zones(&profile);
+            for zone in zones.values() {
+                assert_eq!(
+                    zone.location_data.economic_indicators.target_gold_price_usd,
+                    profile.gold_target
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_spread_and_fee_cap() {
+        let bridge = BankingInteropBridge::new(Network::Mainnet);
+
+        // A buy crosses up by the half-spread; a sell crosses down.
+        assert_eq!(bridge.apply_spread(dec!(100), true), dec!(100.200));
+        assert_eq!(bridge.apply_spread(dec!(100), false), dec!(99.800));
+
+        // Fees are capped at 3% of notional (the binding relative cap here).
+        let (capped, cap) = bridge.cap_total_fee(dec!(1000), dec!(500));
+        assert_eq!(cap, dec!(30));
+        assert_eq!(capped, dec!(30));
+
+        // A fee already under the cap is left untouched.
+        let (capped, _) = bridge.cap_total_fee(dec!(1000), dec!(12));
+        assert_eq!(capped, dec!(12));
+    }
+
+    #[test]
+    fn test_token_amount_hex_or_decimal() {
+        // Decimal-string and 0x-hex JSON both deserialize to the same wei value.
+        let from_dec: TokenAmount = serde_json::from_str("\"1000000000000000000\"").unwrap();
+        let from_hex: TokenAmount = serde_json::from_str("\"0xde0b6b3a7640000\"").unwrap();
+        assert_eq!(from_dec.wei.low_u128(), 1_000_000_000_000_000_000);
+        assert_eq!(from_dec, from_hex);
+
+        // 1.5 tokens at 18 decimals round-trips through the decimal helpers.
+        let amount = TokenAmount::from_decimal(dec!(1.5), 18).unwrap();
+        assert_eq!(amount.wei.low_u128(), 1_500_000_000_000_000_000);
+        assert_eq!(amount.to_decimal().unwrap(), dec!(1.5));
+    }
+
+    #[test]
+    fn test_reserve_borrow_rate_curve() {
+        let mut reserve = LendingReserve::new(ReserveConfig::default());
+        assert_eq!(reserve.current_borrow_rate(), dec!(0.01)); // empty -> min rate
+
+        // 40% utilization sits partway up the lower limb (optimal at 80%).
+        reserve.deposit(dec!(1000));
+        reserve.borrow(dec!(400)).unwrap();
+        assert_eq!(reserve.utilization(), dec!(0.4));
+        assert_eq!(reserve.current_borrow_rate(), dec!(0.045));
+
+        // At the kink the rate is exactly the optimal rate.
+        reserve.repay(dec!(400));
+        reserve.borrow(dec!(800)).unwrap();
+        assert_eq!(reserve.utilization(), dec!(0.8));
+        assert_eq!(reserve.current_borrow_rate(), dec!(0.08));
+    }
+
     #[test]
     fn test_global_market_stabilization_zones() {
-        let zones = BankingInteropBridge::default_velocity_zones();
+        let zones = BankingInteropBridge::default_velocity_zones(
+            &EconomicProfile::for_network(Network::Mainnet),
+        );
 
         // Verify all market stabilization zones exist
         assert!(zones.contains_key("global_primary"));
@@ -1254,7 +2838,7 @@ mod tests {
 
     #[test]
     fn test_score_to_grade_conversion() {
-        let bridge = BankingInteropBridge::new();
+        let bridge = BankingInteropBridge::new(Network::Mainnet);
 
         assert_eq!(bridge.score_to_grade(dec!(90)), "A+");
         assert_eq!(bridge.score_to_grade(dec!(82)), "A");
@@ -1267,7 +2851,7 @@ mod tests {
 
     #[test]
     fn test_fee_adjustment_ranges() {
-        let bridge = BankingInteropBridge::new();
+        let bridge = BankingInteropBridge::new(Network::Mainnet);
 
         // A+ zones get maximum discount
         let adjustment = bridge.score_to_fee_adjustment(dec!(90));
@@ -1282,9 +2866,64 @@ mod tests {
         assert_eq!(adjustment, dec!(0));
     }
 
+    #[test]
+    fn test_policy_version_default_and_monotonic() {
+        let bridge = BankingInteropBridge::new(Network::Mainnet);
+        // New bridges price under V1 so historical behavior is preserved.
+        assert_eq!(bridge.active_policy_version(), PolicyVersion::V1);
+
+        // Every version's fee table stays monotonic in score (worse score
+        // never pays a smaller premium than a better one).
+        let profile = EconomicProfile::for_network(Network::Mainnet);
+        for version in [PolicyVersion::V1, PolicyVersion::V2] {
+            let policy = policy_for_version(version, &profile);
+            let mut last = policy.score_to_fee_adjustment(dec!(100));
+            for score in [dec!(90), dec!(70), dec!(50), dec!(30)] {
+                let fee = policy.score_to_fee_adjustment(score);
+                assert!(fee >= last, "fee must not decrease as score worsens");
+                last = fee;
+            }
+        }
+    }
+
+    #[test]
+    fn test_policy_version_governs_replay() {
+        let mut bridge = BankingInteropBridge::new(Network::Mainnet);
+        let indicators = EconomicIndicators {
+            current_gold_price_usd: dec!(90),
+            target_gold_price_usd: dec!(84),
+            market_volatility: dec!(0.2),
+            transaction_volume: dec!(2_000_000),
+            liquidity_depth: dec!(500_000),
+        };
+
+        // A record created under the active V1 policy is stamped V1 and must
+        // keep replaying against V1 even after governance rolls forward to V2.
+        let mut tx = make_transaction(dec!(1000), PolicyVersion::V1);
+        let under_v1 = bridge.recompute_adjustment(&tx, &indicators);
+
+        bridge.set_policy_version(PolicyVersion::V2);
+        assert_eq!(bridge.active_policy_version(), PolicyVersion::V2);
+        assert_eq!(
+            bridge.recompute_adjustment(&tx, &indicators),
+            under_v1,
+            "stamped version must pin historical pricing"
+        );
+
+        // The conversion layer previews what V2 would charge; V2 throttles
+        // high volume harder, so the repriced adjustment differs from V1's.
+        let (historical, repriced) = bridge.reprice_adjustment(&tx, PolicyVersion::V2, &indicators);
+        assert_eq!(historical, under_v1);
+        assert_ne!(historical, repriced);
+
+        // A record stamped V2 replays against V2 and matches that preview.
+        tx.policy_version = PolicyVersion::V2;
+        assert_eq!(bridge.recompute_adjustment(&tx, &indicators), repriced);
+    }
+
     #[tokio::test]
     async fn test_bridge_transaction_creation() {
-        let bridge = BankingInteropBridge::new();
+        let bridge = BankingInteropBridge::new(Network::Mainnet);
 
         // Test that transaction records are created properly
         let transactions = bridge.list_active_transactions().await.unwrap();
@@ -1296,7 +2935,7 @@ mod tests {
 
     #[test]
     fn test_economic_health_score() {
-        let bridge = BankingInteropBridge::new();
+        let bridge = BankingInteropBridge::new(Network::Mainnet);
 
         // Perfect economic indicators
         let perfect_indicators = EconomicIndicators {
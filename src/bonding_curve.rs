@@ -0,0 +1,263 @@
+//! Caesar Bonding Curve - deterministic, supply-sensitive CSR issuance
+//!
+//! Unlike [`crate::exchange::ExchangeEngine`]'s constant-product/StableSwap
+//! pools, which trade CSR already in circulation against a flat liquidity
+//! pool, this module prices the *primary issuance* of CSR itself: buying
+//! mints new CSR against a deposit, and selling burns CSR and returns the
+//! reserve backing it. The reserve invariant (`reserve == curve.reserve(supply)`)
+//! is maintained by construction — every mint/burn moves `supply` and
+//! `reserve` along the same curve, so the two can never drift apart.
+
+use anyhow::{anyhow, Result};
+use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
+use rust_decimal_macros::dec;
+use std::sync::Arc;
+
+use crate::storage::CaesarStorage;
+use crate::{BondingCurveConfig, BondingCurveKind};
+
+/// Upper bound on Newton iterations for curves with no closed-form inverse.
+const CURVE_MAX_ITERS: u32 = 255;
+
+/// Convergence threshold for the Newton solvers below.
+const CURVE_EPSILON: Decimal = dec!(0.000001);
+
+/// Spot price and cumulative reserve for a primary-issuance bonding curve.
+///
+/// `reserve` must be the definite integral of `price` from `0` to `supply`
+/// for the reserve invariant (`reserve(total_supply) == held reserve`) to
+/// hold; `supply_for_reserve` is `reserve`'s inverse, used to size a buy.
+pub trait Curve: Send + Sync {
+    /// Marginal price (reserve tokens per CSR) at `supply` CSR outstanding.
+    fn price(&self, supply: Decimal) -> Decimal;
+
+    /// Total reserve backing `supply` outstanding CSR.
+    fn reserve(&self, supply: Decimal) -> Decimal;
+
+    /// The supply whose reserve requirement is exactly `reserve`. Errs if the
+    /// curve is degenerate (e.g. zero price) and has no such supply.
+    fn supply_for_reserve(&self, reserve: Decimal) -> Result<Decimal>;
+}
+
+/// Flat price regardless of supply; `reserve` grows linearly.
+pub struct Constant {
+    pub value: Decimal,
+}
+
+impl Curve for Constant {
+    fn price(&self, _supply: Decimal) -> Decimal {
+        self.value
+    }
+
+    fn reserve(&self, supply: Decimal) -> Decimal {
+        self.value * supply
+    }
+
+    fn supply_for_reserve(&self, reserve: Decimal) -> Result<Decimal> {
+        if self.value.is_zero() {
+            return Err(anyhow!("Constant curve has zero price"));
+        }
+        Ok(reserve / self.value)
+    }
+}
+
+/// Price rises linearly with supply: `min_price + slope * supply`.
+pub struct Linear {
+    pub slope: Decimal,
+    pub min_price: Decimal,
+}
+
+impl Curve for Linear {
+    fn price(&self, supply: Decimal) -> Decimal {
+        self.min_price + self.slope * supply
+    }
+
+    fn reserve(&self, supply: Decimal) -> Decimal {
+        self.min_price * supply + self.slope * supply * supply / dec!(2)
+    }
+
+    /// Solves `slope/2 * s^2 + min_price*s - reserve = 0` for the positive
+    /// root via the quadratic formula.
+    fn supply_for_reserve(&self, reserve: Decimal) -> Result<Decimal> {
+        if self.slope.is_zero() {
+            if self.min_price.is_zero() {
+                return Err(anyhow!("Linear curve has zero slope and zero min_price"));
+            }
+            return Ok(reserve / self.min_price);
+        }
+
+        let discriminant = self.min_price * self.min_price + dec!(2) * self.slope * reserve;
+        if discriminant < dec!(0) {
+            return Err(anyhow!("Linear curve reserve has no real solution"));
+        }
+        let root = discriminant
+            .sqrt()
+            .ok_or_else(|| anyhow!("Linear curve discriminant sqrt failed"))?;
+
+        Ok((root - self.min_price) / self.slope)
+    }
+}
+
+/// Price rises with the square root of supply: `scale * sqrt(supply)`.
+pub struct SquareRoot {
+    pub scale: Decimal,
+}
+
+impl Curve for SquareRoot {
+    fn price(&self, supply: Decimal) -> Decimal {
+        self.scale * supply.sqrt().unwrap_or(dec!(0))
+    }
+
+    fn reserve(&self, supply: Decimal) -> Decimal {
+        let root = supply.sqrt().unwrap_or(dec!(0));
+        dec!(2) / dec!(3) * self.scale * supply * root
+    }
+
+    /// `reserve = (2/3)*scale*s^1.5` has no closed-form inverse over
+    /// `Decimal`, so this Newton-solves `f(s) = (2/3)*scale*s*sqrt(s) -
+    /// reserve = 0`, the same fixed-point approach the AMM's StableSwap
+    /// invariant uses in [`crate::exchange`].
+    fn supply_for_reserve(&self, reserve: Decimal) -> Result<Decimal> {
+        if self.scale.is_zero() {
+            return Err(anyhow!("SquareRoot curve has zero scale"));
+        }
+        if reserve.is_zero() {
+            return Ok(dec!(0));
+        }
+
+        let mut s = reserve;
+        for _ in 0..CURVE_MAX_ITERS {
+            let root = s
+                .sqrt()
+                .ok_or_else(|| anyhow!("SquareRoot curve solve left negative supply"))?;
+            let f = dec!(2) / dec!(3) * self.scale * s * root - reserve;
+            let f_prime = self.scale * root;
+            if f_prime.is_zero() {
+                return Err(anyhow!("SquareRoot curve solve stalled at zero derivative"));
+            }
+            let next = s - f / f_prime;
+            if (next - s).abs() <= CURVE_EPSILON {
+                return Ok(next);
+            }
+            s = next;
+        }
+
+        Err(anyhow!("SquareRoot curve reserve solve failed to converge"))
+    }
+}
+
+fn build_curve(kind: &BondingCurveKind) -> Box<dyn Curve> {
+    match kind {
+        BondingCurveKind::Constant { value } => Box::new(Constant { value: *value }),
+        BondingCurveKind::Linear { slope, min_price } => Box::new(Linear { slope: *slope, min_price: *min_price }),
+        BondingCurveKind::SquareRoot { scale } => Box::new(SquareRoot { scale: *scale }),
+    }
+}
+
+/// Mints and burns CSR against a configured [`Curve`], persisting the
+/// resulting `(supply, reserve)` pair through `storage` on every trade so
+/// the invariant survives a restart. Holds no in-memory cache of its own,
+/// mirroring `StakingManager`'s fully storage-backed design.
+pub struct BondingCurveEngine {
+    config: BondingCurveConfig,
+    curve: Box<dyn Curve>,
+    storage: Arc<CaesarStorage>,
+}
+
+impl BondingCurveEngine {
+    pub fn new(config: BondingCurveConfig, storage: Arc<CaesarStorage>) -> Self {
+        let curve = build_curve(&config.curve);
+        Self { config, curve, storage }
+    }
+
+    /// Current `(supply, reserve)`, `(0, 0)` before the first trade.
+    async fn state(&self) -> Result<(Decimal, Decimal)> {
+        Ok(self
+            .storage
+            .get_bonding_curve_state()
+            .await?
+            .unwrap_or((dec!(0), dec!(0))))
+    }
+
+    /// Curve state and spot price, without trading.
+    pub async fn info(&self) -> Result<crate::models::BondingCurveInfo> {
+        let (supply, reserve) = self.state().await?;
+        Ok(crate::models::BondingCurveInfo { supply, reserve, spot_price: self.curve.price(supply) })
+    }
+
+    /// Deposit `deposit` reserve tokens, minting the CSR they buy at the
+    /// curve's current price, and credit `wallet_id`'s balance with it.
+    pub async fn buy(&self, wallet_id: &str, deposit: Decimal) -> Result<crate::models::BondingCurveTradeResponse> {
+        if deposit <= dec!(0) {
+            return Err(anyhow!("Deposit must be positive"));
+        }
+
+        let (supply, reserve) = self.state().await?;
+        let new_reserve = reserve + deposit;
+        let new_supply = self.curve.supply_for_reserve(new_reserve)?;
+        if new_supply <= supply {
+            return Err(anyhow!("Deposit too small to mint any CSR at the current price"));
+        }
+        let minted = new_supply - supply;
+
+        self.storage.set_bonding_curve_state(new_supply, new_reserve).await?;
+
+        let balance = self.storage.get_balance(wallet_id).await?;
+        self.storage.update_balance(wallet_id, balance + minted).await?;
+
+        Ok(crate::models::BondingCurveTradeResponse {
+            wallet_id: wallet_id.to_string(),
+            csr_amount: minted,
+            reserve_amount: deposit,
+            fee: dec!(0),
+            price: self.curve.price(new_supply),
+            supply: new_supply,
+            reserve: new_reserve,
+        })
+    }
+
+    /// Burn `amount` CSR from `wallet_id`'s balance and return the reserve it
+    /// backs, less the configured exit fee (routed to `fee_wallet`, if set).
+    pub async fn sell(&self, wallet_id: &str, amount: Decimal) -> Result<crate::models::BondingCurveTradeResponse> {
+        if amount <= dec!(0) {
+            return Err(anyhow!("Sell amount must be positive"));
+        }
+
+        let (supply, reserve) = self.state().await?;
+        if amount > supply {
+            return Err(anyhow!("Cannot sell more CSR than the curve has issued"));
+        }
+
+        let balance = self.storage.get_balance(wallet_id).await?;
+        if balance < amount {
+            return Err(anyhow!("Insufficient balance to sell {} CSR", amount));
+        }
+
+        let new_supply = supply - amount;
+        let new_reserve = self.curve.reserve(new_supply);
+        let gross_payout = reserve - new_reserve;
+        let fee = gross_payout * self.config.exit_fee;
+        let net_payout = gross_payout - fee;
+
+        self.storage.set_bonding_curve_state(new_supply, new_reserve).await?;
+        self.storage.update_balance(wallet_id, balance - amount).await?;
+
+        if !fee.is_zero() {
+            if let Some(fee_wallet) = &self.config.fee_wallet {
+                let fee_balance = self.storage.get_balance(fee_wallet).await.unwrap_or(dec!(0));
+                self.storage.update_balance(fee_wallet, fee_balance + fee).await?;
+            }
+        }
+
+        Ok(crate::models::BondingCurveTradeResponse {
+            wallet_id: wallet_id.to_string(),
+            csr_amount: amount,
+            reserve_amount: net_payout,
+            fee,
+            price: self.curve.price(new_supply),
+            supply: new_supply,
+            reserve: new_reserve,
+        })
+    }
+}
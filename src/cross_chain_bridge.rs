@@ -10,15 +10,19 @@
 //! - Self-stabilizing economic mechanisms
 
 use anyhow::{Result, anyhow};
+use async_trait::async_trait;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use tracing::{info, warn, error};
 use uuid::Uuid;
 
+use crate::storage::CaesarStorage;
+
 /// Supported blockchain networks for cross-chain operations
 #[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub enum NetworkType {
@@ -79,6 +83,12 @@ pub enum BridgeOperation {
         network: NetworkType,
         recipient: String,
         source_tx: String,
+        /// Source chain the lock + InInstruction events were read from.
+        from_network: NetworkType,
+        /// Source-chain block those events were read at, so
+        /// `verify_in_instruction` checks a fixed point rather than racing
+        /// a reorg. See [`CrossChainBridge::verify_in_instruction`].
+        block_hash: String,
     },
     /// Burn tokens to unlock on source
     Burn {
@@ -121,6 +131,838 @@ pub struct BridgeTransaction {
     pub destination_tx_hash: Option<String>,
     pub confirmations: u32,
     pub required_confirmations: u32,
+    /// This transaction's position on its `MessageLane`, for operations
+    /// with a clear directed network pair (`Lock`, `Mint`). `None` for
+    /// operations not yet wired into the lane (`Burn`, `Unlock`).
+    pub lane_nonce: Option<LaneNonce>,
+}
+
+/// Identifies the active [`Eventuality`] watching a given bridge transaction.
+/// Keyed to the `BridgeTransaction::id` it will resolve.
+pub type EventualityId = String;
+
+/// An event observed on a destination chain by a per-network watcher. Fed
+/// into every active [`Eventuality`] to see whether it resolves one.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ChainEvent {
+    pub network: NetworkType,
+    pub event_type: ChainEventType,
+    pub amount: Decimal,
+    pub recipient: String,
+    /// Nonce tying this event back to the originating `Lock`, e.g. the
+    /// source bridge transaction id. Hashed together with amount/recipient
+    /// to form a [`Claim`]'s `nonce_hash` rather than trusting a raw tx hash.
+    pub nonce: String,
+    pub tx_hash: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChainEventType {
+    Minted,
+    Unlocked,
+}
+
+/// A compact identifier for what resolved an [`Eventuality`]: the
+/// minted/unlocked amount, recipient, and a hash of the nonce, rather than
+/// a transaction id. Comparing claims (instead of tx hashes) is what lets
+/// a resubmitted or reorged destination tx still resolve the same
+/// eventuality under a different `tx_hash`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Claim {
+    pub amount: Decimal,
+    pub recipient: String,
+    pub nonce_hash: String,
+    pub tx_hash: String,
+}
+
+/// Something that can confirm a bridge transaction resolved on the
+/// destination chain without fetching its full transaction. `claim` turns
+/// a raw [`ChainEvent`] into a [`Claim`] if the event is even plausibly
+/// relevant (same network/recipient); `matches` then checks whether that
+/// claim is the one this eventuality was actually waiting for, or a
+/// conflicting claim that should revert it instead.
+pub trait Eventuality: Send + Sync {
+    fn claim(&self, event: &ChainEvent) -> Option<Claim>;
+    fn matches(&self, claim: &Claim) -> bool;
+}
+
+/// The eventuality created when a `Lock` is initiated: we expect the paired
+/// `Mint` to eventually land on `network` for `expected_recipient`.
+pub struct MintEventuality {
+    pub network: NetworkType,
+    pub expected_amount: Decimal,
+    pub expected_recipient: String,
+    pub expected_nonce_hash: String,
+}
+
+impl Eventuality for MintEventuality {
+    fn claim(&self, event: &ChainEvent) -> Option<Claim> {
+        if event.network != self.network || event.event_type != ChainEventType::Minted {
+            return None;
+        }
+        if event.recipient != self.expected_recipient {
+            return None;
+        }
+        Some(Claim {
+            amount: event.amount,
+            recipient: event.recipient.clone(),
+            nonce_hash: claim_nonce_hash(event.amount, &event.recipient, &event.nonce),
+            tx_hash: event.tx_hash.clone(),
+        })
+    }
+
+    fn matches(&self, claim: &Claim) -> bool {
+        claim.amount == self.expected_amount && claim.nonce_hash == self.expected_nonce_hash
+    }
+}
+
+/// Hash of `amount:recipient:nonce`, hex-encoded. Used as a [`Claim`]'s
+/// compact identifier instead of a transaction id.
+fn claim_nonce_hash(amount: Decimal, recipient: &str, nonce: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(format!("{amount}:{recipient}:{nonce}").as_bytes());
+    hex::encode(hasher.finalize())
+}
+
+/// The eventuality registered when the scheduler hands a nonce to an
+/// outbound `Mint`/`Unlock` payout: confirms the payout itself actually
+/// landed, so the signing key holding that nonce can eventually report
+/// empty. Unlike [`MintEventuality`] (which watches for a *paired*
+/// operation elsewhere), this watches for the operation's own effect.
+pub struct PayoutEventuality {
+    pub network: NetworkType,
+    pub expected_event_type: ChainEventType,
+    pub expected_amount: Decimal,
+    pub expected_recipient: String,
+    pub expected_nonce_hash: String,
+}
+
+impl Eventuality for PayoutEventuality {
+    fn claim(&self, event: &ChainEvent) -> Option<Claim> {
+        if event.network != self.network || event.event_type != self.expected_event_type {
+            return None;
+        }
+        if event.recipient != self.expected_recipient {
+            return None;
+        }
+        Some(Claim {
+            amount: event.amount,
+            recipient: event.recipient.clone(),
+            nonce_hash: claim_nonce_hash(event.amount, &event.recipient, &event.nonce),
+            tx_hash: event.tx_hash.clone(),
+        })
+    }
+
+    fn matches(&self, claim: &Claim) -> bool {
+        claim.amount == self.expected_amount && claim.nonce_hash == self.expected_nonce_hash
+    }
+}
+
+/// Opaque id returned by [`Scheduler::schedule`] for a queued payout.
+pub type ScheduledId = String;
+
+/// A transaction sequenced by a [`Scheduler`], ready to be signed and
+/// broadcast in nonce order.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SignedTx {
+    pub scheduled_id: ScheduledId,
+    pub network: NetworkType,
+    pub signing_key_id: String,
+    pub nonce: u64,
+    pub recipient: String,
+    pub amount: Decimal,
+}
+
+/// Owns outbound transaction sequencing for one [`NetworkType`], so
+/// concurrent `Mint`/`Unlock` operations targeting the same network don't
+/// race on nonces.
+#[async_trait]
+pub trait Scheduler: Send + Sync {
+    /// Queue `operation` as an outbound payout, assigning it a nonce.
+    /// Rejects anything other than `Mint`/`Unlock`, and payouts to
+    /// internal/change addresses (to avoid self-referential loops).
+    async fn schedule(&self, operation: BridgeOperation) -> Result<ScheduledId>;
+
+    /// Flush every queued payout in nonce order, clearing the queue.
+    async fn plan(&self) -> Vec<SignedTx>;
+}
+
+/// Per-signing-key bookkeeping: the next nonce to hand out, whether the key
+/// still accepts new work, and which already-assigned nonces haven't
+/// resolved their eventuality yet.
+struct SigningKeyState {
+    next_nonce: u64,
+    accepting_new_work: bool,
+    outstanding_nonces: HashSet<u64>,
+}
+
+/// `Scheduler` for account-based chains (Ethereum, Polygon, Arbitrum, and
+/// other nonce-ordered networks). Maintains a monotonically increasing
+/// nonce counter per signing key and queues payouts for [`Scheduler::plan`]
+/// to flush in nonce order.
+pub struct AccountScheduler {
+    network: NetworkType,
+    active_key_id: RwLock<String>,
+    keys: RwLock<HashMap<String, SigningKeyState>>,
+    queue: RwLock<Vec<SignedTx>>,
+    /// Key/nonce assigned to each `ScheduledId`, so a caller that only gets
+    /// a `ScheduledId` back from `schedule` can still look up what nonce it
+    /// landed on (e.g. to register a [`PayoutEventuality`] against it).
+    assignments: RwLock<HashMap<ScheduledId, (String, u64)>>,
+}
+
+impl AccountScheduler {
+    pub fn new(network: NetworkType, initial_key_id: impl Into<String>) -> Self {
+        let mut keys = HashMap::new();
+        keys.insert(
+            initial_key_id.into(),
+            SigningKeyState {
+                next_nonce: 0,
+                accepting_new_work: true,
+                outstanding_nonces: HashSet::new(),
+            },
+        );
+        let active_key_id = keys.keys().next().cloned().expect("just inserted");
+        Self {
+            network,
+            active_key_id: RwLock::new(active_key_id),
+            keys: RwLock::new(keys),
+            queue: RwLock::new(Vec::new()),
+            assignments: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Activate `new_key_id`: new work is assigned nonces under it, while
+    /// the previously active key keeps the nonces it already handed out and
+    /// stops accepting new work. Call `key_is_empty` on the old key id to
+    /// find out when it's fully retired.
+    pub async fn rotate_key(&self, new_key_id: impl Into<String>) {
+        let mut keys = self.keys.write().await;
+        let mut active = self.active_key_id.write().await;
+        if let Some(old) = keys.get_mut(active.as_str()) {
+            old.accepting_new_work = false;
+        }
+        let new_key_id = new_key_id.into();
+        keys.entry(new_key_id.clone()).or_insert_with(|| SigningKeyState {
+            next_nonce: 0,
+            accepting_new_work: true,
+            outstanding_nonces: HashSet::new(),
+        });
+        *active = new_key_id;
+    }
+
+    /// Look up the key/nonce a previously returned `ScheduledId` landed on.
+    pub async fn nonce_for(&self, scheduled_id: &str) -> Option<(String, u64)> {
+        self.assignments.read().await.get(scheduled_id).cloned()
+    }
+
+    /// Mark the nonce assigned to `key_id` as resolved, e.g. because the
+    /// payout's `PayoutEventuality` completed or reverted.
+    pub async fn resolve_nonce(&self, key_id: &str, nonce: u64) {
+        if let Some(key) = self.keys.write().await.get_mut(key_id) {
+            key.outstanding_nonces.remove(&nonce);
+        }
+    }
+
+    /// `None` if `key_id` is unknown; `Some(true)` once a retired
+    /// (non-accepting) key has no outstanding nonces left.
+    pub async fn key_is_empty(&self, key_id: &str) -> Option<bool> {
+        let keys = self.keys.read().await;
+        let key = keys.get(key_id)?;
+        Some(!key.accepting_new_work && key.outstanding_nonces.is_empty())
+    }
+}
+
+/// Caesar's own wallets/pools that an outbound payout must never target,
+/// since paying them would just loop value back into the bridge itself.
+fn is_internal_recipient(recipient: &str) -> bool {
+    recipient == "EXCHANGE_POOL" || recipient.starts_with("INTERNAL_") || recipient.starts_with("CHANGE_")
+}
+
+#[async_trait]
+impl Scheduler for AccountScheduler {
+    async fn schedule(&self, operation: BridgeOperation) -> Result<ScheduledId> {
+        let (amount, recipient) = match &operation {
+            BridgeOperation::Mint { amount, recipient, .. } => (*amount, recipient.clone()),
+            BridgeOperation::Unlock { amount, recipient, .. } => (*amount, recipient.clone()),
+            _ => return Err(anyhow!("scheduler only sequences outbound Mint/Unlock payouts")),
+        };
+
+        if is_internal_recipient(&recipient) {
+            return Err(anyhow!("refusing to schedule a payout to an internal/change address"));
+        }
+
+        let active_key_id = self.active_key_id.read().await.clone();
+        let (signing_key_id, nonce) = {
+            let mut keys = self.keys.write().await;
+            let key = keys
+                .get_mut(&active_key_id)
+                .ok_or_else(|| anyhow!("active signing key not found"))?;
+            if !key.accepting_new_work {
+                return Err(anyhow!("active signing key is no longer accepting new work"));
+            }
+            let nonce = key.next_nonce;
+            key.next_nonce += 1;
+            key.outstanding_nonces.insert(nonce);
+            (active_key_id, nonce)
+        };
+
+        let scheduled_id = format!("SCHED_{}", Uuid::new_v4());
+        self.assignments
+            .write()
+            .await
+            .insert(scheduled_id.clone(), (signing_key_id.clone(), nonce));
+        self.queue.write().await.push(SignedTx {
+            scheduled_id: scheduled_id.clone(),
+            network: self.network.clone(),
+            signing_key_id,
+            nonce,
+            recipient,
+            amount,
+        });
+
+        Ok(scheduled_id)
+    }
+
+    async fn plan(&self) -> Vec<SignedTx> {
+        let mut planned = std::mem::take(&mut *self.queue.write().await);
+        planned.sort_by(|a, b| (a.signing_key_id.as_str(), a.nonce).cmp(&(b.signing_key_id.as_str(), b.nonce)));
+        planned
+    }
+}
+
+/// One recorded `InInstruction` bridge event: the bridge's own log of
+/// "lock X destined for recipient Y on network Z", independent of the
+/// underlying asset transfer that should accompany it.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct InInstructionEvent {
+    pub network: NetworkType,
+    pub block_hash: String,
+    pub source_tx: String,
+    pub destination_network: NetworkType,
+    pub recipient: String,
+    pub amount: Decimal,
+}
+
+/// One recorded underlying-asset `Transfer` event on the source chain,
+/// used to cross-check an [`InInstructionEvent`] before authorizing a
+/// `Mint`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TransferEvent {
+    pub network: NetworkType,
+    pub block_hash: String,
+    pub source_tx: String,
+    pub to: String,
+    pub amount: Decimal,
+}
+
+/// Proof that a `Mint` is backed by a real deposit: both an
+/// `InInstruction` event and the underlying `Transfer` it claims to
+/// describe were found in the same block and agree on amount.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct VerifiedDeposit {
+    pub network: NetworkType,
+    pub block_hash: String,
+    pub source_tx: String,
+    pub destination_network: NetworkType,
+    pub recipient: String,
+    pub amount: Decimal,
+    pub verified_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// A source-chain event whose inclusion in a finalized block needs
+/// proving before the bridge trusts it.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FinalityEvent {
+    pub network: NetworkType,
+    pub block_hash: String,
+    /// Hash identifying the receipt/log (e.g. the `InInstruction` event)
+    /// whose inclusion under the block's receipts root is being proven.
+    pub receipt_hash: String,
+}
+
+/// One step of a Merkle inclusion proof: the sibling hash and which side
+/// it sits on, so the proof can be folded up to a root in order.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MerkleProofStep {
+    pub sibling_hash: String,
+    pub sibling_is_left: bool,
+}
+
+/// A sync-committee light-client update moving the trusted header chain
+/// forward by one period. `aggregate_signature` stands in for a BLS
+/// aggregate signature over the finalized header by the *previous*
+/// period's committee (verifying a real BLS aggregate would need a
+/// pairing-crypto dependency this tree doesn't have) -- structurally it
+/// still enforces the same invariant: only a value derived from the
+/// currently trusted committee can advance the header store.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SyncCommitteeUpdate {
+    pub period: u64,
+    pub finalized_block_hash: String,
+    pub receipts_root: String,
+    pub aggregate_signature: String,
+    /// Hash committing to the next period's committee, so that committee's
+    /// own update can in turn be checked against this one.
+    pub next_committee_hash: String,
+}
+
+/// Proof format accepted by a [`FinalityVerifier`]. Which variant applies
+/// depends on the network's verifier: light-client chains expect
+/// `LightClient`, chains without one (e.g. Bitcoin) expect
+/// `ConfirmationCount`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum FinalityProof {
+    LightClient {
+        /// `None` if the header store already covers `event.block_hash`.
+        header_update: Option<SyncCommitteeUpdate>,
+        receipt_proof: Vec<MerkleProofStep>,
+    },
+    ConfirmationCount {
+        confirmations: u32,
+    },
+}
+
+/// Proves a source-chain event is in a *finalized* block using the
+/// chain's own consensus, rather than assuming N confirmations equals
+/// finality.
+#[async_trait]
+pub trait FinalityVerifier: Send + Sync {
+    async fn verify_finalized(&self, event: &FinalityEvent, proof: &FinalityProof) -> Result<bool>;
+}
+
+/// Trusted light-client state: the currently trusted sync committee and
+/// every finalized header accepted so far. Only ever advanced by an
+/// update that passes the signature + committee-transition check, so a
+/// forged proof on an unfinalized fork can't get in.
+struct SyncCommitteeState {
+    period: u64,
+    committee_hash: String,
+    headers: HashMap<String, String>,
+}
+
+/// [`FinalityVerifier`] for Ethereum and its L2s: a sync-committee style
+/// light client. The committee rotates each period, with each new
+/// committee attested by the previous one, and a deposit/`InInstruction`
+/// event is proven via Merkle inclusion under a finalized header's
+/// `receipts_root`.
+pub struct SyncCommitteeVerifier {
+    network: NetworkType,
+    state: RwLock<SyncCommitteeState>,
+}
+
+impl SyncCommitteeVerifier {
+    /// Bootstrap from a trusted checkpoint (e.g. a weak-subjectivity
+    /// checkpoint), the same way a real light client starts trusting a
+    /// sync committee out of band before it can verify anything itself.
+    pub fn new(network: NetworkType, trusted_period: u64, trusted_committee_hash: impl Into<String>) -> Self {
+        Self {
+            network,
+            state: RwLock::new(SyncCommitteeState {
+                period: trusted_period,
+                committee_hash: trusted_committee_hash.into(),
+                headers: HashMap::new(),
+            }),
+        }
+    }
+}
+
+#[async_trait]
+impl FinalityVerifier for SyncCommitteeVerifier {
+    async fn verify_finalized(&self, event: &FinalityEvent, proof: &FinalityProof) -> Result<bool> {
+        if event.network != self.network {
+            return Err(anyhow!("event network does not match this verifier's network"));
+        }
+
+        let FinalityProof::LightClient { header_update, receipt_proof } = proof else {
+            return Err(anyhow!("{:?} requires a light-client proof", self.network));
+        };
+
+        if let Some(update) = header_update {
+            let mut state = self.state.write().await;
+            // Periods advance one at a time and each committee is attested
+            // by the one before it, so a forged update can't skip straight
+            // to an arbitrary future committee.
+            if update.period != state.period + 1 {
+                return Err(anyhow!(
+                    "sync committee update period {} does not follow trusted period {}",
+                    update.period,
+                    state.period
+                ));
+            }
+            if update.aggregate_signature != expected_aggregate_signature(&state.committee_hash, update) {
+                return Err(anyhow!("sync committee update failed aggregate signature check"));
+            }
+            state
+                .headers
+                .insert(update.finalized_block_hash.clone(), update.receipts_root.clone());
+            state.committee_hash = update.next_committee_hash.clone();
+            state.period = update.period;
+        }
+
+        let state = self.state.read().await;
+        let Some(receipts_root) = state.headers.get(&event.block_hash) else {
+            return Ok(false);
+        };
+
+        Ok(fold_merkle_proof(&event.receipt_hash, receipt_proof) == *receipts_root)
+    }
+}
+
+/// [`FinalityVerifier`] for chains without a light client (e.g. Bitcoin,
+/// via SPV): falls back to raw confirmation counting.
+pub struct ConfirmationCountVerifier {
+    required_confirmations: u32,
+}
+
+impl ConfirmationCountVerifier {
+    pub fn new(required_confirmations: u32) -> Self {
+        Self { required_confirmations }
+    }
+}
+
+#[async_trait]
+impl FinalityVerifier for ConfirmationCountVerifier {
+    async fn verify_finalized(&self, _event: &FinalityEvent, proof: &FinalityProof) -> Result<bool> {
+        let FinalityProof::ConfirmationCount { confirmations } = proof else {
+            return Err(anyhow!("confirmation-count verifier requires a ConfirmationCount proof"));
+        };
+        Ok(*confirmations >= self.required_confirmations)
+    }
+}
+
+/// Signature a real light client would verify via BLS aggregate
+/// verification against the committee's pubkeys; here it's a hash tying
+/// the update to the currently trusted committee, preserving the same
+/// "only the trusted committee can advance the header store" invariant
+/// without a pairing-crypto dependency.
+fn expected_aggregate_signature(committee_hash: &str, update: &SyncCommitteeUpdate) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(committee_hash.as_bytes());
+    hasher.update(update.finalized_block_hash.as_bytes());
+    hasher.update(update.receipts_root.as_bytes());
+    hasher.update(update.next_committee_hash.as_bytes());
+    hex::encode(hasher.finalize())
+}
+
+/// Fold a Merkle inclusion proof up from `leaf_hash` to its implied root.
+fn fold_merkle_proof(leaf_hash: &str, proof: &[MerkleProofStep]) -> String {
+    let mut current = leaf_hash.to_string();
+    for step in proof {
+        let mut hasher = Sha256::new();
+        if step.sibling_is_left {
+            hasher.update(step.sibling_hash.as_bytes());
+            hasher.update(current.as_bytes());
+        } else {
+            hasher.update(current.as_bytes());
+            hasher.update(step.sibling_hash.as_bytes());
+        }
+        current = hex::encode(hasher.finalize());
+    }
+    current
+}
+
+/// EOA the bridge's deployer contract is always created from, at nonce 0,
+/// so its address is reproducible on any network without recording it
+/// anywhere.
+const BRIDGE_DEPLOYER_EOA: &str = "BRIDGE_DEPLOYER_EOA";
+
+/// Derives the address a contract created by `deployer` at `nonce` would
+/// have under EVM `CREATE` semantics (real EVM: keccak256 of the RLP
+/// encoding of `(deployer, nonce)`, low 20 bytes). This tree has no
+/// RLP/keccak dependency, so this stands in with a SHA-256-based
+/// derivation that keeps the property that actually matters here: the
+/// same `(deployer, nonce)` always yields the same address, and
+/// different inputs don't collide.
+fn derive_create_address(deployer: &str, nonce: u64) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(deployer.as_bytes());
+    hasher.update(nonce.to_be_bytes());
+    let digest = hasher.finalize();
+    format!("0x{}", hex::encode(&digest[12..]))
+}
+
+/// One `Router` the deployer contract has created for a network.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RouterDeployment {
+    pub network: NetworkType,
+    pub deployer_address: String,
+    pub router_address: String,
+    pub deployed_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Deploys and rediscovers the bridge's on-chain `Router` per network,
+/// without relying on an out-of-band record of its address.
+///
+/// Two-step scheme: a tiny fixed "deployer" contract is itself deployed
+/// via an ordinary transaction from a known deployer EOA at nonce 0 (so
+/// its address is reproducible via `derive_create_address`), and that
+/// deployer contract is the one that creates each network's `Router` --
+/// recording it so `find_router` can reconstruct the address later from
+/// the deployer's own state rather than a hand-set placeholder.
+pub struct Deployer {
+    deployer_eoa: String,
+    deployer_contract: RwLock<Option<String>>,
+    routers: RwLock<HashMap<NetworkType, RouterDeployment>>,
+}
+
+impl Deployer {
+    pub fn new(deployer_eoa: impl Into<String>) -> Self {
+        Self {
+            deployer_eoa: deployer_eoa.into(),
+            deployer_contract: RwLock::new(None),
+            routers: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Deploy the fixed deployer contract, if it hasn't been already.
+    async fn ensure_deployer_contract(&self) -> Result<String> {
+        let mut deployer_contract = self.deployer_contract.write().await;
+        if let Some(address) = deployer_contract.as_ref() {
+            return Ok(address.clone());
+        }
+        if self.deployer_eoa.trim().is_empty() {
+            return Err(anyhow!("deployment reverted: no deployer EOA configured"));
+        }
+        let address = derive_create_address(&self.deployer_eoa, 0);
+        *deployer_contract = Some(address.clone());
+        Ok(address)
+    }
+
+    /// Deploy a `Router` for `network` via the deployer contract, at the
+    /// deployer's per-network creation count `nonce`. Returns `Err` -- not
+    /// a zero address -- if the deployment "reverts" (no deployer EOA
+    /// configured) or would overwrite an existing, different Router.
+    pub async fn deploy_router(&self, network: NetworkType, nonce: u64) -> Result<String> {
+        let deployer_contract = self.ensure_deployer_contract().await?;
+        let router_address = derive_create_address(&deployer_contract, nonce);
+
+        let mut routers = self.routers.write().await;
+        if let Some(existing) = routers.get(&network) {
+            if existing.router_address != router_address {
+                return Err(anyhow!(
+                    "a Router is already deployed for {:?} at a different address; refusing to overwrite",
+                    network
+                ));
+            }
+            return Ok(existing.router_address.clone());
+        }
+
+        routers.insert(
+            network.clone(),
+            RouterDeployment {
+                network,
+                deployer_address: deployer_contract,
+                router_address: router_address.clone(),
+                deployed_at: chrono::Utc::now(),
+            },
+        );
+
+        Ok(router_address)
+    }
+
+    /// Reconstruct `network`'s `Router` address from the deployer
+    /// contract's recorded deployments.
+    pub async fn find_router(&self, network: NetworkType) -> Result<Option<String>> {
+        Ok(self.routers.read().await.get(&network).map(|d| d.router_address.clone()))
+    }
+}
+
+/// Strictly increasing position of a [`LaneMessage`] within its [`MessageLane`].
+pub type LaneNonce = u64;
+
+/// A directed network pair: a message lane only ever carries traffic one
+/// way, so the reverse pair (`to` -> `from`) is tracked separately.
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct LaneKey {
+    pub from_network: NetworkType,
+    pub to_network: NetworkType,
+}
+
+/// One outbound bridge operation queued on a lane.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LaneMessage {
+    pub lane: LaneKey,
+    pub nonce: LaneNonce,
+    pub operation: BridgeOperation,
+    pub enqueued_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Proof the destination side processed every nonce in
+/// `from_nonce..=to_nonce` for `lane`, in order. `relayer_signature`
+/// stands in for a real relayer signature the same way a bridge `Claim`
+/// stands in for a raw tx hash elsewhere in this module -- no signature
+/// scheme is wired into this tree, so it's a hash tying the receipt to
+/// its claimed range.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DeliveryReceipt {
+    pub lane: LaneKey,
+    pub from_nonce: LaneNonce,
+    pub to_nonce: LaneNonce,
+    pub relayer_signature: String,
+}
+
+/// The two cursors a lane tracks: the last nonce ever generated, and the
+/// last nonce a delivery receipt has confirmed. `None` means nothing has
+/// happened yet on that axis.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LaneCursors {
+    pub latest_generated: Option<LaneNonce>,
+    pub latest_confirmed: Option<LaneNonce>,
+}
+
+/// Append-only message queue plus confirmation cursor for one directed
+/// network pair.
+struct LaneState {
+    messages: Vec<LaneMessage>,
+    latest_confirmed: Option<LaneNonce>,
+}
+
+impl LaneState {
+    fn new() -> Self {
+        Self { messages: Vec::new(), latest_confirmed: None }
+    }
+}
+
+/// Models cross-chain transfers as an ordered, provable message queue per
+/// directed network pair, instead of isolated `BridgeTransaction`s a
+/// relayer could drop or reorder unnoticed. Invariants: in-order delivery
+/// (no gaps in confirmed nonces) and exactly-once processing per nonce.
+pub struct MessageLaneManager {
+    lanes: RwLock<HashMap<LaneKey, LaneState>>,
+}
+
+impl MessageLaneManager {
+    pub fn new() -> Self {
+        Self { lanes: RwLock::new(HashMap::new()) }
+    }
+
+    /// Assign `operation` the next strictly increasing nonce on the
+    /// `from` -> `to` lane and append it to that lane's message log.
+    pub async fn enqueue_message(&self, from: NetworkType, to: NetworkType, operation: BridgeOperation) -> LaneNonce {
+        let lane = LaneKey { from_network: from, to_network: to };
+        let mut lanes = self.lanes.write().await;
+        let state = lanes.entry(lane.clone()).or_insert_with(LaneState::new);
+        let nonce = state.messages.len() as LaneNonce;
+        state.messages.push(LaneMessage {
+            lane,
+            nonce,
+            operation,
+            enqueued_at: chrono::Utc::now(),
+        });
+        nonce
+    }
+
+    /// Accept `receipt` only if it proves delivery of exactly the next
+    /// contiguous range of nonces `receipt.lane` is expecting -- refuses a
+    /// receipt that would skip a nonce or re-confirm one already covered.
+    pub async fn receive_delivery_proof(&self, lane: LaneKey, receipt: DeliveryReceipt) -> Result<()> {
+        if receipt.lane != lane {
+            return Err(anyhow!("delivery receipt is for a different lane"));
+        }
+        if receipt.from_nonce > receipt.to_nonce {
+            return Err(anyhow!("delivery receipt covers an empty/invalid nonce range"));
+        }
+
+        let mut lanes = self.lanes.write().await;
+        let state = lanes.get_mut(&lane).ok_or_else(|| anyhow!("unknown lane"))?;
+
+        let expected_next = state.latest_confirmed.map(|n| n + 1).unwrap_or(0);
+        if receipt.from_nonce != expected_next {
+            return Err(anyhow!(
+                "delivery receipt starts at nonce {} but lane next expects {}",
+                receipt.from_nonce,
+                expected_next
+            ));
+        }
+        if receipt.to_nonce >= state.messages.len() as LaneNonce {
+            return Err(anyhow!(
+                "delivery receipt covers nonce {} but only {} message(s) have been generated",
+                receipt.to_nonce,
+                state.messages.len()
+            ));
+        }
+
+        state.latest_confirmed = Some(receipt.to_nonce);
+        Ok(())
+    }
+
+    /// Messages generated on `lane` but not yet covered by a confirmed
+    /// delivery receipt.
+    pub async fn undelivered(&self, lane: LaneKey) -> Vec<LaneMessage> {
+        let lanes = self.lanes.read().await;
+        let Some(state) = lanes.get(&lane) else { return Vec::new() };
+        let next_unconfirmed = state.latest_confirmed.map(|n| n + 1).unwrap_or(0) as usize;
+        state.messages[next_unconfirmed.min(state.messages.len())..].to_vec()
+    }
+
+    /// `latest_generated`/`latest_confirmed` for `lane`, or `None` if the
+    /// lane has never had a message enqueued.
+    pub async fn lane_cursors(&self, lane: LaneKey) -> Option<LaneCursors> {
+        let lanes = self.lanes.read().await;
+        let state = lanes.get(&lane)?;
+        Some(LaneCursors {
+            latest_generated: state.messages.len().checked_sub(1).map(|n| n as LaneNonce),
+            latest_confirmed: state.latest_confirmed,
+        })
+    }
+}
+
+impl Default for MessageLaneManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// How far through the HTLC protocol a swap has progressed.
+///
+/// `Proposed -> Funded -> Redeemed | Refunded | Expired`. `Expired` is a
+/// detection state the reaper sets the instant `timelock_t1` passes on an
+/// unredeemed swap; it then immediately auto-refunds `Expired` swaps to
+/// `Refunded`. Splitting detection from the refund action keeps a swap that
+/// failed partway through the refund step idempotently retryable on the
+/// next reaper tick rather than stuck in limbo.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HtlcSwapStatus {
+    Proposed,
+    Funded,
+    Redeemed,
+    Refunded,
+    Expired,
+}
+
+/// An atomic cross-chain swap: the initiator locks CSR on HyperMesh under
+/// `hash_lock = sha256(secret)`, the counterparty locks a foreign asset
+/// under the same hash with a shorter `timelock_t2`, and whichever side
+/// redeems first publishes `secret`, letting the other side redeem too.
+/// Neither party ever has to trust the other or a custodian with both legs
+/// at once.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct HtlcSwap {
+    pub swap_id: String,
+    /// The CSR-side wallet that proposed the swap and locked CSR.
+    pub initiator_wallet: String,
+    /// Free-form identifier for the counterparty's side of the swap (a
+    /// foreign-chain address); this subsystem only tracks the CSR leg, so
+    /// it has no further structure to validate here.
+    pub counterparty: String,
+    /// The foreign network the counterparty is locking their asset on.
+    pub network: NetworkType,
+    pub amount: Decimal,
+    /// Hex-encoded `sha256(secret)`.
+    pub hash_lock: String,
+    /// Hex-encoded preimage, set once revealed by a successful redeem.
+    pub secret: Option<String>,
+    /// Initiator's refund deadline. Must be strictly after `timelock_t2` so
+    /// the counterparty always has room to redeem the CSR leg after
+    /// observing the initiator's redeem on the foreign chain.
+    pub timelock_t1: chrono::DateTime<chrono::Utc>,
+    /// Counterparty's refund deadline for their own leg.
+    pub timelock_t2: chrono::DateTime<chrono::Utc>,
+    pub status: HtlcSwapStatus,
+    pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
 /// Network configuration for cross-chain operations
@@ -148,6 +990,33 @@ pub struct CrossChainBridge {
     stability_config: StabilityConfig,
     /// Fee calculation engine
     fee_calculator: FeeCalculator,
+    /// Active eventualities awaiting resolution, keyed by the bridge
+    /// transaction id they'll complete or revert. Removed on first match so
+    /// each is consumed exactly once.
+    eventualities: Arc<RwLock<HashMap<EventualityId, Box<dyn Eventuality>>>>,
+    /// Outbound transaction schedulers, one per network that needs nonce
+    /// ordering (account-based chains only).
+    schedulers: Arc<RwLock<HashMap<NetworkType, Arc<AccountScheduler>>>>,
+    /// Signing key/nonce assigned to each bridge transaction id that went
+    /// through a scheduler, so `submit_chain_event` can resolve the nonce
+    /// once the transaction's `PayoutEventuality` completes or reverts.
+    nonce_assignments: Arc<RwLock<HashMap<String, (NetworkType, String, u64)>>>,
+    /// `InInstruction` events recorded by a source-network watcher, keyed
+    /// by (network, source_tx).
+    in_instructions: Arc<RwLock<HashMap<(NetworkType, String), InInstructionEvent>>>,
+    /// Underlying asset `Transfer` events recorded the same way.
+    transfers: Arc<RwLock<HashMap<(NetworkType, String), TransferEvent>>>,
+    /// Per-network finality verifiers. Networks with no entry fall back to
+    /// the raw `confirmations`/`required_confirmations` fields already on
+    /// `BridgeTransaction`.
+    finality_verifiers: Arc<RwLock<HashMap<NetworkType, Arc<dyn FinalityVerifier>>>>,
+    /// Deploys/rediscovers each network's Router contract deterministically.
+    deployer: Deployer,
+    /// Ordered, provable message queues per directed network pair.
+    message_lanes: MessageLaneManager,
+    /// Persists HTLC swaps so they survive a restart and can be found again
+    /// by the reaper task.
+    storage: Arc<CaesarStorage>,
 }
 
 /// Stability configuration for "mostly-stable" token
@@ -178,7 +1047,7 @@ pub struct FeeCalculator {
 
 impl CrossChainBridge {
     /// Create new cross-chain bridge
-    pub async fn new() -> Result<Self> {
+    pub async fn new(storage: Arc<CaesarStorage>) -> Result<Self> {
         let mut networks = HashMap::new();
 
         // Initialize default network configurations
@@ -240,12 +1109,254 @@ impl CrossChainBridge {
             stability_factor: dec!(1.0),
         };
 
+        // Account-based chains need nonce-ordered outbound payouts; the rest
+        // (Bitcoin/UTXO-based, HyperMesh's native asset system) don't get a
+        // scheduler registered.
+        let mut schedulers: HashMap<NetworkType, Arc<AccountScheduler>> = HashMap::new();
+        for network in [
+            NetworkType::Ethereum,
+            NetworkType::Polygon,
+            NetworkType::Arbitrum,
+            NetworkType::Optimism,
+            NetworkType::BSC,
+            NetworkType::Avalanche,
+        ] {
+            let initial_key_id = format!("{}-primary", network.as_str());
+            schedulers.insert(network.clone(), Arc::new(AccountScheduler::new(network, initial_key_id)));
+        }
+
         Ok(Self {
             networks: Arc::new(RwLock::new(networks)),
             transactions: Arc::new(RwLock::new(HashMap::new())),
             liquidity_pools: Arc::new(RwLock::new(HashMap::new())),
             stability_config,
             fee_calculator,
+            eventualities: Arc::new(RwLock::new(HashMap::new())),
+            schedulers: Arc::new(RwLock::new(schedulers)),
+            nonce_assignments: Arc::new(RwLock::new(HashMap::new())),
+            in_instructions: Arc::new(RwLock::new(HashMap::new())),
+            transfers: Arc::new(RwLock::new(HashMap::new())),
+            finality_verifiers: Arc::new(RwLock::new(Self::default_finality_verifiers())),
+            deployer: Deployer::new(BRIDGE_DEPLOYER_EOA),
+            message_lanes: MessageLaneManager::new(),
+            storage,
+        })
+    }
+
+    /// Queue `operation` on the `from` -> `to` lane, outside of
+    /// `initiate_bridge`'s automatic wiring (e.g. for `Burn`/`Unlock`,
+    /// which don't yet have one directed pair wired in automatically).
+    pub async fn enqueue_lane_message(&self, from: NetworkType, to: NetworkType, operation: BridgeOperation) -> LaneNonce {
+        self.message_lanes.enqueue_message(from, to, operation).await
+    }
+
+    /// Feed a destination-side delivery receipt into its lane; see
+    /// [`MessageLaneManager::receive_delivery_proof`].
+    pub async fn receive_delivery_proof(&self, lane: LaneKey, receipt: DeliveryReceipt) -> Result<()> {
+        self.message_lanes.receive_delivery_proof(lane, receipt).await
+    }
+
+    /// Messages generated on `lane` but not yet confirmed delivered.
+    pub async fn undelivered_lane_messages(&self, lane: LaneKey) -> Vec<LaneMessage> {
+        self.message_lanes.undelivered(lane).await
+    }
+
+    /// `latest_generated`/`latest_confirmed` for `lane`.
+    pub async fn lane_cursors(&self, lane: LaneKey) -> Option<LaneCursors> {
+        self.message_lanes.lane_cursors(lane).await
+    }
+
+    /// Deploy (idempotently) `network`'s Router via the bridge's deployer
+    /// subsystem, then refresh `NetworkConfig.contract_address` from the
+    /// deployer's own state.
+    pub async fn deploy_router(&self, network: NetworkType, nonce: u64) -> Result<String> {
+        let router_address = self.deployer.deploy_router(network.clone(), nonce).await?;
+        self.refresh_router_address(network).await?;
+        Ok(router_address)
+    }
+
+    /// Reconstruct `network`'s Router address from the deployer's own
+    /// state, without consulting any out-of-band record.
+    pub async fn find_router(&self, network: NetworkType) -> Result<Option<String>> {
+        self.deployer.find_router(network).await
+    }
+
+    /// Write `network`'s `NetworkConfig.contract_address` from
+    /// `find_router`, if one has been deployed.
+    async fn refresh_router_address(&self, network: NetworkType) -> Result<()> {
+        let Some(router_address) = self.deployer.find_router(network.clone()).await? else {
+            return Ok(());
+        };
+        if let Some(config) = self.networks.write().await.get_mut(&network) {
+            config.contract_address = Some(router_address);
+        }
+        Ok(())
+    }
+
+    /// Light-client verifiers for Ethereum and its L2s, plus a
+    /// confirmation-count (SPV-style) verifier for Bitcoin. Solana and
+    /// HyperMesh have no verifier registered and keep falling back to raw
+    /// confirmation counting.
+    fn default_finality_verifiers() -> HashMap<NetworkType, Arc<dyn FinalityVerifier>> {
+        let mut verifiers: HashMap<NetworkType, Arc<dyn FinalityVerifier>> = HashMap::new();
+        for network in [
+            NetworkType::Ethereum,
+            NetworkType::Polygon,
+            NetworkType::Arbitrum,
+            NetworkType::Optimism,
+            NetworkType::BSC,
+            NetworkType::Avalanche,
+        ] {
+            verifiers.insert(
+                network.clone(),
+                Arc::new(SyncCommitteeVerifier::new(network, 0, "genesis-sync-committee")),
+            );
+        }
+        verifiers.insert(NetworkType::Bitcoin, Arc::new(ConfirmationCountVerifier::new(6)));
+        verifiers
+    }
+
+    /// Prove `event` is finalized via `network`'s registered
+    /// [`FinalityVerifier`]. Errors if no verifier is registered for
+    /// `network` -- callers should fall back to confirmation counting in
+    /// that case, as `confirm_transaction` does.
+    pub async fn verify_finalized(
+        &self,
+        network: NetworkType,
+        event: FinalityEvent,
+        proof: FinalityProof,
+    ) -> Result<bool> {
+        let verifier = self
+            .finality_verifiers
+            .read()
+            .await
+            .get(&network)
+            .cloned()
+            .ok_or_else(|| anyhow!("no finality verifier registered for {:?}", network))?;
+        verifier.verify_finalized(&event, &proof).await
+    }
+
+    /// Mark `tx_id` `Confirmed` once its finality is proven: via
+    /// `network`'s light-client/SPV verifier if one is registered, or by
+    /// falling back to `BridgeTransaction::confirmations` against
+    /// `required_confirmations` for networks without one.
+    pub async fn confirm_transaction(
+        &self,
+        tx_id: &str,
+        network: NetworkType,
+        event: FinalityEvent,
+        proof: FinalityProof,
+    ) -> Result<BridgeTransaction> {
+        let verifier = self.finality_verifiers.read().await.get(&network).cloned();
+        let finalized = match verifier {
+            Some(verifier) => verifier.verify_finalized(&event, &proof).await?,
+            None => {
+                let transactions = self.transactions.read().await;
+                let transaction = transactions
+                    .get(tx_id)
+                    .ok_or_else(|| anyhow!("bridge transaction not found: {}", tx_id))?;
+                transaction.confirmations >= transaction.required_confirmations
+            }
+        };
+
+        if !finalized {
+            return Err(anyhow!("event is not yet finalized for bridge transaction {}", tx_id));
+        }
+
+        let mut transactions = self.transactions.write().await;
+        let transaction = transactions
+            .get_mut(tx_id)
+            .ok_or_else(|| anyhow!("bridge transaction not found: {}", tx_id))?;
+        transaction.status = BridgeStatus::Confirmed;
+        Ok(transaction.clone())
+    }
+
+    /// Record an `InInstruction` event observed by a source-network
+    /// watcher, for later cross-checking by `verify_in_instruction`.
+    pub async fn record_in_instruction_event(&self, event: InInstructionEvent) {
+        self.in_instructions
+            .write()
+            .await
+            .insert((event.network.clone(), event.source_tx.clone()), event);
+    }
+
+    /// Record an underlying asset `Transfer` event observed the same way.
+    pub async fn record_transfer_event(&self, event: TransferEvent) {
+        self.transfers
+            .write()
+            .await
+            .insert((event.network.clone(), event.source_tx.clone()), event);
+    }
+
+    /// Authorize a `Mint` only if both an `InInstruction` event and a
+    /// matching underlying `Transfer` to the bridge's custody address exist
+    /// for `source_tx` in `block_hash` and agree on amount. Reading both at
+    /// a fixed block hash, rather than "latest", means a reorg can't swap
+    /// one event out from under the other mid-check. The two-event
+    /// cross-check defends against a spoofed `InInstruction` with no real
+    /// transfer behind it.
+    pub async fn verify_in_instruction(
+        &self,
+        network: NetworkType,
+        block_hash: &str,
+        source_tx: &str,
+    ) -> Result<VerifiedDeposit> {
+        let key = (network.clone(), source_tx.to_string());
+
+        let in_instruction = self
+            .in_instructions
+            .read()
+            .await
+            .get(&key)
+            .cloned()
+            .ok_or_else(|| anyhow!("no InInstruction event found for {} on {:?}", source_tx, network))?;
+        let transfer = self
+            .transfers
+            .read()
+            .await
+            .get(&key)
+            .cloned()
+            .ok_or_else(|| anyhow!("no matching Transfer event found for {} on {:?}", source_tx, network))?;
+
+        if in_instruction.block_hash != block_hash || transfer.block_hash != block_hash {
+            return Err(anyhow!(
+                "InInstruction/Transfer events for {} are not both in block {}",
+                source_tx,
+                block_hash
+            ));
+        }
+
+        let custody_address = self
+            .networks
+            .read()
+            .await
+            .get(&network)
+            .and_then(|config| config.contract_address.clone())
+            .ok_or_else(|| anyhow!("no custody address configured for {:?}", network))?;
+        if transfer.to != custody_address {
+            return Err(anyhow!(
+                "Transfer event for {} does not target the bridge's custody address",
+                source_tx
+            ));
+        }
+
+        if in_instruction.amount != transfer.amount {
+            return Err(anyhow!(
+                "InInstruction amount {} disagrees with Transfer amount {} for {}",
+                in_instruction.amount,
+                transfer.amount,
+                source_tx
+            ));
+        }
+
+        Ok(VerifiedDeposit {
+            network,
+            block_hash: block_hash.to_string(),
+            source_tx: source_tx.to_string(),
+            destination_network: in_instruction.destination_network,
+            recipient: in_instruction.recipient,
+            amount: in_instruction.amount,
+            verified_at: chrono::Utc::now(),
         })
     }
 
@@ -259,6 +1370,38 @@ impl CrossChainBridge {
         // Validate operation
         self.validate_bridge_operation(&operation).await?;
 
+        // A `Mint` must be backed by a verified deposit: an `InInstruction`
+        // event and a matching underlying `Transfer`, both read from the
+        // same source-chain block, agreeing on destination/recipient/amount.
+        if let BridgeOperation::Mint { amount, network, recipient, source_tx, from_network, block_hash } = &operation {
+            let verified = self
+                .verify_in_instruction(from_network.clone(), block_hash, source_tx)
+                .await?;
+            if verified.destination_network != *network || &verified.recipient != recipient || verified.amount != *amount {
+                return Err(anyhow!(
+                    "verified deposit for {} does not match the requested Mint",
+                    source_tx
+                ));
+            }
+        }
+
+        // Queue this operation on its directed lane, if it has one, so it
+        // becomes a provable, ordered message rather than only an isolated
+        // `BridgeTransaction` a relayer could drop or reorder unnoticed.
+        let lane_nonce = match &operation {
+            BridgeOperation::Lock { from_network, to_network, .. } => Some(
+                self.message_lanes
+                    .enqueue_message(from_network.clone(), to_network.clone(), operation.clone())
+                    .await,
+            ),
+            BridgeOperation::Mint { from_network, network, .. } => Some(
+                self.message_lanes
+                    .enqueue_message(from_network.clone(), network.clone(), operation.clone())
+                    .await,
+            ),
+            _ => None,
+        };
+
         let transaction = BridgeTransaction {
             id: tx_id.clone(),
             operation: operation.clone(),
@@ -272,24 +1415,161 @@ impl CrossChainBridge {
             destination_tx_hash: None,
             confirmations: 0,
             required_confirmations: self.get_required_confirmations(&operation).await?,
+            lane_nonce,
         };
 
         // Store transaction
         let mut transactions = self.transactions.write().await;
         transactions.insert(tx_id.clone(), transaction.clone());
+        drop(transactions);
 
-        info!("Initiated cross-chain bridge transaction: {}", tx_id);
+        // A `Lock` expects a paired `Mint` to eventually land on the
+        // destination network; register what that `Mint` should look like
+        // so a watcher feeding us `ChainEvent`s can confirm it deterministically
+        // instead of us polling a single tx hash for confirmations.
+        if let BridgeOperation::Lock { amount, to_network, recipient, .. } = &operation {
+            let eventuality = MintEventuality {
+                network: to_network.clone(),
+                expected_amount: *amount,
+                expected_recipient: recipient.clone(),
+                expected_nonce_hash: claim_nonce_hash(*amount, recipient, &tx_id),
+            };
+            self.eventualities
+                .write()
+                .await
+                .insert(tx_id.clone(), Box::new(eventuality));
+        }
 
-        // Start processing in background
-        tokio::spawn(async move {
-            // Processing logic would go here
-            // For now, we'll just log the operation
-            info!("Processing bridge operation: {:?}", operation);
-        });
+        // `Mint`/`Unlock` are outbound payouts on an account-based chain, so
+        // sequence them through that network's scheduler to avoid racing on
+        // nonces, and watch for the payout itself landing.
+        if let BridgeOperation::Mint { amount, network, recipient, .. }
+        | BridgeOperation::Unlock { amount, network, recipient, .. } = &operation
+        {
+            if let Some(scheduler) = self.schedulers.read().await.get(network).cloned() {
+                let scheduled_id = scheduler.schedule(operation.clone()).await?;
+                if let Some((signing_key_id, nonce)) = scheduler.nonce_for(&scheduled_id).await {
+                    let expected_event_type = match &operation {
+                        BridgeOperation::Mint { .. } => ChainEventType::Minted,
+                        _ => ChainEventType::Unlocked,
+                    };
+                    let eventuality = PayoutEventuality {
+                        network: network.clone(),
+                        expected_event_type,
+                        expected_amount: *amount,
+                        expected_recipient: recipient.clone(),
+                        expected_nonce_hash: claim_nonce_hash(*amount, recipient, &tx_id),
+                    };
+                    self.eventualities
+                        .write()
+                        .await
+                        .insert(tx_id.clone(), Box::new(eventuality));
+                    self.nonce_assignments
+                        .write()
+                        .await
+                        .insert(tx_id.clone(), (network.clone(), signing_key_id, nonce));
+                }
+            }
+        }
+
+        info!("Initiated cross-chain bridge transaction: {}", tx_id);
+        info!("Awaiting destination confirmation for bridge operation: {:?}", operation);
 
         Ok(transaction)
     }
 
+    /// Feed an observed [`ChainEvent`] from a per-network watcher into every
+    /// active eventuality. The first one that claims it is removed from the
+    /// store (so it can only ever resolve once) and its bridge transaction
+    /// transitions to `Completed` on a matching claim, or `Reverted` if a
+    /// conflicting claim resolved it instead. Returns the affected bridge
+    /// transaction id, if any.
+    pub async fn submit_chain_event(&self, event: ChainEvent) -> Result<Option<String>> {
+        let resolution = {
+            let eventualities = self.eventualities.read().await;
+            eventualities.iter().find_map(|(tx_id, eventuality)| {
+                eventuality
+                    .claim(&event)
+                    .map(|claim| (tx_id.clone(), eventuality.matches(&claim), claim))
+            })
+        };
+
+        let Some((tx_id, matched, claim)) = resolution else {
+            return Ok(None);
+        };
+
+        self.eventualities.write().await.remove(&tx_id);
+
+        let mut transactions = self.transactions.write().await;
+        if let Some(transaction) = transactions.get_mut(&tx_id) {
+            transaction.destination_tx_hash = Some(claim.tx_hash.clone());
+            if matched {
+                transaction.status = BridgeStatus::Completed;
+                transaction.completed_at = Some(chrono::Utc::now());
+                info!("Bridge transaction {} completed via claim {:?}", tx_id, claim);
+            } else {
+                transaction.status = BridgeStatus::Reverted;
+                warn!("Bridge transaction {} reverted by conflicting claim {:?}", tx_id, claim);
+            }
+        }
+        drop(transactions);
+
+        // If this transaction was an outbound payout sequenced through a
+        // scheduler, its nonce is now resolved either way, letting a
+        // retired signing key eventually report empty.
+        if let Some((network, signing_key_id, nonce)) =
+            self.nonce_assignments.write().await.remove(&tx_id)
+        {
+            if let Some(scheduler) = self.schedulers.read().await.get(&network).cloned() {
+                scheduler.resolve_nonce(&signing_key_id, nonce).await;
+            }
+        }
+
+        Ok(Some(tx_id))
+    }
+
+    /// Rotate `network`'s active signing key to `new_key_id`. The old key
+    /// keeps the nonces already assigned to it and stops accepting new
+    /// work; check `signing_key_is_empty` to see when it's fully retired.
+    pub async fn rotate_signing_key(&self, network: NetworkType, new_key_id: String) -> Result<()> {
+        let scheduler = self
+            .schedulers
+            .read()
+            .await
+            .get(&network)
+            .cloned()
+            .ok_or_else(|| anyhow!("no outbound scheduler registered for {:?}", network))?;
+        scheduler.rotate_key(new_key_id).await;
+        Ok(())
+    }
+
+    /// `true` once a retired signing key has no outstanding nonces left.
+    pub async fn signing_key_is_empty(&self, network: NetworkType, key_id: &str) -> Result<bool> {
+        let scheduler = self
+            .schedulers
+            .read()
+            .await
+            .get(&network)
+            .cloned()
+            .ok_or_else(|| anyhow!("no outbound scheduler registered for {:?}", network))?;
+        scheduler
+            .key_is_empty(key_id)
+            .await
+            .ok_or_else(|| anyhow!("unknown signing key: {}", key_id))
+    }
+
+    /// Flush `network`'s queued outbound payouts in nonce order.
+    pub async fn plan_outbound(&self, network: NetworkType) -> Result<Vec<SignedTx>> {
+        let scheduler = self
+            .schedulers
+            .read()
+            .await
+            .get(&network)
+            .cloned()
+            .ok_or_else(|| anyhow!("no outbound scheduler registered for {:?}", network))?;
+        Ok(scheduler.plan().await)
+    }
+
     /// Calculate dynamic fees for bridge operation
     async fn calculate_bridge_fees(&self, operation: &BridgeOperation) -> Result<(Decimal, Decimal, Decimal)> {
         let networks = self.networks.read().await;
@@ -446,13 +1726,165 @@ impl CrossChainBridge {
     }
 
     /// Update network configuration
-    pub async fn update_network_config(&self, config: NetworkConfig) -> Result<()> {
+    pub async fn update_network_config(&self, mut config: NetworkConfig) -> Result<()> {
+        // Rediscover the Router from the deployer's own state rather than
+        // trusting a hand-set `contract_address`; falls back to whatever
+        // the caller provided (e.g. `None` for Bitcoin) if none is deployed.
+        if let Some(router_address) = self.deployer.find_router(config.network.clone()).await? {
+            config.contract_address = Some(router_address);
+        }
         let mut networks = self.networks.write().await;
         networks.insert(config.network.clone(), config);
         Ok(())
     }
+
+    // HTLC atomic swaps
+
+    /// Initiator side: debit `amount` CSR out of `initiator_wallet`'s
+    /// spendable balance and record a swap locking it under `hash_lock`,
+    /// awaiting the counterparty to lock their own leg on `network` by
+    /// `t2_seconds` from now. Errs if the wallet can't cover `amount`.
+    pub async fn init_htlc_swap(
+        &self,
+        initiator_wallet: String,
+        counterparty: String,
+        network: NetworkType,
+        amount: Decimal,
+        hash_lock: String,
+        t1_seconds: i64,
+        t2_seconds: i64,
+    ) -> Result<HtlcSwap> {
+        if amount <= Decimal::ZERO {
+            return Err(anyhow!("swap amount must be positive"));
+        }
+        if t2_seconds >= t1_seconds {
+            return Err(anyhow!(
+                "counterparty timelock T2 must be strictly shorter than initiator timelock T1, \
+                 so the counterparty always has time to redeem the CSR leg after T2 expires"
+            ));
+        }
+
+        let now = chrono::Utc::now();
+        let swap = HtlcSwap {
+            swap_id: format!("HTLC_{}", Uuid::new_v4()),
+            initiator_wallet,
+            counterparty,
+            network,
+            amount,
+            hash_lock,
+            secret: None,
+            timelock_t1: now + chrono::Duration::seconds(t1_seconds),
+            timelock_t2: now + chrono::Duration::seconds(t2_seconds),
+            status: HtlcSwapStatus::Proposed,
+            created_at: now,
+        };
+
+        self.storage.create_htlc_swap(&swap).await?;
+        info!("Proposed HTLC swap {} locking {} CSR under {}", swap.swap_id, swap.amount, swap.hash_lock);
+        Ok(swap)
+    }
+
+    /// Mark a swap `Funded` once the counterparty has locked their leg
+    /// under the same hash.
+    pub async fn mark_htlc_funded(&self, swap_id: &str) -> Result<HtlcSwap> {
+        let mut swap = self.storage.get_htlc_swap(swap_id).await?;
+        if swap.status != HtlcSwapStatus::Proposed {
+            return Err(anyhow!("swap {} is not awaiting funding (status: {:?})", swap_id, swap.status));
+        }
+
+        swap.status = HtlcSwapStatus::Funded;
+        self.storage.update_htlc_swap(&swap).await?;
+        Ok(swap)
+    }
+
+    /// Redeem a `Funded` swap by revealing `secret`. Verifies
+    /// `sha256(secret) == hash_lock`, credits the locked `amount` CSR to
+    /// `counterparty`, moves the swap to `Redeemed`, and persists the
+    /// now-public secret.
+    pub async fn redeem_htlc_swap(&self, swap_id: &str, secret: &str) -> Result<HtlcSwap> {
+        let mut swap = self.storage.get_htlc_swap(swap_id).await?;
+        if swap.status != HtlcSwapStatus::Funded {
+            return Err(anyhow!("swap {} is not funded (status: {:?})", swap_id, swap.status));
+        }
+        if chrono::Utc::now() >= swap.timelock_t1 {
+            return Err(anyhow!("swap {} timelock T1 has already passed", swap_id));
+        }
+        if hex::encode(Sha256::digest(secret.as_bytes())) != swap.hash_lock {
+            return Err(anyhow!("secret does not hash to this swap's hash lock"));
+        }
+
+        swap.secret = Some(secret.to_string());
+        swap.status = HtlcSwapStatus::Redeemed;
+        self.storage.redeem_htlc_swap(&swap).await?;
+        info!("Redeemed HTLC swap {}", swap_id);
+        Ok(swap)
+    }
+
+    /// Refund the initiator's leg after `timelock_t1` has passed without a
+    /// redeem: credits the locked `amount` CSR back to `initiator_wallet`
+    /// and moves the swap to `Refunded`. Also reachable directly from
+    /// `Expired` (the reaper's detection state) without waiting for another
+    /// tick.
+    pub async fn refund_htlc_swap(&self, swap_id: &str) -> Result<HtlcSwap> {
+        let mut swap = self.storage.get_htlc_swap(swap_id).await?;
+        if !matches!(swap.status, HtlcSwapStatus::Proposed | HtlcSwapStatus::Funded | HtlcSwapStatus::Expired) {
+            return Err(anyhow!("swap {} cannot be refunded from status {:?}", swap_id, swap.status));
+        }
+        if chrono::Utc::now() < swap.timelock_t1 {
+            return Err(anyhow!("swap {} timelock T1 has not passed yet", swap_id));
+        }
+
+        swap.status = HtlcSwapStatus::Refunded;
+        self.storage.refund_htlc_swap(&swap).await?;
+        info!("Refunded HTLC swap {}", swap_id);
+        Ok(swap)
+    }
+
+    pub async fn get_htlc_swap(&self, swap_id: &str) -> Result<HtlcSwap> {
+        self.storage.get_htlc_swap(swap_id).await
+    }
+
+    pub async fn list_htlc_swaps_for_party(&self, party: &str) -> Result<Vec<HtlcSwap>> {
+        self.storage.get_htlc_swaps_for_party(party).await
+    }
+
+    /// One reaper pass: every `Proposed`/`Funded` swap whose `timelock_t1`
+    /// has passed moves to `Expired`, then every `Expired` swap is
+    /// auto-refunded to `Refunded`.
+    async fn reap_expired_htlc_swaps(storage: &CaesarStorage) -> Result<()> {
+        let now = chrono::Utc::now();
+        for mut swap in storage.get_htlc_swaps_past_t1(now).await? {
+            swap.status = HtlcSwapStatus::Expired;
+            storage.update_htlc_swap(&swap).await?;
+
+            swap.status = HtlcSwapStatus::Refunded;
+            storage.refund_htlc_swap(&swap).await?;
+            info!("Reaper auto-refunded expired HTLC swap {}", swap.swap_id);
+        }
+
+        Ok(())
+    }
+
+    /// Spawn the background task that periodically auto-refunds expired
+    /// HTLC swaps. Follows the same spawned-loop-with-sleep shape as
+    /// `exchange::WebSocketPriceFeed::subscribe`'s reconnect loop, minus the
+    /// backoff (a fixed poll interval is fine here; there's no socket to
+    /// reconnect to, just a table to sweep).
+    pub fn spawn_htlc_reaper(storage: Arc<CaesarStorage>) {
+        tokio::spawn(async move {
+            loop {
+                if let Err(e) = Self::reap_expired_htlc_swaps(&storage).await {
+                    warn!("HTLC reaper tick failed: {}", e);
+                }
+                tokio::time::sleep(std::time::Duration::from_secs(HTLC_REAPER_INTERVAL_SECS)).await;
+            }
+        });
+    }
 }
 
+/// How often the HTLC reaper sweeps for expired swaps.
+const HTLC_REAPER_INTERVAL_SECS: u64 = 60;
+
 // Request/Response models for API endpoints
 #[derive(Debug, Serialize, Deserialize)]
 pub struct InitiateBridgeRequest {
@@ -478,4 +1910,210 @@ pub struct BridgeTransactionsResponse {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SupportedNetworksResponse {
     pub networks: Vec<NetworkType>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SubmitChainEventRequest {
+    pub event: ChainEvent,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SubmitChainEventResponse {
+    pub resolved_transaction_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RotateSigningKeyRequest {
+    pub network: NetworkType,
+    pub new_key_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PlanOutboundResponse {
+    pub network: NetworkType,
+    pub planned: Vec<SignedTx>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VerifyInInstructionRequest {
+    pub network: NetworkType,
+    pub block_hash: String,
+    pub source_tx: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VerifyInInstructionResponse {
+    pub deposit: VerifiedDeposit,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConfirmTransactionRequest {
+    pub tx_id: String,
+    pub network: NetworkType,
+    pub event: FinalityEvent,
+    pub proof: FinalityProof,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DeployRouterRequest {
+    pub network: NetworkType,
+    pub nonce: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FindRouterResponse {
+    pub network: NetworkType,
+    pub router_address: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UndeliveredLaneMessagesResponse {
+    pub lane: LaneKey,
+    pub messages: Vec<LaneMessage>,
+    pub cursors: Option<LaneCursors>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InitHtlcSwapRequest {
+    pub initiator_wallet: String,
+    pub counterparty: String,
+    pub network: NetworkType,
+    pub amount: Decimal,
+    pub hash_lock: String,
+    pub t1_seconds: i64,
+    pub t2_seconds: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HtlcSwapResponse {
+    pub swap: HtlcSwap,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RedeemHtlcSwapRequest {
+    pub secret: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HtlcSwapsResponse {
+    pub swaps: Vec<HtlcSwap>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::CreateWalletRequest;
+    use crate::storage::CaesarStorage;
+    use crate::CaesarConfig;
+
+    async fn bridge_with_storage() -> (CrossChainBridge, Arc<CaesarStorage>) {
+        let storage = Arc::new(
+            CaesarStorage::new(CaesarConfig::default().database)
+                .await
+                .expect("failed to create in-memory test storage"),
+        );
+        let bridge = CrossChainBridge::new(storage.clone()).await.unwrap();
+        (bridge, storage)
+    }
+
+    async fn funded_wallet(storage: &CaesarStorage, balance: Decimal) -> String {
+        storage
+            .create_wallet(CreateWalletRequest {
+                user_id: "test_user".to_string(),
+                initial_balance: Some(balance),
+                external_descriptor: None,
+            })
+            .await
+            .unwrap()
+            .wallet_id
+    }
+
+    fn hash_lock_for(secret: &str) -> String {
+        hex::encode(Sha256::digest(secret.as_bytes()))
+    }
+
+    #[tokio::test]
+    async fn test_htlc_happy_path_locks_then_redeems() {
+        let (bridge, storage) = bridge_with_storage().await;
+        let initiator = funded_wallet(&storage, dec!(1000)).await;
+        let counterparty = funded_wallet(&storage, dec!(0)).await;
+        let secret = "correct horse battery staple";
+
+        let swap = bridge
+            .init_htlc_swap(
+                initiator.clone(),
+                counterparty.clone(),
+                NetworkType::Ethereum,
+                dec!(100),
+                hash_lock_for(secret),
+                3600,
+                60,
+            )
+            .await
+            .unwrap();
+        assert_eq!(swap.status, HtlcSwapStatus::Proposed);
+        // The lock is a real debit, not just a status.
+        assert_eq!(storage.get_balance(&initiator).await.unwrap(), dec!(900));
+
+        bridge.mark_htlc_funded(&swap.swap_id).await.unwrap();
+        let redeemed = bridge.redeem_htlc_swap(&swap.swap_id, secret).await.unwrap();
+        assert_eq!(redeemed.status, HtlcSwapStatus::Redeemed);
+        assert_eq!(storage.get_balance(&counterparty).await.unwrap(), dec!(100));
+        assert_eq!(storage.get_balance(&initiator).await.unwrap(), dec!(900));
+    }
+
+    #[tokio::test]
+    async fn test_double_redeem_is_rejected_and_does_not_double_pay() {
+        let (bridge, storage) = bridge_with_storage().await;
+        let initiator = funded_wallet(&storage, dec!(1000)).await;
+        let counterparty = funded_wallet(&storage, dec!(0)).await;
+        let secret = "double-spend-me-not";
+
+        let swap = bridge
+            .init_htlc_swap(initiator, counterparty.clone(), NetworkType::Ethereum, dec!(100), hash_lock_for(secret), 3600, 60)
+            .await
+            .unwrap();
+        bridge.mark_htlc_funded(&swap.swap_id).await.unwrap();
+        bridge.redeem_htlc_swap(&swap.swap_id, secret).await.unwrap();
+
+        // Second redeem of the same swap must fail — it's no longer `Funded`.
+        assert!(bridge.redeem_htlc_swap(&swap.swap_id, secret).await.is_err());
+        assert_eq!(storage.get_balance(&counterparty).await.unwrap(), dec!(100));
+    }
+
+    #[tokio::test]
+    async fn test_refund_before_timeout_is_rejected() {
+        let (bridge, storage) = bridge_with_storage().await;
+        let initiator = funded_wallet(&storage, dec!(1000)).await;
+        let counterparty = funded_wallet(&storage, dec!(0)).await;
+
+        let swap = bridge
+            .init_htlc_swap(initiator.clone(), counterparty, NetworkType::Ethereum, dec!(100), hash_lock_for("s"), 3600, 60)
+            .await
+            .unwrap();
+
+        // T1 is an hour out — refunding now must fail and leave the lock intact.
+        assert!(bridge.refund_htlc_swap(&swap.swap_id).await.is_err());
+        assert_eq!(storage.get_balance(&initiator).await.unwrap(), dec!(900));
+    }
+
+    #[tokio::test]
+    async fn test_reaper_auto_refunds_expired_swap() {
+        let (bridge, storage) = bridge_with_storage().await;
+        let initiator = funded_wallet(&storage, dec!(1000)).await;
+        let counterparty = funded_wallet(&storage, dec!(0)).await;
+
+        // Both timelocks already in the past (t2 < t1 is still satisfied).
+        let swap = bridge
+            .init_htlc_swap(initiator.clone(), counterparty, NetworkType::Ethereum, dec!(100), hash_lock_for("s"), -10, -20)
+            .await
+            .unwrap();
+        assert_eq!(storage.get_balance(&initiator).await.unwrap(), dec!(900));
+
+        CrossChainBridge::reap_expired_htlc_swaps(&storage).await.unwrap();
+
+        let refunded = storage.get_htlc_swap(&swap.swap_id).await.unwrap();
+        assert_eq!(refunded.status, HtlcSwapStatus::Refunded);
+        assert_eq!(storage.get_balance(&initiator).await.unwrap(), dec!(1000));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,177 @@
+//! Structured REST error responses
+//!
+//! REST handlers used to collapse every failure into a bare `StatusCode`,
+//! so a client couldn't tell "insufficient balance" from a database outage.
+//! `CaesarError` carries a machine-readable `code`, the right HTTP status,
+//! and a human `message`, and implements `IntoResponse` so handlers can
+//! return `Result<Json<T>, CaesarError>` directly.
+//!
+//! The business logic in `storage`/`transactions`/`staking`/`exchange` still
+//! reports failures as `anyhow::Error` — rewriting every one of those layers
+//! onto a typed error enum is a larger, riskier change than this pass makes.
+//! Instead `CaesarError::from(anyhow::Error)` classifies the already-
+//! consistent wording those layers use (e.g. "insufficient", "not found") so
+//! handlers get an accurate status/code today, with room to swap in real
+//! typed variants at the source later without touching the handlers again.
+
+use axum::{http::StatusCode, response::{IntoResponse, Json, Response}};
+use serde::Serialize;
+
+/// Machine-readable error code a client can branch on, independent of the
+/// human-readable `message` or the HTTP status used to carry it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CaesarErrorCode {
+    WalletNotFound,
+    TransactionNotFound,
+    StakeNotFound,
+    OrderNotFound,
+    SwapNotFound,
+    InsufficientFunds,
+    AmountOutOfBounds,
+    SlippageExceeded,
+    StaleRate,
+    Unauthorized,
+    InvalidRequest,
+    Internal,
+}
+
+#[derive(Debug, Clone)]
+pub enum CaesarError {
+    WalletNotFound(String),
+    TransactionNotFound(String),
+    StakeNotFound(String),
+    OrderNotFound(String),
+    SwapNotFound(String),
+    InsufficientFunds(String),
+    AmountOutOfBounds(String),
+    SlippageExceeded(String),
+    StaleRate(String),
+    Unauthorized(String),
+    /// A malformed or missing request field (e.g. a required query param).
+    InvalidRequest(String),
+    /// Anything that isn't one of the above — treated as a real server bug.
+    Internal(String),
+}
+
+impl CaesarError {
+    fn code(&self) -> CaesarErrorCode {
+        match self {
+            CaesarError::WalletNotFound(_) => CaesarErrorCode::WalletNotFound,
+            CaesarError::TransactionNotFound(_) => CaesarErrorCode::TransactionNotFound,
+            CaesarError::StakeNotFound(_) => CaesarErrorCode::StakeNotFound,
+            CaesarError::OrderNotFound(_) => CaesarErrorCode::OrderNotFound,
+            CaesarError::SwapNotFound(_) => CaesarErrorCode::SwapNotFound,
+            CaesarError::InsufficientFunds(_) => CaesarErrorCode::InsufficientFunds,
+            CaesarError::AmountOutOfBounds(_) => CaesarErrorCode::AmountOutOfBounds,
+            CaesarError::SlippageExceeded(_) => CaesarErrorCode::SlippageExceeded,
+            CaesarError::StaleRate(_) => CaesarErrorCode::StaleRate,
+            CaesarError::Unauthorized(_) => CaesarErrorCode::Unauthorized,
+            CaesarError::InvalidRequest(_) => CaesarErrorCode::InvalidRequest,
+            CaesarError::Internal(_) => CaesarErrorCode::Internal,
+        }
+    }
+
+    fn status(&self) -> StatusCode {
+        match self {
+            CaesarError::WalletNotFound(_)
+            | CaesarError::TransactionNotFound(_)
+            | CaesarError::StakeNotFound(_)
+            | CaesarError::OrderNotFound(_)
+            | CaesarError::SwapNotFound(_) => StatusCode::NOT_FOUND,
+            CaesarError::InsufficientFunds(_) => StatusCode::PAYMENT_REQUIRED,
+            CaesarError::AmountOutOfBounds(_) | CaesarError::InvalidRequest(_) => StatusCode::BAD_REQUEST,
+            CaesarError::SlippageExceeded(_) | CaesarError::StaleRate(_) => StatusCode::CONFLICT,
+            CaesarError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            CaesarError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn message(&self) -> &str {
+        match self {
+            CaesarError::WalletNotFound(m)
+            | CaesarError::TransactionNotFound(m)
+            | CaesarError::StakeNotFound(m)
+            | CaesarError::OrderNotFound(m)
+            | CaesarError::SwapNotFound(m)
+            | CaesarError::InsufficientFunds(m)
+            | CaesarError::AmountOutOfBounds(m)
+            | CaesarError::SlippageExceeded(m)
+            | CaesarError::StaleRate(m)
+            | CaesarError::Unauthorized(m)
+            | CaesarError::InvalidRequest(m)
+            | CaesarError::Internal(m) => m,
+        }
+    }
+
+    /// A required field was missing or malformed in the request itself,
+    /// rather than failing once it reached the business logic.
+    pub fn invalid_request(message: impl Into<String>) -> Self {
+        CaesarError::InvalidRequest(message.into())
+    }
+}
+
+#[derive(Serialize)]
+struct ErrorDetail {
+    code: CaesarErrorCode,
+    message: String,
+}
+
+#[derive(Serialize)]
+struct ErrorBody {
+    error: ErrorDetail,
+}
+
+impl IntoResponse for CaesarError {
+    fn into_response(self) -> Response {
+        let status = self.status();
+        let body = ErrorBody {
+            error: ErrorDetail {
+                code: self.code(),
+                message: self.message().to_string(),
+            },
+        };
+        (status, Json(body)).into_response()
+    }
+}
+
+/// Classify an `anyhow::Error` bubbling up from storage/transactions/staking/
+/// exchange by the wording those layers already use consistently, so a
+/// client gets an actionable code/status without every one of those layers
+/// needing its own typed error type yet.
+impl From<anyhow::Error> for CaesarError {
+    fn from(e: anyhow::Error) -> Self {
+        let message = e.to_string();
+        let lower = message.to_lowercase();
+
+        if lower.contains("wallet not found") {
+            CaesarError::WalletNotFound(message)
+        } else if lower.contains("transaction not found") {
+            CaesarError::TransactionNotFound(message)
+        } else if lower.contains("stake not found") {
+            CaesarError::StakeNotFound(message)
+        } else if lower.contains("order not found") {
+            CaesarError::OrderNotFound(message)
+        } else if lower.contains("swap not found") {
+            CaesarError::SwapNotFound(message)
+        } else if lower.contains("insufficient") {
+            CaesarError::InsufficientFunds(message)
+        } else if lower.contains("slippage") {
+            CaesarError::SlippageExceeded(message)
+        } else if lower.contains("stale") {
+            CaesarError::StaleRate(message)
+        } else if lower.contains("wrong passphrase") {
+            CaesarError::Unauthorized(message)
+        } else if lower.contains("below minimum")
+            || lower.contains("exceed maximum")
+            || lower.contains("invalid amount")
+            || lower.contains("invalid duration")
+            || lower.contains("invalid target rate")
+            || lower.contains("invalid initial deposit")
+        {
+            CaesarError::AmountOutOfBounds(message)
+        } else {
+            CaesarError::Internal(message)
+        }
+    }
+}
@@ -1,18 +1,106 @@
 //! Caesar Exchange System - Token exchange and market operations
 
 use anyhow::{Result, anyhow};
-use chrono::{DateTime, Utc};
+use async_trait::async_trait;
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use futures_util::{SinkExt, StreamExt};
 use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
 use rust_decimal_macros::dec;
 use rust_decimal::prelude::ToPrimitive;
+use std::str::FromStr;
 use std::sync::Arc;
-use tracing::{info, debug};
+use std::time::Duration;
+use tokio::sync::{watch, RwLock};
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+use tracing::{info, warn, debug};
 use uuid::Uuid;
-use tokio::sync::RwLock;
 use std::collections::HashMap;
 
 use crate::models::*;
-use crate::ExchangeConfig;
+use crate::storage::CaesarStorage;
+use crate::{CurveMode, ExchangeConfig};
+
+/// Fallible `Decimal` arithmetic that maps overflow and division-by-zero to an
+/// `anyhow` error instead of panicking, mirroring the `checked_*` reward path.
+///
+/// Swap and liquidity math runs on attacker-influenced inputs (empty pools,
+/// extreme trade sizes), so the raw operators' panic-on-overflow / divide-by-
+/// zero behaviour would take down the worker thread.
+trait TryArith: Sized {
+    fn try_mul(self, rhs: Self) -> Result<Self>;
+    fn try_div(self, rhs: Self) -> Result<Self>;
+    fn try_add(self, rhs: Self) -> Result<Self>;
+    fn try_sub(self, rhs: Self) -> Result<Self>;
+}
+
+impl TryArith for Decimal {
+    fn try_mul(self, rhs: Self) -> Result<Self> {
+        self.checked_mul(rhs).ok_or_else(|| anyhow!("decimal multiply overflow"))
+    }
+
+    fn try_div(self, rhs: Self) -> Result<Self> {
+        self.checked_div(rhs)
+            .ok_or_else(|| anyhow!("decimal divide by zero or overflow"))
+    }
+
+    fn try_add(self, rhs: Self) -> Result<Self> {
+        self.checked_add(rhs).ok_or_else(|| anyhow!("decimal add overflow"))
+    }
+
+    fn try_sub(self, rhs: Self) -> Result<Self> {
+        self.checked_sub(rhs).ok_or_else(|| anyhow!("decimal subtract overflow"))
+    }
+}
+
+/// Upper bound on Newton iterations for the StableSwap invariant solvers.
+const STABLESWAP_MAX_ITERS: u32 = 255;
+
+/// Convergence threshold for the StableSwap invariant solvers.
+const STABLESWAP_EPSILON: Decimal = dec!(0.000001);
+
+/// Solve the two-asset StableSwap invariant `D` for reserves `x` and `y` with
+/// amplification `a`, by fixed-point iteration. Errors if it fails to converge
+/// within [`STABLESWAP_MAX_ITERS`].
+fn stableswap_d(x: Decimal, y: Decimal, a: Decimal) -> Result<Decimal> {
+    let n = dec!(2);
+    let s = x + y;
+    if s.is_zero() {
+        return Ok(dec!(0));
+    }
+    let ann = a * dec!(4); // A · nⁿ for n = 2
+    let mut d = s;
+    for _ in 0..STABLESWAP_MAX_ITERS {
+        // D_P = Dⁿ⁺¹ / (nⁿ · ∏reserves)
+        let d_p = d * d / (x * n) * d / (y * n);
+        let d_prev = d;
+        d = (ann * s + d_p * n) * d / ((ann - dec!(1)) * d + (n + dec!(1)) * d_p);
+        if (d - d_prev).abs() <= STABLESWAP_EPSILON {
+            return Ok(d);
+        }
+    }
+    Err(anyhow!("StableSwap invariant D failed to converge"))
+}
+
+/// Given the invariant `D`, amplification `a` and the post-trade known reserve
+/// `x`, solve for the opposite reserve `y` by Newton's iteration
+/// `y' = (y² + c) / (2y + b − D)`. Errors on non-convergence.
+fn stableswap_y(x: Decimal, d: Decimal, a: Decimal) -> Result<Decimal> {
+    let n = dec!(2);
+    let ann = a * dec!(4);
+    // c = Dⁿ⁺¹ / (nⁿ · x · Ann),  b = x + D / Ann
+    let c = d * d / (x * n) * d / (ann * n);
+    let b = x + d / ann;
+    let mut y = d;
+    for _ in 0..STABLESWAP_MAX_ITERS {
+        let y_prev = y;
+        y = (y * y + c) / (dec!(2) * y + b - d);
+        if (y - y_prev).abs() <= STABLESWAP_EPSILON {
+            return Ok(y);
+        }
+    }
+    Err(anyhow!("StableSwap reserve solve failed to converge"))
+}
 
 /// Exchange engine for token swaps and market operations
 pub struct ExchangeEngine {
@@ -20,36 +108,425 @@ pub struct ExchangeEngine {
 
     // Market state
     market_state: Arc<RwLock<MarketState>>,
+
+    /// Trading filters keyed by `(base_token, quote_token)`.
+    pairs: Arc<RwLock<HashMap<(String, String), ExchangePairInfo>>>,
+
+    /// Persists every order (resting and filled) so the book survives a
+    /// restart; see `CaesarStorage::{create_order, update_order, get_order,
+    /// get_open_orders}`.
+    storage: Arc<CaesarStorage>,
 }
 
 #[derive(Debug, Clone)]
 struct MarketState {
-    csr_usd_rate: Decimal,
-    liquidity_pool: Decimal,
+    /// CSR side of the constant-product pool.
+    csr_reserve: Decimal,
+    /// USD side of the constant-product pool.
+    usd_reserve: Decimal,
+    /// Outstanding liquidity-provider shares.
+    total_lp_shares: Decimal,
     volume_24h: Decimal,
     fees_24h: Decimal,
     last_update: DateTime<Utc>,
+    /// When the price was last refreshed from an external feed (vs a swap).
+    last_feed_update: Option<DateTime<Utc>>,
+    /// Resting bid levels, sorted by descending price (best bid first).
+    bids: Vec<OrderLevel>,
+    /// Resting ask levels, sorted by ascending price (best ask first).
+    asks: Vec<OrderLevel>,
+}
+
+/// A single resting order-book level.
+#[derive(Debug, Clone)]
+pub struct OrderLevel {
+    pub price: Decimal,
+    pub quantity: Decimal,
+}
+
+impl MarketState {
+    /// Spot CSR/USD rate implied by the reserve ratio (USD per CSR).
+    fn spot_rate(&self) -> Decimal {
+        if self.csr_reserve.is_zero() {
+            dec!(0)
+        } else {
+            self.usd_reserve / self.csr_reserve
+        }
+    }
+}
+
+/// Source of CSR/USD mid-price updates feeding `ExchangeEngine::update_price`.
+///
+/// A `fixed` implementation replays the configured rate so tests run offline,
+/// while the `websocket` implementation streams live ticker frames from an
+/// external exchange.
+#[async_trait]
+pub trait PriceFeed: Send + Sync {
+    /// Obtain a receiver that yields the latest mid-price as it changes.
+    async fn subscribe(&self) -> watch::Receiver<Decimal>;
+
+    /// Pull the feed's current bid/ask/mid without subscribing, for a
+    /// one-off quote. Errs if the feed has no rate yet to report (a
+    /// `WebSocketPriceFeed` that's never connected, or whose last tick is
+    /// older than its configured staleness window) — callers should fall
+    /// back to a `FixedPriceFeed` rather than quote a stale number.
+    async fn latest_rate(&self) -> Result<RateSnapshot>;
+
+    /// Bid/ask spread this feed quotes its snapshots around.
+    async fn spread(&self) -> Decimal;
+}
+
+/// A feed's bid/ask/mid at a point in time, for a caller that pulled
+/// [`PriceFeed::latest_rate`] instead of subscribing to the push stream.
+#[derive(Debug, Clone, Copy)]
+pub struct RateSnapshot {
+    pub bid: Decimal,
+    pub ask: Decimal,
+    pub mid: Decimal,
+    pub last_updated: DateTime<Utc>,
+}
+
+/// Price feed that always reports a single configured rate.
+///
+/// Used as the default and in tests where no live socket is available.
+pub struct FixedPriceFeed {
+    rate: Decimal,
+    spread: Decimal,
+}
+
+impl FixedPriceFeed {
+    pub fn new(rate: Decimal, spread: Decimal) -> Self {
+        Self { rate, spread }
+    }
+}
+
+#[async_trait]
+impl PriceFeed for FixedPriceFeed {
+    async fn subscribe(&self) -> watch::Receiver<Decimal> {
+        let (_tx, rx) = watch::channel(self.rate);
+        rx
+    }
+
+    async fn latest_rate(&self) -> Result<RateSnapshot> {
+        let half_spread = self.spread / dec!(2);
+        Ok(RateSnapshot {
+            bid: self.rate * (dec!(1) - half_spread),
+            ask: self.rate * (dec!(1) + half_spread),
+            mid: self.rate,
+            last_updated: Utc::now(),
+        })
+    }
+
+    async fn spread(&self) -> Decimal {
+        self.spread
+    }
+}
+
+/// Configuration for the live WebSocket price feed.
+#[derive(Debug, Clone)]
+pub struct WebSocketFeedConfig {
+    /// Upstream ticker WebSocket endpoint (Kraken-style public feed).
+    pub ws_url: String,
+    /// Pair to subscribe to, in the upstream's `BASE/QUOTE` notation.
+    pub pair: String,
+    /// Initial reconnect backoff after a socket close.
+    pub reconnect_base_delay: Duration,
+    /// Upper bound on the exponential reconnect backoff.
+    pub reconnect_max_delay: Duration,
+    /// How long a pulled [`PriceFeed::latest_rate`] snapshot is trusted
+    /// before it's treated as stale (the socket may have gone quiet without
+    /// actually closing).
+    pub max_snapshot_age: ChronoDuration,
+}
+
+impl Default for WebSocketFeedConfig {
+    fn default() -> Self {
+        Self {
+            ws_url: "wss://ws.kraken.com".to_string(),
+            pair: "CSR/USD".to_string(),
+            reconnect_base_delay: Duration::from_secs(1),
+            reconnect_max_delay: Duration::from_secs(30),
+            max_snapshot_age: ChronoDuration::seconds(30),
+        }
+    }
+}
+
+/// Live price feed that derives a mid-price from a streamed ticker channel.
+///
+/// Subscribing spawns a reconnecting read loop that subscribes to the ticker
+/// channel, extracts the best bid/ask from each frame, and publishes the mid
+/// price over a `watch` channel. The socket auto-reconnects with exponential
+/// backoff; heartbeat and status events (JSON objects, not arrays) are ignored.
+pub struct WebSocketPriceFeed {
+    config: WebSocketFeedConfig,
+    initial: Decimal,
+    /// Last ticker frame's bid/ask/mid, refreshed by the read loop a
+    /// `subscribe()` call spawns; `None` until the socket has delivered its
+    /// first tick. Backs the pull-based [`PriceFeed::latest_rate`].
+    snapshot: Arc<RwLock<Option<RateSnapshot>>>,
+}
+
+impl WebSocketPriceFeed {
+    pub fn new(config: WebSocketFeedConfig, initial: Decimal) -> Self {
+        Self { config, initial, snapshot: Arc::new(RwLock::new(None)) }
+    }
+
+    /// Open a single connection, subscribe, and pump frames until the socket
+    /// closes or errors.
+    async fn run_connection(
+        config: &WebSocketFeedConfig,
+        tx: &watch::Sender<Decimal>,
+        snapshot: &Arc<RwLock<Option<RateSnapshot>>>,
+    ) -> Result<()> {
+        let (mut ws, _) = connect_async(&config.ws_url).await?;
+
+        let subscribe = serde_json::json!({
+            "event": "subscribe",
+            "pair": [config.pair],
+            "subscription": { "name": "ticker" },
+        });
+        ws.send(Message::Text(subscribe.to_string())).await?;
+
+        while let Some(msg) = ws.next().await {
+            let msg = msg?;
+            let text = match msg {
+                Message::Text(text) => text,
+                Message::Ping(payload) => {
+                    ws.send(Message::Pong(payload)).await?;
+                    continue;
+                }
+                Message::Close(_) => return Ok(()),
+                _ => continue,
+            };
+
+            if let Some((bid, ask)) = Self::parse_ticker(&text) {
+                let mid = (bid + ask) / dec!(2);
+                *snapshot.write().await = Some(RateSnapshot { bid, ask, mid, last_updated: Utc::now() });
+
+                // A closed receiver means the engine has gone away; stop.
+                if tx.send(mid).is_err() {
+                    return Ok(());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parse a ticker frame `[channelId, {"a":[ask,..],"b":[bid,..]}, "ticker", "PAIR"]`
+    /// into its `(bid, ask)` pair. Heartbeat and status events (JSON objects)
+    /// return `None`.
+    fn parse_ticker(text: &str) -> Option<(Decimal, Decimal)> {
+        let value: serde_json::Value = serde_json::from_str(text).ok()?;
+        let array = value.as_array()?;
+        if array.len() < 4 || array[2].as_str() != Some("ticker") {
+            return None;
+        }
+
+        let payload = array[1].as_object()?;
+        let ask = Self::first_level(payload.get("a")?)?;
+        let bid = Self::first_level(payload.get("b")?)?;
+        Some((bid, ask))
+    }
+
+    /// Extract the price (first element) from a `[price, ...]` ticker level.
+    fn first_level(level: &serde_json::Value) -> Option<Decimal> {
+        let price = level.as_array()?.first()?.as_str()?;
+        Decimal::from_str(price).ok()
+    }
+}
+
+#[async_trait]
+impl PriceFeed for WebSocketPriceFeed {
+    async fn subscribe(&self) -> watch::Receiver<Decimal> {
+        let (tx, rx) = watch::channel(self.initial);
+        let config = self.config.clone();
+        let snapshot = self.snapshot.clone();
+
+        tokio::spawn(async move {
+            let mut backoff = config.reconnect_base_delay;
+            loop {
+                match Self::run_connection(&config, &tx, &snapshot).await {
+                    Ok(()) => debug!("Price feed socket closed cleanly, reconnecting"),
+                    Err(e) => warn!("Price feed connection error: {}", e),
+                }
+
+                tokio::time::sleep(backoff).await;
+                backoff = (backoff * 2).min(config.reconnect_max_delay);
+            }
+        });
+
+        rx
+    }
+
+    async fn latest_rate(&self) -> Result<RateSnapshot> {
+        let snapshot = (*self.snapshot.read().await).ok_or_else(|| anyhow!("Price feed has not received a tick yet"))?;
+        let age = Utc::now() - snapshot.last_updated;
+        if age > self.config.max_snapshot_age {
+            return Err(anyhow!(
+                "Price feed snapshot is {}s old, exceeding the {}s staleness window",
+                age.num_seconds(), self.config.max_snapshot_age.num_seconds()
+            ));
+        }
+        Ok(snapshot)
+    }
+
+    async fn spread(&self) -> Decimal {
+        // Derived per-tick from the venue's own bid/ask rather than a fixed
+        // config value, unlike `FixedPriceFeed`'s configured spread.
+        match *self.snapshot.read().await {
+            Some(snapshot) if !snapshot.mid.is_zero() => {
+                (snapshot.ask - snapshot.bid) / snapshot.mid
+            }
+            _ => dec!(0),
+        }
+    }
 }
 
 impl ExchangeEngine {
-    pub fn new(config: ExchangeConfig) -> Self {
+    pub fn new(config: ExchangeConfig, storage: Arc<CaesarStorage>) -> Self {
+        // Seed a 50/50 pool: half the configured liquidity on each side, with
+        // the USD/CSR reserve ratio matching the configured spot rate.
+        let usd_reserve = config.liquidity_pool / dec!(2);
+        let csr_reserve = if config.csr_usd_rate.is_zero() {
+            config.liquidity_pool / dec!(2)
+        } else {
+            usd_reserve / config.csr_usd_rate
+        };
+        let total_lp_shares = (csr_reserve * usd_reserve).sqrt().unwrap_or(dec!(0));
+
         let market_state = Arc::new(RwLock::new(MarketState {
-            csr_usd_rate: config.csr_usd_rate,
-            liquidity_pool: config.liquidity_pool,
+            csr_reserve,
+            usd_reserve,
+            total_lp_shares,
             volume_24h: dec!(0),
             fees_24h: dec!(0),
             last_update: Utc::now(),
+            last_feed_update: None,
+            bids: Vec::new(),
+            asks: Vec::new(),
         }));
 
+        let mut pairs = HashMap::new();
+        pairs.insert(
+            ("CSR".to_string(), "USD".to_string()),
+            ExchangePairInfo {
+                base_token: "CSR".to_string(),
+                quote_token: "USD".to_string(),
+                base_precision: 8,
+                quote_precision: 2,
+                filters: vec![
+                    TradingFilter::PriceFilter {
+                        min_price: dec!(0.0001),
+                        max_price: dec!(1000000),
+                        tick_size: dec!(0.0001),
+                    },
+                    TradingFilter::LotSize {
+                        min_qty: dec!(0.001),
+                        max_qty: dec!(10000000),
+                        step_size: dec!(0.001),
+                    },
+                    TradingFilter::MinNotional { min_notional: dec!(1) },
+                ],
+            },
+        );
+
         Self {
             config,
             market_state,
+            pairs: Arc::new(RwLock::new(pairs)),
+            storage,
         }
     }
 
-    /// Calculate USD value of CSR tokens
-    pub fn calculate_usd_value(&self, csr_amount: Decimal) -> Result<Decimal> {
-        Ok(csr_amount * self.config.csr_usd_rate)
+    /// Trading filters registered for a `(base, quote)` pair, if any.
+    pub async fn get_pair_info(&self, base_token: &str, quote_token: &str) -> Option<ExchangePairInfo> {
+        self.pairs.read().await.get(&(base_token.to_string(), quote_token.to_string())).cloned()
+    }
+
+    /// Validate `quantity` (traded at `price`) against every filter
+    /// registered for `pair`, snapping it down to the nearest `step_size`
+    /// multiple first. Returns the normalized quantity, or an error naming
+    /// the filter that rejected the trade.
+    fn apply_trading_filters(pair: &ExchangePairInfo, price: Decimal, quantity: Decimal) -> Result<Decimal> {
+        let mut quantity = quantity;
+
+        for filter in &pair.filters {
+            match filter {
+                TradingFilter::LotSize { min_qty, max_qty, step_size } => {
+                    if *step_size > dec!(0) {
+                        quantity = (quantity / step_size).floor() * step_size;
+                    }
+                    if quantity < *min_qty {
+                        return Err(anyhow!(
+                            "Quantity {} is below the pair's minimum {}",
+                            quantity, min_qty
+                        ));
+                    }
+                    if quantity > *max_qty {
+                        return Err(anyhow!(
+                            "Quantity {} exceeds the pair's maximum {}",
+                            quantity, max_qty
+                        ));
+                    }
+                }
+                TradingFilter::PriceFilter { min_price, max_price, tick_size } => {
+                    if price < *min_price || price > *max_price {
+                        return Err(anyhow!(
+                            "Price {} is outside the pair's allowed range [{}, {}]",
+                            price, min_price, max_price
+                        ));
+                    }
+                    if *tick_size > dec!(0) && (price / tick_size).fract() != dec!(0) {
+                        return Err(anyhow!("Price {} is not a multiple of tick size {}", price, tick_size));
+                    }
+                }
+                TradingFilter::MinNotional { min_notional } => {
+                    if price * quantity < *min_notional {
+                        return Err(anyhow!(
+                            "Notional {} is below the pair's minimum {}",
+                            price * quantity, min_notional
+                        ));
+                    }
+                }
+            }
+        }
+
+        Ok(quantity)
+    }
+
+    /// Attach a price feed and forward its mid-price updates into the engine.
+    ///
+    /// Spawns a background task that subscribes to `feed` and calls
+    /// `update_price` for every new mid-price, keeping the ±10% sanity clamp.
+    /// A `FixedPriceFeed` keeps the engine on the configured rate; a
+    /// `WebSocketPriceFeed` tracks a live ticker.
+    pub async fn start_price_feed(self: &Arc<Self>, feed: Arc<dyn PriceFeed>) {
+        let mut rx = feed.subscribe().await;
+        let engine = self.clone();
+
+        tokio::spawn(async move {
+            while rx.changed().await.is_ok() {
+                let rate = *rx.borrow_and_update();
+                if let Err(e) = engine.update_price(rate).await {
+                    debug!("Rejected feed price update {}: {}", rate, e);
+                }
+            }
+        });
+    }
+
+    /// Calculate USD value of CSR tokens, at the pool's live spot rate
+    /// rather than the frozen `ExchangeConfig::csr_usd_rate` it was seeded
+    /// from — the reserves track every swap and feed update since startup.
+    pub async fn calculate_usd_value(&self, csr_amount: Decimal) -> Result<Decimal> {
+        csr_amount.try_mul(self.get_spot_rate().await)
+    }
+
+    /// Current CSR/USD rate implied by the pool reserves, with none of
+    /// `get_rates`'s simulated volatility jitter — for callers (like
+    /// analytics) that want the real mark, not a quote.
+    pub async fn get_spot_rate(&self) -> Decimal {
+        self.market_state.read().await.spot_rate()
     }
 
     /// Get current exchange rates
@@ -61,39 +538,57 @@ impl ExchangeEngine {
             Decimal::from_f64_retain(rand::random::<f64>()).unwrap_or(dec!(0)) - dec!(0.5)
         ) * self.config.volatility * dec!(2);
 
-        let adjusted_rate = state.csr_usd_rate * volatility_factor;
+        let adjusted_rate = state.spot_rate() * volatility_factor;
+        let half_spread = self.config.spread / dec!(2);
+
+        let csr_usd = adjusted_rate;
+        let csr_eth = adjusted_rate.try_div(dec!(3000))?; // Assuming ETH = $3000
+        let csr_btc = adjusted_rate.try_div(dec!(60000))?; // Assuming BTC = $60000
 
         let rates = vec![
             ExchangeRate {
                 from_token: "CSR".to_string(),
                 to_token: "USD".to_string(),
-                rate: adjusted_rate,
-                inverse_rate: dec!(1) / adjusted_rate,
+                rate: csr_usd,
+                inverse_rate: dec!(1).try_div(csr_usd)?,
+                bid: csr_usd.try_mul(dec!(1) - half_spread)?,
+                ask: csr_usd.try_mul(dec!(1) + half_spread)?,
                 timestamp: Utc::now(),
                 volume_24h: state.volume_24h,
             },
             ExchangeRate {
                 from_token: "CSR".to_string(),
                 to_token: "ETH".to_string(),
-                rate: adjusted_rate / dec!(3000), // Assuming ETH = $3000
-                inverse_rate: dec!(3000) / adjusted_rate,
+                rate: csr_eth,
+                inverse_rate: dec!(3000).try_div(adjusted_rate)?,
+                bid: csr_eth.try_mul(dec!(1) - half_spread)?,
+                ask: csr_eth.try_mul(dec!(1) + half_spread)?,
                 timestamp: Utc::now(),
-                volume_24h: state.volume_24h / dec!(10),
+                volume_24h: state.volume_24h.try_div(dec!(10))?,
             },
             ExchangeRate {
                 from_token: "CSR".to_string(),
                 to_token: "BTC".to_string(),
-                rate: adjusted_rate / dec!(60000), // Assuming BTC = $60000
-                inverse_rate: dec!(60000) / adjusted_rate,
+                rate: csr_btc,
+                inverse_rate: dec!(60000).try_div(adjusted_rate)?,
+                bid: csr_btc.try_mul(dec!(1) - half_spread)?,
+                ask: csr_btc.try_mul(dec!(1) + half_spread)?,
                 timestamp: Utc::now(),
-                volume_24h: state.volume_24h / dec!(20),
+                volume_24h: state.volume_24h.try_div(dec!(20))?,
             },
         ];
 
+        // Flag when the external feed has gone quiet (or was never attached).
+        let feed_stale = match state.last_feed_update {
+            Some(ts) => Utc::now() - ts > ChronoDuration::seconds(self.config.quote_max_age_secs),
+            None => true,
+        };
+
         Ok(ExchangeRatesResponse {
             rates,
             base_currency: "CSR".to_string(),
             last_updated: Utc::now(),
+            feed_stale,
         })
     }
 
@@ -101,54 +596,129 @@ impl ExchangeEngine {
     pub async fn swap(&self, request: SwapRequest) -> Result<SwapResponse> {
         let mut state = self.market_state.write().await;
 
-        // Get current rate
-        let rate = if request.from_token == "CSR" && request.to_token == "USD" {
-            state.csr_usd_rate
+        // Resolve which reserve is the input (X) and which is the output (Y).
+        let selling_csr = if request.from_token == "CSR" && request.to_token == "USD" {
+            true
         } else if request.from_token == "USD" && request.to_token == "CSR" {
-            dec!(1) / state.csr_usd_rate
+            false
         } else {
             return Err(anyhow!("Unsupported token pair"));
         };
 
-        // Calculate slippage based on amount relative to liquidity
-        let impact = request.amount / state.liquidity_pool;
-        let slippage = impact * self.config.volatility * dec!(10); // Amplify for larger trades
+        let (reserve_in, reserve_out) = if selling_csr {
+            (state.csr_reserve, state.usd_reserve)
+        } else {
+            (state.usd_reserve, state.csr_reserve)
+        };
 
-        // Check slippage tolerance
-        let tolerance = request.slippage_tolerance.unwrap_or(self.config.slippage_tolerance);
-        if slippage > tolerance {
-            return Err(anyhow!("Slippage {} exceeds tolerance {}", slippage, tolerance));
+        if reserve_in.is_zero() || reserve_out.is_zero() {
+            return Err(anyhow!("Pool has no liquidity"));
         }
 
-        // Adjust rate for slippage
-        let effective_rate = rate * (dec!(1) - slippage);
+        // Pre-trade spot price in the direction of the trade (output per input).
+        let spot_before = reserve_out.try_div(reserve_in)?;
 
-        // Calculate output amount
-        let to_amount = request.amount * effective_rate;
+        // The side of the spread this trade actually executes against: a CSR
+        // seller is paid the bid, a CSR buyer pays the ask. Expressed in the
+        // trade's own output-per-input direction, so it composes with
+        // `spot_before` below without a unit conversion.
+        let half_spread = self.config.spread.try_div(dec!(2))?;
+        let reference_rate = if selling_csr {
+            spot_before.try_mul(dec!(1) - half_spread)?
+        } else {
+            spot_before.try_div(dec!(1) + half_spread)?
+        };
 
-        // Calculate fee (0.3% standard)
-        let fee = request.amount * dec!(0.003);
+        // If the caller locked in a quote (e.g. from `GET /exchange/rates`),
+        // honor it only while it's still fresh and the market hasn't moved
+        // past their slippage tolerance since.
+        if let (Some(quoted_rate), Some(quoted_at)) = (request.quoted_rate, request.quoted_at) {
+            let age = Utc::now() - quoted_at;
+            if age > ChronoDuration::seconds(self.config.quote_max_age_secs) {
+                return Err(anyhow!(
+                    "Quoted rate is {}s old, exceeding the {}s max age",
+                    age.num_seconds(), self.config.quote_max_age_secs
+                ));
+            }
 
-        // Update market state
-        state.volume_24h += request.amount;
-        state.fees_24h += fee;
+            let tolerance = request.slippage_tolerance.unwrap_or(self.config.slippage_tolerance);
+            let drift = reference_rate.try_sub(quoted_rate)?.try_div(quoted_rate)?.abs();
+            if drift > tolerance {
+                return Err(anyhow!(
+                    "Market has moved {} from the quoted rate {}, exceeding tolerance {}",
+                    drift, quoted_rate, tolerance
+                ));
+            }
+        }
 
-        // Simulate price impact
-        if request.from_token == "CSR" {
-            // Selling CSR decreases price
-            state.csr_usd_rate *= dec!(1) - (impact * dec!(0.01));
+        // Normalize the traded amount against the pair's registered filters
+        // (if any) before running it through the curve, snapping it down to
+        // a `step_size` multiple and rejecting it outright if it falls
+        // outside the pair's allowed price/quantity/notional bounds.
+        let amount = if let Some(pair) = self.get_pair_info("CSR", "USD").await {
+            let price = if selling_csr { spot_before } else { dec!(1).try_div(spot_before)? };
+            Self::apply_trading_filters(&pair, price, request.amount)?
         } else {
-            // Buying CSR increases price
-            state.csr_usd_rate *= dec!(1) + (impact * dec!(0.01));
+            request.amount
+        };
+
+        let fee_rate = dec!(0.003);
+        let (to_amount, fee) = match self.config.curve_mode {
+            CurveMode::ConstantProduct => {
+                // Δy = (Y · Δx · (1 − fee)) / (X + Δx · (1 − fee)), fee on input.
+                let amount_in_less_fee = amount.try_mul(dec!(1) - fee_rate)?;
+                let out = reserve_out
+                    .try_mul(amount_in_less_fee)?
+                    .try_div(reserve_in.try_add(amount_in_less_fee)?)?;
+                (out, amount.try_mul(fee_rate)?)
+            }
+            CurveMode::StableSwap => {
+                // Solve the invariant, then the opposite reserve after the trade;
+                // the fee is taken out of the gross reserve delta.
+                let d = stableswap_d(reserve_in, reserve_out, self.config.amplification)?;
+                let y_new = stableswap_y(
+                    reserve_in.try_add(amount)?,
+                    d,
+                    self.config.amplification,
+                )?;
+                let gross = reserve_out.try_sub(y_new)?;
+                let fee = gross.try_mul(fee_rate)?;
+                (gross.try_sub(fee)?, fee)
+            }
+        };
+
+        // Price impact is now emergent: compare the executed rate to the
+        // bid/ask side the trade actually quotes against, not the raw mid.
+        let effective_rate = to_amount.try_div(amount)?;
+        let slippage = reference_rate
+            .try_sub(effective_rate)?
+            .try_div(reference_rate)?
+            .abs();
+
+        // Check slippage tolerance
+        let tolerance = request.slippage_tolerance.unwrap_or(self.config.slippage_tolerance);
+        if slippage > tolerance {
+            return Err(anyhow!("Slippage {} exceeds tolerance {}", slippage, tolerance));
         }
 
+        // Settle the trade against the reserves (the fee stays in the pool).
+        if selling_csr {
+            state.csr_reserve = state.csr_reserve.try_add(amount)?;
+            state.usd_reserve = state.usd_reserve.try_sub(to_amount)?;
+        } else {
+            state.usd_reserve = state.usd_reserve.try_add(amount)?;
+            state.csr_reserve = state.csr_reserve.try_sub(to_amount)?;
+        }
+
+        state.volume_24h = state.volume_24h.try_add(amount)?;
+        state.fees_24h = state.fees_24h.try_add(fee)?;
         state.last_update = Utc::now();
 
         let swap_id = format!("SWAP_{}", Uuid::new_v4());
 
         info!(
             "Swap executed: {} {} -> {} {} at rate {}",
-            request.amount, request.from_token, to_amount, request.to_token, effective_rate
+            amount, request.from_token, to_amount, request.to_token, effective_rate
         );
 
         Ok(SwapResponse {
@@ -156,7 +726,7 @@ impl ExchangeEngine {
             wallet_id: request.wallet_id,
             from_token: request.from_token,
             to_token: request.to_token,
-            from_amount: request.amount,
+            from_amount: amount,
             to_amount,
             rate: effective_rate,
             fee,
@@ -166,74 +736,587 @@ impl ExchangeEngine {
         })
     }
 
+    /// Place a market, limit, or stop-limit order against `CSR/USD`.
+    ///
+    /// Book crossing ([`Self::match_against_book`]) is currently disabled —
+    /// there's no per-wallet USD ledger to settle that leg against — so every
+    /// order falls straight through to the AMM pool the same way a bare
+    /// `swap` would, subject to the order still being marketable at the
+    /// pool's current rate. A `Limit`/`GoodTilCancelled` order that isn't
+    /// marketable simply rests on the book unfilled. `StopLimit` doesn't yet
+    /// track a separate trigger price — it's
+    /// handled identically to `Limit`, using `price` as both the trigger and
+    /// the limit, a deliberate simplification pending a real trigger engine.
+    /// The order (filled, partially filled, resting, or cancelled) is
+    /// persisted through `self.storage` so it survives a restart and can be
+    /// looked up or cancelled later.
+    pub async fn place_order(&self, request: PlaceOrderRequest) -> Result<PlaceOrderResponse> {
+        if request.base_token != "CSR" || request.quote_token != "USD" {
+            return Err(anyhow!("Unsupported trading pair"));
+        }
+        if request.quantity <= dec!(0) {
+            return Err(anyhow!("Order quantity must be positive"));
+        }
+        if matches!(request.order_type, OrderType::Limit | OrderType::StopLimit) && request.price.is_none() {
+            return Err(anyhow!("Limit and stop-limit orders require a price"));
+        }
+
+        let mut order = Order {
+            order_id: format!("ORD_{}", Uuid::new_v4()),
+            wallet_id: request.wallet_id.clone(),
+            base_token: request.base_token.clone(),
+            quote_token: request.quote_token.clone(),
+            side: request.side,
+            order_type: request.order_type,
+            price: request.price,
+            quantity: request.quantity,
+            filled_quantity: dec!(0),
+            status: OrderStatus::New,
+            time_in_force: request.time_in_force,
+            created_at: Utc::now(),
+        };
+
+        let mut fills = self.match_against_book(&mut order).await?;
+
+        let remaining = order.quantity.try_sub(order.filled_quantity)?;
+        if remaining > dec!(0) {
+            let marketable = match order.order_type {
+                OrderType::Market => true,
+                OrderType::Limit | OrderType::StopLimit => {
+                    let spot = self.get_spot_rate().await;
+                    let price = order.price.expect("checked above");
+                    match order.side {
+                        OrderSide::Buy => spot <= price,
+                        OrderSide::Sell => spot >= price,
+                    }
+                }
+            };
+
+            if marketable {
+                match self.fill_order(&order, remaining).await {
+                    Ok((swap, transaction)) => {
+                        let filled = match order.side {
+                            OrderSide::Sell => swap.from_amount,
+                            OrderSide::Buy => swap.to_amount,
+                        };
+                        order.filled_quantity = order.filled_quantity.try_add(filled)?;
+                        fills.push(transaction);
+                    }
+                    Err(e) if order.order_type == OrderType::Market && fills.is_empty() => return Err(e),
+                    Err(_) => {
+                        // Marketable in principle but the pool couldn't honor it
+                        // (e.g. slippage); fall through to resting/cancelling below.
+                    }
+                }
+            }
+        }
+
+        order.status = if order.filled_quantity >= order.quantity {
+            OrderStatus::Filled
+        } else if order.filled_quantity > dec!(0) {
+            match order.time_in_force {
+                TimeInForce::ImmediateOrCancel | TimeInForce::FillOrKill => OrderStatus::Cancelled,
+                TimeInForce::GoodTilCancelled => OrderStatus::PartiallyFilled,
+            }
+        } else {
+            match order.time_in_force {
+                TimeInForce::ImmediateOrCancel | TimeInForce::FillOrKill => OrderStatus::Cancelled,
+                TimeInForce::GoodTilCancelled => OrderStatus::New,
+            }
+        };
+
+        self.storage.create_order(&order).await?;
+        Ok(PlaceOrderResponse { order, fills })
+    }
+
+    /// Cross `order` against resting opposite-side orders on the same pair,
+    /// best price first and oldest-at-that-price first — **disabled**.
+    ///
+    /// `Wallet` carries a single `balance: Decimal`, which this system treats
+    /// as CSR-only; there is no per-wallet USD balance anywhere in the
+    /// storage layer. Crossing two resting `CSR/USD` orders directly (instead
+    /// of through the AMM) would need to move both legs — the CSR leg via a
+    /// wallet-to-wallet transfer, the USD leg via whatever ledger holds a
+    /// user's USD — but only the CSR leg has a real ledger to settle against.
+    /// An earlier version of this function settled the CSR leg anyway and
+    /// skipped the USD leg entirely, which silently gave the buyer CSR for
+    /// free at the seller's expense. Until a real multi-asset ledger exists,
+    /// book crossing is disabled and every order falls through to the AMM
+    /// path in [`Self::place_order`], which settles correctly because a swap
+    /// only ever trades a wallet's own CSR against the pool, never against
+    /// another wallet's unsettled leg.
+    async fn match_against_book(&self, order: &mut Order) -> Result<Vec<Transaction>> {
+        let _ = order;
+        Ok(Vec::new())
+    }
+
+    /// Execute `quantity` of an order against the AMM pool, with no slippage
+    /// limit (the order's own price check already decided it should fill).
+    /// Returns the swap result plus a synthetic `Exchange` transaction
+    /// recording the fill.
+    async fn fill_order(&self, order: &Order, quantity: Decimal) -> Result<(SwapResponse, Transaction)> {
+        let (from_token, to_token, amount) = match order.side {
+            OrderSide::Sell => (order.base_token.clone(), order.quote_token.clone(), quantity),
+            OrderSide::Buy => {
+                // The AMM takes an input amount, but a buy order's quantity is
+                // denominated in the base token it wants to receive — approximate
+                // the quote-token input needed using the order's reference price
+                // (the limit price if set, else the current spot rate).
+                let price = match order.price {
+                    Some(p) => p,
+                    None => self.get_spot_rate().await,
+                };
+                (order.quote_token.clone(), order.base_token.clone(), quantity.try_mul(price)?)
+            }
+        };
+
+        let swap = self
+            .swap(SwapRequest {
+                wallet_id: order.wallet_id.clone(),
+                from_token,
+                to_token,
+                amount,
+                slippage_tolerance: Some(dec!(1)),
+                quoted_rate: None,
+                quoted_at: None,
+            })
+            .await?;
+
+        let transaction = Transaction {
+            transaction_id: swap.transaction_id.clone(),
+            from_wallet: "EXCHANGE_POOL".to_string(),
+            to_wallet: order.wallet_id.clone(),
+            amount: swap.to_amount,
+            transaction_type: TransactionType::Exchange,
+            status: TransactionStatus::Completed,
+            fee: swap.fee,
+            description: format!("Order {} fill: {} {} -> {} {}", order.order_id, swap.from_amount, swap.from_token, swap.to_amount, swap.to_token),
+            timestamp: swap.timestamp,
+            applied_rate: None,
+            memo: None,
+        };
+
+        Ok((swap, transaction))
+    }
+
+    /// Cancel a resting order. Already-terminal orders (`Filled`/`Cancelled`)
+    /// can't be cancelled again; a partially-filled order is cancelled with
+    /// its `filled_quantity` left intact.
+    pub async fn cancel_order(&self, request: CancelOrderRequest) -> Result<Order> {
+        let mut order = self.storage.get_order(&request.order_id).await?;
+
+        if matches!(order.status, OrderStatus::Filled | OrderStatus::Cancelled) {
+            return Err(anyhow!("Order {} is already {:?}", order.order_id, order.status));
+        }
+
+        order.status = OrderStatus::Cancelled;
+        self.storage.update_order(&order).await?;
+        Ok(order)
+    }
+
+    /// Look up one order by id, resting or not.
+    pub async fn get_order(&self, order_id: &str) -> Result<Order> {
+        self.storage.get_order(order_id).await
+    }
+
+    /// Every resting (`New`/`PartiallyFilled`) order on a pair, oldest first.
+    pub async fn list_open_orders(&self, base_token: &str, quote_token: &str) -> Result<Vec<Order>> {
+        self.storage.get_open_orders(base_token, quote_token).await
+    }
+
+    /// Replace the resting order book used by [`Self::simulate_swap`].
+    ///
+    /// Levels are sorted into price priority: bids descending (best bid first),
+    /// asks ascending (best ask first).
+    pub async fn set_order_book(&self, mut bids: Vec<OrderLevel>, mut asks: Vec<OrderLevel>) {
+        bids.sort_by(|a, b| b.price.cmp(&a.price));
+        asks.sort_by(|a, b| a.price.cmp(&b.price));
+
+        let mut state = self.market_state.write().await;
+        state.bids = bids;
+        state.asks = asks;
+    }
+
+    /// Simulate a swap against the resting order book, filling level by level.
+    ///
+    /// Selling CSR walks the bids from the best price; buying CSR walks the
+    /// asks. Each level contributes `min(remaining, level_qty)` until the input
+    /// is exhausted, accumulating the output at that level's price. Returns the
+    /// volume-weighted effective rate and the realized slippage versus the
+    /// top-of-book price, or an error if the book lacks depth to fill the order.
+    pub async fn simulate_swap(&self, request: &SwapRequest) -> Result<OrderBookSwapResult> {
+        let selling_csr = if request.from_token == "CSR" && request.to_token == "USD" {
+            true
+        } else if request.from_token == "USD" && request.to_token == "CSR" {
+            false
+        } else {
+            return Err(anyhow!("Unsupported token pair"));
+        };
+
+        let state = self.market_state.read().await;
+        let levels = if selling_csr { &state.bids } else { &state.asks };
+        let top_of_book = levels
+            .first()
+            .map(|l| l.price)
+            .ok_or_else(|| anyhow!("Order book side is empty"))?;
+
+        let mut remaining = request.amount;
+        let mut to_amount = dec!(0);
+
+        for level in levels {
+            if remaining <= dec!(0) {
+                break;
+            }
+            if selling_csr {
+                // Input is CSR; each bid absorbs up to `quantity` CSR at `price`.
+                let take = remaining.min(level.quantity);
+                to_amount = to_amount.try_add(take.try_mul(level.price)?)?;
+                remaining = remaining.try_sub(take)?;
+            } else {
+                // Input is USD; each ask offers `quantity` CSR costing `quantity·price`.
+                let level_cost = level.quantity.try_mul(level.price)?;
+                let spend = remaining.min(level_cost);
+                to_amount = to_amount.try_add(spend.try_div(level.price)?)?;
+                remaining = remaining.try_sub(spend)?;
+            }
+        }
+
+        if remaining > dec!(0) {
+            return Err(anyhow!("Insufficient order-book depth to fill order"));
+        }
+
+        // Effective rate is output per input; slippage compares it to the top.
+        let effective_rate = to_amount.try_div(request.amount)?;
+        let reference = if selling_csr {
+            top_of_book
+        } else {
+            // Buying CSR: top-of-book is an ask price (USD/CSR); the input-side
+            // reference rate is CSR per USD.
+            dec!(1).try_div(top_of_book)?
+        };
+        let slippage = reference.try_sub(effective_rate)?.try_div(reference)?.abs();
+
+        Ok(OrderBookSwapResult {
+            from_token: request.from_token.clone(),
+            to_token: request.to_token.clone(),
+            from_amount: request.amount,
+            to_amount,
+            effective_rate,
+            slippage,
+            top_of_book,
+        })
+    }
+
     /// Get liquidity pool information
     pub async fn get_liquidity_info(&self) -> Result<LiquidityInfoResponse> {
         let state = self.market_state.read().await;
 
+        // Total liquidity is valued in USD: the USD reserve plus the CSR reserve
+        // marked at the current spot rate.
+        let total_liquidity = state
+            .usd_reserve
+            .try_add(state.csr_reserve.try_mul(state.spot_rate())?)?;
+
         // Calculate APY from fees
-        let annual_fees = state.fees_24h * dec!(365);
-        let apy = (annual_fees / state.liquidity_pool) * dec!(100);
+        let annual_fees = state.fees_24h.try_mul(dec!(365))?;
+        let apy = if total_liquidity.is_zero() {
+            dec!(0)
+        } else {
+            annual_fees.try_div(total_liquidity)?.try_mul(dec!(100))?
+        };
 
         Ok(LiquidityInfoResponse {
-            total_liquidity: state.liquidity_pool,
-            csr_liquidity: state.liquidity_pool / dec!(2), // 50/50 split
-            usd_liquidity: state.liquidity_pool / dec!(2),
+            total_liquidity,
+            csr_liquidity: state.csr_reserve,
+            usd_liquidity: state.usd_reserve,
             volume_24h: state.volume_24h,
             fee_24h: state.fees_24h,
             apy,
         })
     }
 
-    /// Add liquidity to pool
-    pub async fn add_liquidity(&self, amount_csr: Decimal, amount_usd: Decimal) -> Result<()> {
-        let mut state = self.market_state.write().await;
+    /// Get market depth and slippage metrics derived from the AMM reserves.
+    pub async fn get_market_depth(&self) -> Result<MarketDepth> {
+        let state = self.market_state.read().await;
+        let price = state.spot_rate();
+
+        // Liquidity available on each side, valued in USD.
+        let bid_liquidity = state.usd_reserve;
+        let ask_liquidity = state.csr_reserve.try_mul(price)?;
 
-        // Validate ratio matches current rate
-        let expected_usd = amount_csr * state.csr_usd_rate;
-        if (expected_usd - amount_usd).abs() > amount_usd * dec!(0.01) {
-            return Err(anyhow!("Liquidity amounts don't match current rate"));
+        // Constant-product closed form for the CSR that can be sold before
+        // moving the price by 10%: post-trade reserve is X/√0.9, so
+        // Δx = X·(1/√0.9 − 1). Valued in USD at the current spot rate.
+        let depth_factor = dec!(1).try_div(dec!(0.9).sqrt().ok_or_else(|| anyhow!("Depth calculation failed"))?)? - dec!(1);
+        let depth_10_percent = state.csr_reserve.try_mul(depth_factor)?.try_mul(price)?;
+
+        // Slippage on a $100k-notional CSR sale, priced via the same curve
+        // math `swap()` uses, without committing any state.
+        let csr_amount = dec!(100000).try_div(price)?;
+        let slippage_100k = self.quote_sell_slippage(&state, csr_amount)?;
+
+        Ok(MarketDepth {
+            bid_liquidity,
+            ask_liquidity,
+            spread: self.config.spread,
+            depth_10_percent,
+            slippage_100k,
+        })
+    }
+
+    /// Quote the slippage of selling `csr_amount` CSR against the current
+    /// reserves, mirroring [`Self::swap`]'s per-curve math without mutating
+    /// any state.
+    fn quote_sell_slippage(&self, state: &MarketState, csr_amount: Decimal) -> Result<Decimal> {
+        let (reserve_in, reserve_out) = (state.csr_reserve, state.usd_reserve);
+        if reserve_in.is_zero() || reserve_out.is_zero() {
+            return Err(anyhow!("Pool has no liquidity"));
         }
 
-        state.liquidity_pool += amount_csr + amount_usd;
+        let spot_before = reserve_out.try_div(reserve_in)?;
+        let fee_rate = dec!(0.003);
+        let to_amount = match self.config.curve_mode {
+            CurveMode::ConstantProduct => {
+                let amount_in_less_fee = csr_amount.try_mul(dec!(1) - fee_rate)?;
+                reserve_out
+                    .try_mul(amount_in_less_fee)?
+                    .try_div(reserve_in.try_add(amount_in_less_fee)?)?
+            }
+            CurveMode::StableSwap => {
+                let d = stableswap_d(reserve_in, reserve_out, self.config.amplification)?;
+                let y_new = stableswap_y(reserve_in.try_add(csr_amount)?, d, self.config.amplification)?;
+                let gross = reserve_out.try_sub(y_new)?;
+                gross.try_sub(gross.try_mul(fee_rate)?)?
+            }
+        };
+
+        let effective_rate = to_amount.try_div(csr_amount)?;
+        Ok(spot_before.try_sub(effective_rate)?.try_div(spot_before)?.abs())
+    }
+
+    /// Add liquidity to pool, minting LP shares proportional to the deposit.
+    ///
+    /// The first deposit mints `√(csr·usd)` shares and sets the pool ratio;
+    /// subsequent deposits must match the current reserve ratio and mint
+    /// `deposit/reserve · total_shares`. Returns the number of shares minted.
+    pub async fn add_liquidity(&self, amount_csr: Decimal, amount_usd: Decimal) -> Result<Decimal> {
+        let mut state = self.market_state.write().await;
+
+        let minted = if state.total_lp_shares.is_zero() {
+            amount_csr
+                .try_mul(amount_usd)?
+                .sqrt()
+                .ok_or_else(|| anyhow!("Invalid initial deposit"))?
+        } else {
+            // Deposit must match the current reserve ratio (within 1%).
+            let expected_usd = amount_csr.try_mul(state.spot_rate())?;
+            if expected_usd.try_sub(amount_usd)?.abs() > amount_usd.try_mul(dec!(0.01))? {
+                return Err(anyhow!("Liquidity amounts don't match current rate"));
+            }
+            amount_csr.try_div(state.csr_reserve)?.try_mul(state.total_lp_shares)?
+        };
+
+        state.csr_reserve = state.csr_reserve.try_add(amount_csr)?;
+        state.usd_reserve = state.usd_reserve.try_add(amount_usd)?;
+        state.total_lp_shares = state.total_lp_shares.try_add(minted)?;
         state.last_update = Utc::now();
 
-        debug!("Added liquidity: {} CSR, {} USD", amount_csr, amount_usd);
-        Ok(())
+        debug!("Added liquidity: {} CSR, {} USD ({} shares)", amount_csr, amount_usd, minted);
+        Ok(minted)
     }
 
-    /// Remove liquidity from pool
+    /// Remove liquidity by burning LP shares, returning `share/total · reserve`
+    /// of each asset.
     pub async fn remove_liquidity(&self, lp_tokens: Decimal) -> Result<(Decimal, Decimal)> {
         let mut state = self.market_state.write().await;
 
-        if lp_tokens > state.liquidity_pool {
+        if lp_tokens > state.total_lp_shares {
             return Err(anyhow!("Insufficient liquidity"));
         }
 
-        let percentage = lp_tokens / state.liquidity_pool;
-        let csr_amount = (state.liquidity_pool / dec!(2)) * percentage;
-        let usd_amount = (state.liquidity_pool / dec!(2)) * percentage;
+        let share = lp_tokens.try_div(state.total_lp_shares)?;
+        let csr_amount = state.csr_reserve.try_mul(share)?;
+        let usd_amount = state.usd_reserve.try_mul(share)?;
 
-        state.liquidity_pool -= lp_tokens;
+        state.csr_reserve = state.csr_reserve.try_sub(csr_amount)?;
+        state.usd_reserve = state.usd_reserve.try_sub(usd_amount)?;
+        state.total_lp_shares = state.total_lp_shares.try_sub(lp_tokens)?;
         state.last_update = Utc::now();
 
         debug!("Removed liquidity: {} CSR, {} USD", csr_amount, usd_amount);
         Ok((csr_amount, usd_amount))
     }
 
-    /// Update market price (oracle or external feed)
+    /// Update market price (oracle or external feed).
+    ///
+    /// Nudges the reserves along the constant-product curve so the new spot
+    /// rate matches `new_rate` while keeping `k = csr·usd` invariant, as an
+    /// arbitrageur would. The ±10% per-update sanity clamp is preserved.
     pub async fn update_price(&self, new_rate: Decimal) -> Result<()> {
         let mut state = self.market_state.write().await;
 
+        let current = state.spot_rate();
+        if current.is_zero() {
+            return Err(anyhow!("Pool has no liquidity"));
+        }
+
         // Validate reasonable price change (max 10% per update)
-        let change = ((new_rate - state.csr_usd_rate) / state.csr_usd_rate).abs();
+        let change = ((new_rate - current) / current).abs();
         if change > dec!(0.1) {
             return Err(anyhow!("Price change {} exceeds 10% limit", change));
         }
 
-        state.csr_usd_rate = new_rate;
+        // Hold k constant and solve for reserves at the target price p = Y/X:
+        //   X = √(k/p),  Y = √(k·p).
+        let k = state.csr_reserve * state.usd_reserve;
+        state.csr_reserve = (k / new_rate).sqrt().ok_or_else(|| anyhow!("Invalid target rate"))?;
+        state.usd_reserve = (k * new_rate).sqrt().ok_or_else(|| anyhow!("Invalid target rate"))?;
         state.last_update = Utc::now();
+        state.last_feed_update = Some(Utc::now());
 
         info!("Market price updated to {} USD/CSR", new_rate);
         Ok(())
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CaesarConfig;
+
+    async fn engine_with_pool(pool: Decimal) -> ExchangeEngine {
+        let mut config = CaesarConfig::default().exchange;
+        config.liquidity_pool = pool;
+        let storage = Arc::new(
+            crate::storage::CaesarStorage::new(CaesarConfig::default().database)
+                .await
+                .expect("failed to create in-memory test storage"),
+        );
+        ExchangeEngine::new(config, storage)
+    }
+
+    fn sell_csr(amount: Decimal) -> SwapRequest {
+        SwapRequest {
+            wallet_id: "wallet".to_string(),
+            from_token: "CSR".to_string(),
+            to_token: "USD".to_string(),
+            amount,
+            slippage_tolerance: Some(dec!(1)),
+            quoted_rate: None,
+            quoted_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_zero_liquidity_swap_errors_cleanly() {
+        let engine = engine_with_pool(dec!(0)).await;
+        // An empty pool must return an error rather than panicking on a divide.
+        assert!(engine.swap(sell_csr(dec!(100))).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_near_overflow_trade_errors_cleanly() {
+        let engine = engine_with_pool(dec!(1000000)).await;
+        // An extreme trade overflows the reserve math and must surface an error.
+        assert!(engine.swap(sell_csr(Decimal::MAX)).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_market_order_fills_immediately() {
+        let engine = engine_with_pool(dec!(1000000)).await;
+        let response = engine
+            .place_order(PlaceOrderRequest {
+                wallet_id: "wallet".to_string(),
+                base_token: "CSR".to_string(),
+                quote_token: "USD".to_string(),
+                side: OrderSide::Sell,
+                order_type: OrderType::Market,
+                price: None,
+                quantity: dec!(100),
+                time_in_force: TimeInForce::GoodTilCancelled,
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.order.status, OrderStatus::Filled);
+        assert_eq!(response.fills.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_unmarketable_limit_order_rests() {
+        let engine = engine_with_pool(dec!(1000000)).await;
+        let spot = engine.get_spot_rate().await;
+        let response = engine
+            .place_order(PlaceOrderRequest {
+                wallet_id: "wallet".to_string(),
+                base_token: "CSR".to_string(),
+                quote_token: "USD".to_string(),
+                side: OrderSide::Sell,
+                order_type: OrderType::Limit,
+                // Priced far above spot, so a sell order can't possibly fill.
+                price: Some(spot.try_mul(dec!(10)).unwrap()),
+                quantity: dec!(100),
+                time_in_force: TimeInForce::GoodTilCancelled,
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.order.status, OrderStatus::New);
+        assert!(response.fills.is_empty());
+
+        let cancelled = engine
+            .cancel_order(CancelOrderRequest { order_id: response.order.order_id })
+            .await
+            .unwrap();
+        assert_eq!(cancelled.status, OrderStatus::Cancelled);
+    }
+
+    #[tokio::test]
+    async fn test_incoming_order_fills_against_amm_not_resting_book_order() {
+        let engine = engine_with_pool(dec!(1000000)).await;
+        let spot = engine.get_spot_rate().await;
+
+        // Rest a bid priced above spot. With book crossing disabled (no
+        // per-wallet USD ledger to settle that leg against), an incoming
+        // sell must NOT cross it directly — it should fill through the AMM
+        // instead, and the resting order must be left untouched.
+        let bid_price = spot.try_mul(dec!(2)).unwrap();
+        let resting = engine
+            .place_order(PlaceOrderRequest {
+                wallet_id: "buyer".to_string(),
+                base_token: "CSR".to_string(),
+                quote_token: "USD".to_string(),
+                side: OrderSide::Buy,
+                order_type: OrderType::Limit,
+                price: Some(bid_price),
+                quantity: dec!(50),
+                time_in_force: TimeInForce::GoodTilCancelled,
+            })
+            .await
+            .unwrap();
+        assert_eq!(resting.order.status, OrderStatus::New);
+
+        let response = engine
+            .place_order(PlaceOrderRequest {
+                wallet_id: "seller".to_string(),
+                base_token: "CSR".to_string(),
+                quote_token: "USD".to_string(),
+                side: OrderSide::Sell,
+                order_type: OrderType::Market,
+                price: None,
+                quantity: dec!(20),
+                time_in_force: TimeInForce::GoodTilCancelled,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(response.order.status, OrderStatus::Filled);
+        assert_eq!(response.fills.len(), 1);
+        assert_eq!(response.fills[0].amount, dec!(20));
+        // The fill is an AMM settlement (pool on one side), not a trade
+        // against the resting buy order's wallet.
+        assert_eq!(response.fills[0].from_wallet, "EXCHANGE_POOL");
+        assert_eq!(response.fills[0].to_wallet, "seller");
+
+        let resting_after = engine.get_order(&resting.order.order_id).await.unwrap();
+        assert_eq!(resting_after.status, OrderStatus::New);
+        assert_eq!(resting_after.filled_quantity, dec!(0));
+    }
+}
@@ -10,18 +10,19 @@
 use anyhow::{Result, anyhow};
 use async_trait::async_trait;
 use axum::{
+    extract::ws::{Message, WebSocket, WebSocketUpgrade},
     extract::{Path, Query, State},
-    http::StatusCode,
-    response::Json,
+    response::{IntoResponse, Json, Response},
     routing::{get, post, put, delete},
     Router,
 };
 use chrono::{DateTime, Utc, Duration};
+use futures_util::{SinkExt, StreamExt};
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{broadcast, RwLock};
 use tracing::{info, warn, error, debug};
 use uuid::Uuid;
 use std::collections::HashMap;
@@ -35,21 +36,40 @@ pub mod storage;
 pub mod rewards;
 pub mod staking;
 pub mod exchange;
+pub mod bonding_curve;
+pub mod settlement;
+pub mod wallet_backend;
+pub mod wallet_file;
 pub mod transactions;
 pub mod analytics;
 pub mod banking_interop_bridge;
 pub mod banking_providers;
 pub mod crypto_exchange_providers;
 pub mod cross_chain_bridge;
+pub mod backup;
+pub mod webhooks;
+pub mod realtime;
+pub mod rpc;
+pub mod error;
+pub mod payment_requests;
 
 use models::*;
 use storage::CaesarStorage;
 use rewards::RewardCalculator;
 use staking::StakingManager;
 use exchange::ExchangeEngine;
+use bonding_curve::BondingCurveEngine;
+use settlement::{SettlementBackend, TransferStatus, BridgeToChainRequest, BridgeToChainResponse, BridgeFromChainResponse};
+use wallet_backend::{ExternalWallet, HttpRpcWallet, WalletBackendConfig};
+use wallet_file::{WalletFile, WalletFileManager};
 use transactions::TransactionProcessor;
 use analytics::AnalyticsEngine;
-use cross_chain_bridge::CrossChainBridge;
+use cross_chain_bridge::{CrossChainBridge, HtlcSwap, InitHtlcSwapRequest, HtlcSwapResponse, RedeemHtlcSwapRequest, HtlcSwapsResponse};
+use backup::BackupManager;
+use webhooks::{WebhookManager, WebhookEndpoint, WebhookEventType, ResendWebhooksResponse, ResendTransactionWebhooksRequest};
+use realtime::RealtimeHub;
+use error::CaesarError;
+use payment_requests::{PaymentRequestManager, PaymentRequest, PaymentRequestPreview, CreatePaymentRequestRequest, ParsePaymentRequestRequest};
 
 /// Caesar Economic System Configuration
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -66,8 +86,14 @@ pub struct CaesarConfig {
     /// Exchange settings
     pub exchange: ExchangeConfig,
 
+    /// Bonding-curve primary-issuance settings
+    pub bonding_curve: BondingCurveConfig,
+
     /// Database configuration
     pub database: DatabaseConfig,
+
+    /// External chain wallet backend settings
+    pub wallet_backend: WalletBackendConfig,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -86,6 +112,21 @@ pub struct EconomicsConfig {
 
     /// Transaction fee percentage
     pub transaction_fee: Decimal,
+
+    /// Maximum age, in seconds, of a conversion rate before it is rejected as stale
+    pub max_rate_age_secs: i64,
+
+    /// Seconds between block-height polls while waiting for finality
+    pub finality_poll_interval_secs: u64,
+
+    /// Block confirmations required before a transaction is considered final
+    pub required_confirmations: u64,
+
+    /// Wallet that collects transaction fees
+    pub treasury_wallet: String,
+
+    /// Window, in seconds, over which rolling transactions-per-second is computed
+    pub stats_tps_window_secs: i64,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -107,6 +148,21 @@ pub struct RewardConfig {
 
     /// Asset hosting multiplier
     pub hosting_multiplier: Decimal,
+
+    /// Target share of supply that should be locked/participating
+    pub target_locked_ratio: Decimal,
+
+    /// Proportional gain of the inflation controller
+    pub inflation_p_gain: Decimal,
+
+    /// Derivative gain of the inflation controller
+    pub inflation_d_gain: Decimal,
+
+    /// Maximum annual inflation the controller may emit
+    pub max_inflation: Decimal,
+
+    /// Hours in an emission epoch, used to spread inflation over the epoch
+    pub epoch_hours: Decimal,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -125,6 +181,30 @@ pub struct StakingConfig {
 
     /// Compound frequency in hours
     pub compound_frequency_hours: u32,
+
+    /// Maximum share (percent) of currently-effective network stake that may
+    /// activate, or cool down, per activation epoch
+    pub warmup_cooldown_rate: Decimal,
+
+    /// Target fraction of total supply that should be actively staked
+    pub target_locked_ratio: Decimal,
+
+    /// Proportional gain of the staking inflation PD controller
+    pub inflation_p_gain: Decimal,
+
+    /// Derivative gain of the staking inflation PD controller
+    pub inflation_d_gain: Decimal,
+
+    /// Maximum annual inflation the staking controller may emit
+    pub max_inflation: Decimal,
+
+    /// Hours in one reward distribution epoch, used to scale the annual
+    /// inflation emission down to a single epoch's pool
+    pub epoch_hours: Decimal,
+
+    /// Number of deterministic partitions a reward distribution epoch is
+    /// split into, so one call only credits a slice of active stakes
+    pub reward_partitions: u32,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -140,6 +220,55 @@ pub struct ExchangeConfig {
 
     /// Slippage tolerance percentage
     pub slippage_tolerance: Decimal,
+
+    /// Bid/ask spread applied symmetrically around the mid-rate
+    pub spread: Decimal,
+
+    /// Which invariant curve swaps trade against
+    pub curve_mode: CurveMode,
+
+    /// StableSwap amplification coefficient (higher = flatter near the peg)
+    pub amplification: Decimal,
+
+    /// How old a client-signed quote (`SwapRequest::quoted_rate`/`quoted_at`)
+    /// or the external rate feed may be before it's treated as stale.
+    pub quote_max_age_secs: i64,
+}
+
+/// Invariant curve a swap trades against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CurveMode {
+    /// Uniswap-style constant product `x·y=k`.
+    ConstantProduct,
+    /// StableSwap invariant tuned for assets trading near a reference peg.
+    StableSwap,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BondingCurveConfig {
+    /// Which pricing curve primary issuance mints/burns against
+    pub curve: BondingCurveKind,
+
+    /// Fraction of a sell's reserve payout withheld as an exit fee
+    pub exit_fee: Decimal,
+
+    /// Wallet credited with exit fees; fees are simply not collected if unset
+    pub fee_wallet: Option<String>,
+}
+
+/// A [`crate::bonding_curve::Curve`] and its parameters, in a form that can
+/// round-trip through config. The engine builds the actual `Curve` trait
+/// object from this on startup.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum BondingCurveKind {
+    /// Flat price regardless of supply.
+    Constant { value: Decimal },
+    /// Price rises linearly with supply: `min_price + slope * supply`.
+    Linear { slope: Decimal, min_price: Decimal },
+    /// Price rises with the square root of supply: `scale * sqrt(supply)`.
+    SquareRoot { scale: Decimal },
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -171,6 +300,9 @@ pub struct CaesarEconomicSystem {
     /// Exchange engine
     exchange: Arc<ExchangeEngine>,
 
+    /// Bonding-curve primary issuance
+    bonding_curve: Arc<BondingCurveEngine>,
+
     /// Transaction processor
     transactions: Arc<TransactionProcessor>,
 
@@ -180,9 +312,35 @@ pub struct CaesarEconomicSystem {
     /// Cross-chain bridge for "mostly-stable" token
     bridge: Arc<CrossChainBridge>,
 
+    /// Optional EVM settlement backend for `bridge_to_chain`/`bridge_from_chain`.
+    /// `None` until a chain RPC endpoint is configured; the in-memory economy
+    /// runs fine without one.
+    settlement: Option<Arc<dyn SettlementBackend>>,
+
+    /// Encrypted wallet backup/restore
+    backup: Arc<BackupManager>,
+
+    /// Versioned, passphrase-recoverable single-wallet backup files
+    wallet_files: Arc<WalletFileManager>,
+
+    /// Webhook subscription/delivery tracking
+    webhooks: Arc<WebhookManager>,
+
+    /// Live WebSocket fan-out for balance/rewards/rate deltas
+    realtime: Arc<RealtimeHub>,
+
+    /// Signed payment-request URI minting/resolution
+    payment_requests: Arc<PaymentRequestManager>,
+
     /// Active sessions cache
     sessions: Arc<RwLock<HashMap<String, UserSession>>>,
 
+    /// Live [`ExternalWallet`] backends, keyed by Caesar wallet id. Lazily
+    /// populated from the wallet's stored `external_descriptor` on first
+    /// access rather than at startup, so a wallet with no external pairing
+    /// never constructs one.
+    external_wallets: RwLock<HashMap<String, Arc<dyn ExternalWallet>>>,
+
     /// HyperMesh Asset Manager integration
     #[cfg(feature = "hypermesh")]
     asset_manager: Option<Arc<AssetManager>>,
@@ -198,6 +356,13 @@ pub struct UserSession {
     pub active_stakes: Vec<StakeInfo>,
 }
 
+/// A client's request, sent as a WebSocket text frame, to start receiving
+/// deltas from one or more [`realtime`] channels on this connection.
+#[derive(Debug, Clone, Deserialize)]
+struct WsSubscribeRequest {
+    subscribe: Vec<String>,
+}
+
 impl CaesarEconomicSystem {
     /// Create new Caesar economic system
     pub async fn new(config: CaesarConfig) -> Result<Self> {
@@ -210,13 +375,31 @@ impl CaesarEconomicSystem {
 
         // Initialize components
         let rewards = Arc::new(RewardCalculator::new(config.rewards.clone(), storage.clone()));
-        let staking = Arc::new(StakingManager::new(config.staking.clone(), storage.clone()).await?);
-        let exchange = Arc::new(ExchangeEngine::new(config.exchange.clone()));
+        let staking = Arc::new(
+            StakingManager::new(config.staking.clone(), config.economics.total_supply, storage.clone()).await?,
+        );
+        let exchange = Arc::new(ExchangeEngine::new(config.exchange.clone(), storage.clone()));
+        let bonding_curve = Arc::new(BondingCurveEngine::new(config.bonding_curve.clone(), storage.clone()));
         let transactions = Arc::new(TransactionProcessor::new(config.economics.clone(), storage.clone()).await?);
-        let analytics = Arc::new(AnalyticsEngine::new(storage.clone()).await?);
-        let bridge = Arc::new(CrossChainBridge::new().await?);
+
+        // Replay any transfers a prior crash left half-applied in the journal.
+        match transactions.recover().await {
+            Ok(0) => {}
+            Ok(n) => info!("Recovered {} journaled transfer(s) on startup", n),
+            Err(e) => error!("Transfer journal recovery failed: {}", e),
+        }
+
+        let analytics = Arc::new(AnalyticsEngine::new(storage.clone(), staking.clone(), exchange.clone()).await?);
+        let bridge = Arc::new(CrossChainBridge::new(storage.clone()).await?);
+        CrossChainBridge::spawn_htlc_reaper(storage.clone());
+        let backup = Arc::new(BackupManager::new(storage.clone()));
+        let wallet_files = Arc::new(WalletFileManager::new(storage.clone()));
+        let webhooks = Arc::new(WebhookManager::new());
+        let realtime = Arc::new(RealtimeHub::new());
+        let payment_requests = Arc::new(PaymentRequestManager::new());
 
         let sessions = Arc::new(RwLock::new(HashMap::new()));
+        let external_wallets = RwLock::new(HashMap::new());
 
         Ok(Self {
             config,
@@ -224,10 +407,18 @@ impl CaesarEconomicSystem {
             rewards,
             staking,
             exchange,
+            bonding_curve,
             transactions,
             analytics,
             bridge,
+            settlement: None, // Will be set when a chain RPC endpoint is configured
+            backup,
+            wallet_files,
+            webhooks,
+            realtime,
+            payment_requests,
             sessions,
+            external_wallets,
             #[cfg(feature = "hypermesh")]
             asset_manager: None, // Will be set when integrated with HyperMesh
         })
@@ -262,11 +453,40 @@ impl CaesarEconomicSystem {
             .route("/api/v1/caesar/exchange/rates", get(Self::get_exchange_rates))
             .route("/api/v1/caesar/exchange/swap", post(Self::swap_tokens))
             .route("/api/v1/caesar/exchange/liquidity", get(Self::get_liquidity_info))
+            .route("/api/v1/caesar/exchange/orders", get(Self::get_open_orders))
+            .route("/api/v1/caesar/exchange/orders/place", post(Self::place_order))
+            .route("/api/v1/caesar/exchange/orders/:id", delete(Self::cancel_order))
+
+            // Bonding curve endpoints
+            .route("/api/v1/caesar/exchange/curve", get(Self::get_bonding_curve_info))
+            .route("/api/v1/caesar/exchange/curve/buy", post(Self::bonding_curve_buy))
+            .route("/api/v1/caesar/exchange/curve/sell", post(Self::bonding_curve_sell))
 
             // Analytics endpoints
             .route("/api/v1/caesar/analytics/overview", get(Self::get_analytics_overview))
             .route("/api/v1/caesar/analytics/earnings", get(Self::get_earnings_breakdown))
 
+            // Cross-chain HTLC swap endpoints
+            .route("/api/v1/caesar/bridge/swap", get(Self::get_htlc_swaps))
+            .route("/api/v1/caesar/bridge/swap/init", post(Self::init_htlc_swap))
+            .route("/api/v1/caesar/bridge/swap/redeem/:id", post(Self::redeem_htlc_swap))
+            .route("/api/v1/caesar/bridge/swap/refund/:id", post(Self::refund_htlc_swap))
+            .route("/api/v1/caesar/bridge/swap/status/:id", get(Self::get_htlc_swap_status))
+
+            // EVM settlement endpoints
+            .route("/api/v1/caesar/bridge/chain/deposit", post(Self::bridge_to_chain))
+            .route("/api/v1/caesar/bridge/chain/confirm/:tx_hash", post(Self::bridge_from_chain))
+
+            // Realtime streaming endpoint
+            .route("/api/v1/caesar/ws", get(Self::ws_upgrade))
+
+            // JSON-RPC 2.0 endpoint
+            .route("/api/v1/caesar/rpc", post(Self::rpc_endpoint))
+
+            // Payment request endpoints
+            .route("/api/v1/caesar/wallet/:id/request", post(Self::create_payment_request))
+            .route("/api/v1/caesar/payments/parse", post(Self::parse_payment_request))
+
             .with_state(self)
     }
 
@@ -274,15 +494,15 @@ impl CaesarEconomicSystem {
     async fn get_wallet(
         State(caesar): State<Arc<CaesarEconomicSystem>>,
         Query(params): Query<HashMap<String, String>>,
-    ) -> Result<Json<WalletResponse>, StatusCode> {
+    ) -> Result<Json<WalletResponse>, CaesarError> {
         let wallet_id = params.get("wallet_id")
-            .ok_or(StatusCode::BAD_REQUEST)?;
+            .ok_or_else(|| CaesarError::invalid_request("wallet_id query parameter is required"))?;
 
         match caesar.get_wallet_info(wallet_id).await {
             Ok(wallet) => Ok(Json(wallet)),
             Err(e) => {
                 error!("Failed to get wallet: {}", e);
-                Err(StatusCode::INTERNAL_SERVER_ERROR)
+                Err(CaesarError::from(e))
             }
         }
     }
@@ -291,12 +511,12 @@ impl CaesarEconomicSystem {
     async fn get_balance(
         State(caesar): State<Arc<CaesarEconomicSystem>>,
         Path(wallet_id): Path<String>,
-    ) -> Result<Json<BalanceResponse>, StatusCode> {
+    ) -> Result<Json<BalanceResponse>, CaesarError> {
         match caesar.get_wallet_balance(&wallet_id).await {
             Ok(balance) => Ok(Json(balance)),
             Err(e) => {
                 error!("Failed to get balance: {}", e);
-                Err(StatusCode::INTERNAL_SERVER_ERROR)
+                Err(CaesarError::from(e))
             }
         }
     }
@@ -305,12 +525,12 @@ impl CaesarEconomicSystem {
     async fn create_wallet(
         State(caesar): State<Arc<CaesarEconomicSystem>>,
         Json(request): Json<CreateWalletRequest>,
-    ) -> Result<Json<WalletResponse>, StatusCode> {
+    ) -> Result<Json<WalletResponse>, CaesarError> {
         match caesar.create_new_wallet(request).await {
             Ok(wallet) => Ok(Json(wallet)),
             Err(e) => {
                 error!("Failed to create wallet: {}", e);
-                Err(StatusCode::INTERNAL_SERVER_ERROR)
+                Err(CaesarError::from(e))
             }
         }
     }
@@ -319,15 +539,15 @@ impl CaesarEconomicSystem {
     async fn get_transactions(
         State(caesar): State<Arc<CaesarEconomicSystem>>,
         Query(params): Query<HashMap<String, String>>,
-    ) -> Result<Json<TransactionsResponse>, StatusCode> {
+    ) -> Result<Json<TransactionsResponse>, CaesarError> {
         let wallet_id = params.get("wallet_id")
-            .ok_or(StatusCode::BAD_REQUEST)?;
+            .ok_or_else(|| CaesarError::invalid_request("wallet_id query parameter is required"))?;
 
         match caesar.get_wallet_transactions(wallet_id).await {
             Ok(transactions) => Ok(Json(transactions)),
             Err(e) => {
                 error!("Failed to get transactions: {}", e);
-                Err(StatusCode::INTERNAL_SERVER_ERROR)
+                Err(CaesarError::from(e))
             }
         }
     }
@@ -336,12 +556,12 @@ impl CaesarEconomicSystem {
     async fn get_transaction(
         State(caesar): State<Arc<CaesarEconomicSystem>>,
         Path(tx_id): Path<String>,
-    ) -> Result<Json<TransactionResponse>, StatusCode> {
+    ) -> Result<Json<TransactionResponse>, CaesarError> {
         match caesar.get_transaction_details(&tx_id).await {
             Ok(transaction) => Ok(Json(transaction)),
             Err(e) => {
                 error!("Failed to get transaction: {}", e);
-                Err(StatusCode::NOT_FOUND)
+                Err(CaesarError::from(e))
             }
         }
     }
@@ -350,12 +570,12 @@ impl CaesarEconomicSystem {
     async fn send_transaction(
         State(caesar): State<Arc<CaesarEconomicSystem>>,
         Json(request): Json<SendTransactionRequest>,
-    ) -> Result<Json<TransactionResponse>, StatusCode> {
+    ) -> Result<Json<TransactionResponse>, CaesarError> {
         match caesar.process_transaction(request).await {
             Ok(transaction) => Ok(Json(transaction)),
             Err(e) => {
                 error!("Failed to send transaction: {}", e);
-                Err(StatusCode::INTERNAL_SERVER_ERROR)
+                Err(CaesarError::from(e))
             }
         }
     }
@@ -364,15 +584,15 @@ impl CaesarEconomicSystem {
     async fn get_rewards(
         State(caesar): State<Arc<CaesarEconomicSystem>>,
         Query(params): Query<HashMap<String, String>>,
-    ) -> Result<Json<RewardsResponse>, StatusCode> {
+    ) -> Result<Json<RewardsResponse>, CaesarError> {
         let wallet_id = params.get("wallet_id")
-            .ok_or(StatusCode::BAD_REQUEST)?;
+            .ok_or_else(|| CaesarError::invalid_request("wallet_id query parameter is required"))?;
 
         match caesar.get_rewards_info(wallet_id).await {
             Ok(rewards) => Ok(Json(rewards)),
             Err(e) => {
                 error!("Failed to get rewards: {}", e);
-                Err(StatusCode::INTERNAL_SERVER_ERROR)
+                Err(CaesarError::from(e))
             }
         }
     }
@@ -381,12 +601,12 @@ impl CaesarEconomicSystem {
     async fn claim_rewards(
         State(caesar): State<Arc<CaesarEconomicSystem>>,
         Json(request): Json<ClaimRewardsRequest>,
-    ) -> Result<Json<ClaimRewardsResponse>, StatusCode> {
+    ) -> Result<Json<ClaimRewardsResponse>, CaesarError> {
         match caesar.claim_pending_rewards(request).await {
             Ok(response) => Ok(Json(response)),
             Err(e) => {
                 error!("Failed to claim rewards: {}", e);
-                Err(StatusCode::INTERNAL_SERVER_ERROR)
+                Err(CaesarError::from(e))
             }
         }
     }
@@ -395,15 +615,15 @@ impl CaesarEconomicSystem {
     async fn get_reward_history(
         State(caesar): State<Arc<CaesarEconomicSystem>>,
         Query(params): Query<HashMap<String, String>>,
-    ) -> Result<Json<RewardHistoryResponse>, StatusCode> {
+    ) -> Result<Json<RewardHistoryResponse>, CaesarError> {
         let wallet_id = params.get("wallet_id")
-            .ok_or(StatusCode::BAD_REQUEST)?;
+            .ok_or_else(|| CaesarError::invalid_request("wallet_id query parameter is required"))?;
 
         match caesar.get_reward_history_for_wallet(wallet_id).await {
             Ok(history) => Ok(Json(history)),
             Err(e) => {
                 error!("Failed to get reward history: {}", e);
-                Err(StatusCode::INTERNAL_SERVER_ERROR)
+                Err(CaesarError::from(e))
             }
         }
     }
@@ -412,12 +632,12 @@ impl CaesarEconomicSystem {
     async fn calculate_rewards(
         State(caesar): State<Arc<CaesarEconomicSystem>>,
         Json(request): Json<CalculateRewardsRequest>,
-    ) -> Result<Json<CalculateRewardsResponse>, StatusCode> {
+    ) -> Result<Json<CalculateRewardsResponse>, CaesarError> {
         match caesar.calculate_resource_rewards(request).await {
             Ok(response) => Ok(Json(response)),
             Err(e) => {
                 error!("Failed to calculate rewards: {}", e);
-                Err(StatusCode::INTERNAL_SERVER_ERROR)
+                Err(CaesarError::from(e))
             }
         }
     }
@@ -426,15 +646,15 @@ impl CaesarEconomicSystem {
     async fn get_staking_info(
         State(caesar): State<Arc<CaesarEconomicSystem>>,
         Query(params): Query<HashMap<String, String>>,
-    ) -> Result<Json<StakingInfoResponse>, StatusCode> {
+    ) -> Result<Json<StakingInfoResponse>, CaesarError> {
         let wallet_id = params.get("wallet_id")
-            .ok_or(StatusCode::BAD_REQUEST)?;
+            .ok_or_else(|| CaesarError::invalid_request("wallet_id query parameter is required"))?;
 
         match caesar.get_staking_details(wallet_id).await {
             Ok(info) => Ok(Json(info)),
             Err(e) => {
                 error!("Failed to get staking info: {}", e);
-                Err(StatusCode::INTERNAL_SERVER_ERROR)
+                Err(CaesarError::from(e))
             }
         }
     }
@@ -443,12 +663,12 @@ impl CaesarEconomicSystem {
     async fn stake_tokens(
         State(caesar): State<Arc<CaesarEconomicSystem>>,
         Json(request): Json<StakeRequest>,
-    ) -> Result<Json<StakeResponse>, StatusCode> {
+    ) -> Result<Json<StakeResponse>, CaesarError> {
         match caesar.stake_tokens_for_wallet(request).await {
             Ok(response) => Ok(Json(response)),
             Err(e) => {
                 error!("Failed to stake tokens: {}", e);
-                Err(StatusCode::INTERNAL_SERVER_ERROR)
+                Err(CaesarError::from(e))
             }
         }
     }
@@ -457,12 +677,12 @@ impl CaesarEconomicSystem {
     async fn unstake_tokens(
         State(caesar): State<Arc<CaesarEconomicSystem>>,
         Json(request): Json<UnstakeRequest>,
-    ) -> Result<Json<UnstakeResponse>, StatusCode> {
+    ) -> Result<Json<UnstakeResponse>, CaesarError> {
         match caesar.unstake_tokens_for_wallet(request).await {
             Ok(response) => Ok(Json(response)),
             Err(e) => {
                 error!("Failed to unstake tokens: {}", e);
-                Err(StatusCode::INTERNAL_SERVER_ERROR)
+                Err(CaesarError::from(e))
             }
         }
     }
@@ -471,15 +691,15 @@ impl CaesarEconomicSystem {
     async fn get_staking_rewards(
         State(caesar): State<Arc<CaesarEconomicSystem>>,
         Query(params): Query<HashMap<String, String>>,
-    ) -> Result<Json<StakingRewardsResponse>, StatusCode> {
+    ) -> Result<Json<StakingRewardsResponse>, CaesarError> {
         let wallet_id = params.get("wallet_id")
-            .ok_or(StatusCode::BAD_REQUEST)?;
+            .ok_or_else(|| CaesarError::invalid_request("wallet_id query parameter is required"))?;
 
         match caesar.calculate_staking_rewards(wallet_id).await {
             Ok(rewards) => Ok(Json(rewards)),
             Err(e) => {
                 error!("Failed to get staking rewards: {}", e);
-                Err(StatusCode::INTERNAL_SERVER_ERROR)
+                Err(CaesarError::from(e))
             }
         }
     }
@@ -487,12 +707,12 @@ impl CaesarEconomicSystem {
     /// Get exchange rates
     async fn get_exchange_rates(
         State(caesar): State<Arc<CaesarEconomicSystem>>,
-    ) -> Result<Json<ExchangeRatesResponse>, StatusCode> {
+    ) -> Result<Json<ExchangeRatesResponse>, CaesarError> {
         match caesar.get_current_exchange_rates().await {
             Ok(rates) => Ok(Json(rates)),
             Err(e) => {
                 error!("Failed to get exchange rates: {}", e);
-                Err(StatusCode::INTERNAL_SERVER_ERROR)
+                Err(CaesarError::from(e))
             }
         }
     }
@@ -501,12 +721,12 @@ impl CaesarEconomicSystem {
     async fn swap_tokens(
         State(caesar): State<Arc<CaesarEconomicSystem>>,
         Json(request): Json<SwapRequest>,
-    ) -> Result<Json<SwapResponse>, StatusCode> {
+    ) -> Result<Json<SwapResponse>, CaesarError> {
         match caesar.execute_token_swap(request).await {
             Ok(response) => Ok(Json(response)),
             Err(e) => {
                 error!("Failed to swap tokens: {}", e);
-                Err(StatusCode::INTERNAL_SERVER_ERROR)
+                Err(CaesarError::from(e))
             }
         }
     }
@@ -514,12 +734,98 @@ impl CaesarEconomicSystem {
     /// Get liquidity information
     async fn get_liquidity_info(
         State(caesar): State<Arc<CaesarEconomicSystem>>,
-    ) -> Result<Json<LiquidityInfoResponse>, StatusCode> {
+    ) -> Result<Json<LiquidityInfoResponse>, CaesarError> {
         match caesar.get_liquidity_pool_info().await {
             Ok(info) => Ok(Json(info)),
             Err(e) => {
                 error!("Failed to get liquidity info: {}", e);
-                Err(StatusCode::INTERNAL_SERVER_ERROR)
+                Err(CaesarError::from(e))
+            }
+        }
+    }
+
+    /// List resting orders for a pair (defaults to `CSR/USD`)
+    async fn get_open_orders(
+        State(caesar): State<Arc<CaesarEconomicSystem>>,
+        Query(params): Query<HashMap<String, String>>,
+    ) -> Result<Json<OrdersResponse>, CaesarError> {
+        let base_token = params.get("base_token").map(String::as_str).unwrap_or("CSR");
+        let quote_token = params.get("quote_token").map(String::as_str).unwrap_or("USD");
+
+        match caesar.list_open_exchange_orders(base_token, quote_token).await {
+            Ok(orders) => Ok(Json(OrdersResponse { orders })),
+            Err(e) => {
+                error!("Failed to list open orders: {}", e);
+                Err(CaesarError::from(e))
+            }
+        }
+    }
+
+    /// Place a limit/market order against the order book
+    async fn place_order(
+        State(caesar): State<Arc<CaesarEconomicSystem>>,
+        Json(request): Json<PlaceOrderRequest>,
+    ) -> Result<Json<PlaceOrderResponse>, CaesarError> {
+        match caesar.place_exchange_order(request).await {
+            Ok(response) => Ok(Json(response)),
+            Err(e) => {
+                error!("Failed to place order: {}", e);
+                Err(CaesarError::from(e))
+            }
+        }
+    }
+
+    /// Cancel a resting order
+    async fn cancel_order(
+        State(caesar): State<Arc<CaesarEconomicSystem>>,
+        Path(order_id): Path<String>,
+    ) -> Result<Json<Order>, CaesarError> {
+        match caesar.cancel_exchange_order(CancelOrderRequest { order_id }).await {
+            Ok(order) => Ok(Json(order)),
+            Err(e) => {
+                error!("Failed to cancel order: {}", e);
+                Err(CaesarError::from(e))
+            }
+        }
+    }
+
+    /// Current bonding-curve supply/reserve/spot price
+    async fn get_bonding_curve_info(
+        State(caesar): State<Arc<CaesarEconomicSystem>>,
+    ) -> Result<Json<BondingCurveInfo>, CaesarError> {
+        match caesar.get_bonding_curve_info().await {
+            Ok(info) => Ok(Json(info)),
+            Err(e) => {
+                error!("Failed to get bonding curve info: {}", e);
+                Err(CaesarError::from(e))
+            }
+        }
+    }
+
+    /// Mint CSR by depositing reserve tokens into the bonding curve
+    async fn bonding_curve_buy(
+        State(caesar): State<Arc<CaesarEconomicSystem>>,
+        Json(request): Json<BondingCurveBuyRequest>,
+    ) -> Result<Json<BondingCurveTradeResponse>, CaesarError> {
+        match caesar.buy_from_bonding_curve(request).await {
+            Ok(response) => Ok(Json(response)),
+            Err(e) => {
+                error!("Failed to buy from bonding curve: {}", e);
+                Err(CaesarError::from(e))
+            }
+        }
+    }
+
+    /// Burn CSR and withdraw the reserve tokens it backs
+    async fn bonding_curve_sell(
+        State(caesar): State<Arc<CaesarEconomicSystem>>,
+        Json(request): Json<BondingCurveSellRequest>,
+    ) -> Result<Json<BondingCurveTradeResponse>, CaesarError> {
+        match caesar.sell_to_bonding_curve(request).await {
+            Ok(response) => Ok(Json(response)),
+            Err(e) => {
+                error!("Failed to sell to bonding curve: {}", e);
+                Err(CaesarError::from(e))
             }
         }
     }
@@ -528,14 +834,14 @@ impl CaesarEconomicSystem {
     async fn get_analytics_overview(
         State(caesar): State<Arc<CaesarEconomicSystem>>,
         Query(params): Query<HashMap<String, String>>,
-    ) -> Result<Json<AnalyticsOverviewResponse>, StatusCode> {
+    ) -> Result<Json<AnalyticsOverviewResponse>, CaesarError> {
         let wallet_id = params.get("wallet_id");
 
         match caesar.get_analytics_data(wallet_id).await {
             Ok(analytics) => Ok(Json(analytics)),
             Err(e) => {
                 error!("Failed to get analytics: {}", e);
-                Err(StatusCode::INTERNAL_SERVER_ERROR)
+                Err(CaesarError::from(e))
             }
         }
     }
@@ -544,15 +850,221 @@ impl CaesarEconomicSystem {
     async fn get_earnings_breakdown(
         State(caesar): State<Arc<CaesarEconomicSystem>>,
         Query(params): Query<HashMap<String, String>>,
-    ) -> Result<Json<EarningsBreakdownResponse>, StatusCode> {
+    ) -> Result<Json<EarningsBreakdownResponse>, CaesarError> {
         let wallet_id = params.get("wallet_id")
-            .ok_or(StatusCode::BAD_REQUEST)?;
+            .ok_or_else(|| CaesarError::invalid_request("wallet_id query parameter is required"))?;
 
         match caesar.get_earnings_details(wallet_id).await {
             Ok(earnings) => Ok(Json(earnings)),
             Err(e) => {
                 error!("Failed to get earnings breakdown: {}", e);
-                Err(StatusCode::INTERNAL_SERVER_ERROR)
+                Err(CaesarError::from(e))
+            }
+        }
+    }
+
+    /// List HTLC swaps a wallet is party to, as either initiator or
+    /// counterparty
+    async fn get_htlc_swaps(
+        State(caesar): State<Arc<CaesarEconomicSystem>>,
+        Query(params): Query<HashMap<String, String>>,
+    ) -> Result<Json<HtlcSwapsResponse>, CaesarError> {
+        let wallet_id = params.get("wallet_id")
+            .ok_or_else(|| CaesarError::invalid_request("wallet_id query parameter is required"))?;
+
+        match caesar.list_htlc_swaps(wallet_id).await {
+            Ok(swaps) => Ok(Json(HtlcSwapsResponse { swaps })),
+            Err(e) => {
+                error!("Failed to list HTLC swaps: {}", e);
+                Err(CaesarError::from(e))
+            }
+        }
+    }
+
+    /// Propose an atomic cross-chain HTLC swap
+    async fn init_htlc_swap(
+        State(caesar): State<Arc<CaesarEconomicSystem>>,
+        Json(request): Json<InitHtlcSwapRequest>,
+    ) -> Result<Json<HtlcSwapResponse>, CaesarError> {
+        match caesar.propose_htlc_swap(request).await {
+            Ok(swap) => Ok(Json(HtlcSwapResponse { swap })),
+            Err(e) => {
+                error!("Failed to init HTLC swap: {}", e);
+                Err(CaesarError::from(e))
+            }
+        }
+    }
+
+    /// Redeem an HTLC swap by revealing its secret
+    async fn redeem_htlc_swap(
+        State(caesar): State<Arc<CaesarEconomicSystem>>,
+        Path(swap_id): Path<String>,
+        Json(request): Json<RedeemHtlcSwapRequest>,
+    ) -> Result<Json<HtlcSwapResponse>, CaesarError> {
+        match caesar.redeem_htlc_swap(&swap_id, &request.secret).await {
+            Ok(swap) => Ok(Json(HtlcSwapResponse { swap })),
+            Err(e) => {
+                error!("Failed to redeem HTLC swap {}: {}", swap_id, e);
+                Err(CaesarError::from(e))
+            }
+        }
+    }
+
+    /// Refund an expired, unredeemed HTLC swap
+    async fn refund_htlc_swap(
+        State(caesar): State<Arc<CaesarEconomicSystem>>,
+        Path(swap_id): Path<String>,
+    ) -> Result<Json<HtlcSwapResponse>, CaesarError> {
+        match caesar.refund_htlc_swap(&swap_id).await {
+            Ok(swap) => Ok(Json(HtlcSwapResponse { swap })),
+            Err(e) => {
+                error!("Failed to refund HTLC swap {}: {}", swap_id, e);
+                Err(CaesarError::from(e))
+            }
+        }
+    }
+
+    /// Get an HTLC swap's current status
+    async fn get_htlc_swap_status(
+        State(caesar): State<Arc<CaesarEconomicSystem>>,
+        Path(swap_id): Path<String>,
+    ) -> Result<Json<HtlcSwapResponse>, CaesarError> {
+        match caesar.get_htlc_swap(&swap_id).await {
+            Ok(swap) => Ok(Json(HtlcSwapResponse { swap })),
+            Err(e) => {
+                error!("Failed to get HTLC swap {}: {}", swap_id, e);
+                Err(CaesarError::from(e))
+            }
+        }
+    }
+
+    /// Debit a wallet and settle the equivalent out to the configured chain
+    async fn bridge_to_chain(
+        State(caesar): State<Arc<CaesarEconomicSystem>>,
+        Json(request): Json<BridgeToChainRequest>,
+    ) -> Result<Json<BridgeToChainResponse>, CaesarError> {
+        match caesar.bridge_to_chain(&request.wallet_id, request.amount).await {
+            Ok(tx_hash) => Ok(Json(BridgeToChainResponse { tx_hash })),
+            Err(e) => {
+                error!("Failed to bridge to chain: {}", e);
+                Err(CaesarError::from(e))
+            }
+        }
+    }
+
+    /// Confirm a previously submitted inbound transfer and mint it in
+    async fn bridge_from_chain(
+        State(caesar): State<Arc<CaesarEconomicSystem>>,
+        Path(tx_hash): Path<String>,
+    ) -> Result<Json<BridgeFromChainResponse>, CaesarError> {
+        match caesar.bridge_from_chain(&tx_hash).await {
+            Ok(amount) => Ok(Json(BridgeFromChainResponse { tx_hash, amount })),
+            Err(e) => {
+                error!("Failed to confirm chain transfer {}: {}", tx_hash, e);
+                Err(CaesarError::from(e))
+            }
+        }
+    }
+
+    /// Upgrade to a WebSocket that streams balance/rewards/rate deltas
+    async fn ws_upgrade(
+        State(caesar): State<Arc<CaesarEconomicSystem>>,
+        ws: WebSocketUpgrade,
+    ) -> Response {
+        ws.on_upgrade(move |socket| Self::handle_ws_connection(caesar, socket))
+            .into_response()
+    }
+
+    /// Turn one broadcast receiver into an owned stream of its payloads,
+    /// skipping over messages a slow subscriber lagged past rather than
+    /// erroring the whole connection out over a few dropped deltas.
+    fn broadcast_stream(rx: broadcast::Receiver<String>) -> impl futures_util::Stream<Item = String> {
+        futures_util::stream::unfold(rx, |mut rx| async move {
+            loop {
+                match rx.recv().await {
+                    Ok(msg) => return Some((msg, rx)),
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        })
+    }
+
+    /// Drive one client's WebSocket connection: it sends `{"subscribe":
+    /// ["balance:<wallet_id>", "rewards:<wallet_id>", "rates"]}` to pick its
+    /// channels, and from then on receives each published delta as a JSON
+    /// text frame, without needing a reply. Subscribe messages can be sent
+    /// again later to add more channels; there's no unsubscribe since
+    /// dropping the channel's few messages a client no longer wants is
+    /// cheaper than tracking per-client interest more precisely.
+    async fn handle_ws_connection(caesar: Arc<CaesarEconomicSystem>, socket: WebSocket) {
+        let (mut sink, mut stream) = socket.split();
+        let mut combined = futures_util::stream::SelectAll::new();
+
+        loop {
+            tokio::select! {
+                incoming = stream.next() => {
+                    match incoming {
+                        Some(Ok(Message::Text(text))) => {
+                            if let Ok(req) = serde_json::from_str::<WsSubscribeRequest>(&text) {
+                                for channel in req.subscribe {
+                                    let rx = caesar.realtime.subscribe(&channel).await;
+                                    combined.push(Box::pin(Self::broadcast_stream(rx))
+                                        as std::pin::Pin<Box<dyn futures_util::Stream<Item = String> + Send>>);
+                                }
+                            }
+                        }
+                        Some(Ok(Message::Close(_))) | None => break,
+                        Some(Ok(_)) => {}
+                        Some(Err(_)) => break,
+                    }
+                }
+                Some(payload) = combined.next(), if !combined.is_empty() => {
+                    if sink.send(Message::Text(payload)).await.is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    /// JSON-RPC 2.0 endpoint: dispatches `{jsonrpc, id, method, params}` (or
+    /// a batch array of them) onto the public `CaesarEconomicSystem` methods
+    /// via [`rpc::handle_payload`]. Always returns 200 with a JSON-RPC
+    /// envelope — per-call errors are reported inside that envelope, not as
+    /// HTTP status codes.
+    async fn rpc_endpoint(
+        State(caesar): State<Arc<CaesarEconomicSystem>>,
+        Json(payload): Json<serde_json::Value>,
+    ) -> Json<serde_json::Value> {
+        Json(rpc::handle_payload(&caesar, payload).await)
+    }
+
+    /// Mint a shareable, signed payment-request URI for a wallet
+    async fn create_payment_request(
+        State(caesar): State<Arc<CaesarEconomicSystem>>,
+        Path(wallet_id): Path<String>,
+        Json(request): Json<CreatePaymentRequestRequest>,
+    ) -> Result<Json<PaymentRequest>, CaesarError> {
+        match caesar.mint_payment_request(&wallet_id, request.amount, request.memo, request.ttl_seconds) {
+            Ok(payment_request) => Ok(Json(payment_request)),
+            Err(e) => {
+                error!("Failed to create payment request: {}", e);
+                Err(CaesarError::from(e))
+            }
+        }
+    }
+
+    /// Resolve a payment-request URI into a priced transfer preview
+    async fn parse_payment_request(
+        State(caesar): State<Arc<CaesarEconomicSystem>>,
+        Json(request): Json<ParsePaymentRequestRequest>,
+    ) -> Result<Json<PaymentRequestPreview>, CaesarError> {
+        match caesar.resolve_payment_request(&request.uri) {
+            Ok(preview) => Ok(Json(preview)),
+            Err(e) => {
+                error!("Failed to parse payment request: {}", e);
+                Err(CaesarError::from(e))
             }
         }
     }
@@ -567,7 +1079,7 @@ impl CaesarEconomicSystem {
 
         // Calculate USD value
         let total_csr = balance + pending_rewards + staked;
-        let usd_value = self.exchange.calculate_usd_value(total_csr)?;
+        let usd_value = self.exchange.calculate_usd_value(total_csr).await?;
 
         Ok(WalletResponse {
             wallet_id: wallet_id.to_string(),
@@ -599,6 +1111,38 @@ impl CaesarEconomicSystem {
         self.get_wallet_info(&wallet.wallet_id).await
     }
 
+    /// The live [`ExternalWallet`] paired to `wallet_id`, constructing and
+    /// caching one from its stored `external_descriptor` on first access.
+    /// Errs if the wallet has no descriptor.
+    async fn external_wallet_for(&self, wallet_id: &str) -> Result<Arc<dyn ExternalWallet>> {
+        if let Some(existing) = self.external_wallets.read().await.get(wallet_id) {
+            return Ok(existing.clone());
+        }
+
+        let descriptor = self
+            .storage
+            .get_wallet_external_descriptor(wallet_id)
+            .await?
+            .ok_or_else(|| anyhow!("Wallet {} has no paired external wallet", wallet_id))?;
+
+        let backend: Arc<dyn ExternalWallet> =
+            Arc::new(HttpRpcWallet::new(self.config.wallet_backend.clone(), descriptor));
+        self.external_wallets
+            .write()
+            .await
+            .insert(wallet_id.to_string(), backend.clone());
+        Ok(backend)
+    }
+
+    /// Sync `wallet_id`'s paired external chain wallet and return its
+    /// freshly-synced balance. Errs if the wallet has no
+    /// `external_descriptor` or the sync fails.
+    pub async fn get_external_wallet_balance(&self, wallet_id: &str) -> Result<Decimal> {
+        let backend = self.external_wallet_for(wallet_id).await?;
+        backend.sync().await?;
+        backend.balance().await
+    }
+
     pub async fn get_wallet_transactions(&self, wallet_id: &str) -> Result<TransactionsResponse> {
         let transactions = self.storage.get_transactions(wallet_id, 50).await?;
         let total_count = transactions.len();
@@ -610,12 +1154,132 @@ impl CaesarEconomicSystem {
         })
     }
 
+    /// Filtered, cursor-paginated transaction history for `wallet_id`. Pass
+    /// the previous response's `next_cursor` back in `query.cursor` to fetch
+    /// the following page.
+    pub async fn query_wallet_transactions(
+        &self,
+        wallet_id: &str,
+        query: TransactionQuery,
+    ) -> Result<TransactionHistoryResponse> {
+        let cursor = query.cursor.as_deref().map(Cursor::decode).transpose()?;
+        let transactions = self
+            .storage
+            .query_transactions(wallet_id, &query, cursor.as_ref())
+            .await?;
+
+        let next_cursor = if transactions.len() >= query.limit {
+            transactions.last().map(|t| {
+                Cursor {
+                    timestamp: t.timestamp,
+                    transaction_id: t.transaction_id.clone(),
+                }
+                .encode()
+            })
+        } else {
+            None
+        };
+
+        Ok(TransactionHistoryResponse {
+            wallet_id: wallet_id.to_string(),
+            transactions,
+            next_cursor,
+        })
+    }
+
+    /// Delta sync of `wallet_id`'s transactions since `last_knowledge_of_server`
+    /// (or everything, the first time a client syncs). A transaction in
+    /// `changed` whose status is `Cancelled`/`Failed` is marked `deleted` so
+    /// the client tombstones it instead of keeping a stale copy.
+    pub async fn sync_wallet_transactions(
+        &self,
+        wallet_id: &str,
+        last_knowledge_of_server: Option<u64>,
+    ) -> Result<DeltaResponse<TransactionDelta>> {
+        let since = last_knowledge_of_server.unwrap_or(0) as i64;
+        let changed = self
+            .storage
+            .get_transactions_since(wallet_id, since)
+            .await?
+            .into_iter()
+            .map(|transaction| {
+                let deleted = matches!(
+                    transaction.status,
+                    TransactionStatus::Cancelled | TransactionStatus::Failed
+                );
+                TransactionDelta { transaction, deleted }
+            })
+            .collect();
+
+        Ok(DeltaResponse {
+            server_knowledge: self.storage.current_knowledge() as u64,
+            changed,
+        })
+    }
+
+    /// Delta sync of `wallet_id`'s rewards since `last_knowledge_of_server`.
+    pub async fn sync_wallet_rewards(
+        &self,
+        wallet_id: &str,
+        last_knowledge_of_server: Option<u64>,
+    ) -> Result<DeltaResponse<RewardDelta>> {
+        let since = last_knowledge_of_server.unwrap_or(0) as i64;
+        let changed = self
+            .storage
+            .get_rewards_since(wallet_id, since)
+            .await?
+            .into_iter()
+            .map(|reward| RewardDelta { reward, deleted: false })
+            .collect();
+
+        Ok(DeltaResponse {
+            server_knowledge: self.storage.current_knowledge() as u64,
+            changed,
+        })
+    }
+
+    /// Delta sync of `wallet_id`'s balance since `last_knowledge_of_server`.
+    /// `changed` holds zero or one entry: empty when the balance hasn't
+    /// moved since then.
+    pub async fn sync_wallet_balance(
+        &self,
+        wallet_id: &str,
+        last_knowledge_of_server: Option<u64>,
+    ) -> Result<DeltaResponse<BalanceDelta>> {
+        let since = last_knowledge_of_server.unwrap_or(0) as i64;
+        let changed = self
+            .storage
+            .get_wallet_balance_since(wallet_id, since)
+            .await?
+            .map(|balance| BalanceDelta {
+                wallet_id: wallet_id.to_string(),
+                balance,
+                deleted: false,
+            })
+            .into_iter()
+            .collect();
+
+        Ok(DeltaResponse {
+            server_knowledge: self.storage.current_knowledge() as u64,
+            changed,
+        })
+    }
+
     pub async fn get_transaction_details(&self, tx_id: &str) -> Result<TransactionResponse> {
         self.storage.get_transaction(tx_id).await
     }
 
     pub async fn process_transaction(&self, request: SendTransactionRequest) -> Result<TransactionResponse> {
-        self.transactions.process(request).await
+        let response = self.transactions.process(request).await?;
+
+        if let Ok(balance) = self.get_wallet_balance(&response.from_wallet).await {
+            self.realtime.publish(&realtime::balance_channel(&response.from_wallet), &balance).await;
+        }
+        if let Ok(balance) = self.get_wallet_balance(&response.to_wallet).await {
+            self.realtime.publish(&realtime::balance_channel(&response.to_wallet), &balance).await;
+        }
+
+        Ok(response)
     }
 
     pub async fn get_rewards_info(&self, wallet_id: &str) -> Result<RewardsResponse> {
@@ -635,12 +1299,18 @@ impl CaesarEconomicSystem {
     pub async fn claim_pending_rewards(&self, request: ClaimRewardsRequest) -> Result<ClaimRewardsResponse> {
         let claimed = self.rewards.claim_rewards(&request.wallet_id).await?;
 
-        Ok(ClaimRewardsResponse {
+        let response = ClaimRewardsResponse {
             wallet_id: request.wallet_id,
             claimed_amount: claimed,
             transaction_id: Uuid::new_v4().to_string(),
             timestamp: Utc::now(),
-        })
+        };
+
+        if let Ok(rewards) = self.get_rewards_info(&response.wallet_id).await {
+            self.realtime.publish(&realtime::rewards_channel(&response.wallet_id), &rewards).await;
+        }
+
+        Ok(response)
     }
 
     pub async fn get_reward_history_for_wallet(&self, wallet_id: &str) -> Result<RewardHistoryResponse> {
@@ -661,7 +1331,7 @@ impl CaesarEconomicSystem {
     pub async fn get_staking_details(&self, wallet_id: &str) -> Result<StakingInfoResponse> {
         let stakes = self.staking.get_stakes(wallet_id).await?;
         let total_staked = self.staking.get_staked_amount(wallet_id).await?;
-        let apy = self.staking.get_current_apy();
+        let apy = self.staking.get_current_apy().await?;
         let rewards = self.staking.calculate_rewards(wallet_id).await?;
 
         Ok(StakingInfoResponse {
@@ -681,6 +1351,95 @@ impl CaesarEconomicSystem {
         self.staking.unstake(request).await
     }
 
+    pub async fn propose_htlc_swap(&self, request: InitHtlcSwapRequest) -> Result<HtlcSwap> {
+        self.bridge.init_htlc_swap(
+            request.initiator_wallet,
+            request.counterparty,
+            request.network,
+            request.amount,
+            request.hash_lock,
+            request.t1_seconds,
+            request.t2_seconds,
+        ).await
+    }
+
+    pub async fn redeem_htlc_swap(&self, swap_id: &str, secret: &str) -> Result<HtlcSwap> {
+        self.bridge.redeem_htlc_swap(swap_id, secret).await
+    }
+
+    pub async fn refund_htlc_swap(&self, swap_id: &str) -> Result<HtlcSwap> {
+        self.bridge.refund_htlc_swap(swap_id).await
+    }
+
+    pub async fn get_htlc_swap(&self, swap_id: &str) -> Result<HtlcSwap> {
+        self.bridge.get_htlc_swap(swap_id).await
+    }
+
+    pub async fn list_htlc_swaps(&self, wallet_id: &str) -> Result<Vec<HtlcSwap>> {
+        self.bridge.list_htlc_swaps_for_party(wallet_id).await
+    }
+
+    /// Mint a shareable, signed payment-request URI for a wallet.
+    pub fn mint_payment_request(
+        &self,
+        wallet_id: &str,
+        amount: Decimal,
+        memo: Option<String>,
+        ttl_seconds: i64,
+    ) -> Result<PaymentRequest> {
+        self.payment_requests.create_request(wallet_id, amount, memo, ttl_seconds)
+    }
+
+    /// Resolve a payment-request URI into a priced transfer preview.
+    pub fn resolve_payment_request(&self, uri: &str) -> Result<PaymentRequestPreview> {
+        self.payment_requests.parse_request(uri, &self.config.economics)
+    }
+
+    /// Encrypted, portable snapshot of a wallet's balance, transactions,
+    /// rewards, and stakes — see [`backup::BackupManager::export_wallet_backup`].
+    pub async fn export_wallet_backup(&self, wallet_id: &str, passphrase: &str) -> Result<Vec<u8>> {
+        self.backup.export_wallet_backup(wallet_id, passphrase).await
+    }
+
+    /// Restore a wallet from a blob produced by [`Self::export_wallet_backup`].
+    pub async fn import_wallet_backup(&self, blob: &[u8], passphrase: &str) -> Result<String> {
+        self.backup.import_wallet_backup(blob, passphrase).await
+    }
+
+    /// Seal `wallet_id` into a versioned, Argon2id + AEAD-encrypted
+    /// [`WalletFile`] — a portable `.dat`-style recovery artifact, distinct
+    /// from [`Self::export_wallet_backup`]'s full-history snapshot.
+    pub async fn export_wallet(&self, wallet_id: &str, passphrase: &str) -> Result<WalletFile> {
+        self.wallet_files.export_wallet(wallet_id, passphrase).await
+    }
+
+    /// Restore a wallet from a [`WalletFile`] produced by [`Self::export_wallet`].
+    pub async fn import_wallet(&self, file: &WalletFile, passphrase: &str) -> Result<String> {
+        self.wallet_files.import_wallet(file, passphrase).await
+    }
+
+    pub async fn register_webhook_endpoint(
+        &self,
+        target_url: String,
+        subscribed_events: Vec<WebhookEventType>,
+        secret: String,
+    ) -> WebhookEndpoint {
+        self.webhooks.register_endpoint(target_url, subscribed_events, secret).await
+    }
+
+    /// Replay every failed webhook delivery across all endpoints.
+    pub async fn resend_failed_webhooks(&self) -> ResendWebhooksResponse {
+        self.webhooks.resend_failed_deliveries().await
+    }
+
+    /// Re-fire only the created/updated deliveries recorded for one transaction.
+    pub async fn resend_transaction_webhooks(
+        &self,
+        request: ResendTransactionWebhooksRequest,
+    ) -> ResendWebhooksResponse {
+        self.webhooks.resend_transaction_webhooks(request).await
+    }
+
     pub async fn calculate_staking_rewards(&self, wallet_id: &str) -> Result<StakingRewardsResponse> {
         let rewards = self.staking.calculate_rewards(wallet_id).await?;
         let breakdown = self.staking.get_rewards_breakdown(wallet_id).await?;
@@ -698,20 +1457,138 @@ impl CaesarEconomicSystem {
     }
 
     pub async fn execute_token_swap(&self, request: SwapRequest) -> Result<SwapResponse> {
-        self.exchange.swap(request).await
+        let response = self.exchange.swap(request).await?;
+
+        if let Ok(rates) = self.exchange.get_rates().await {
+            self.realtime.publish(realtime::RATES_CHANNEL, &rates).await;
+        }
+
+        Ok(response)
+    }
+
+    pub async fn place_exchange_order(&self, request: PlaceOrderRequest) -> Result<PlaceOrderResponse> {
+        let response = self.exchange.place_order(request).await?;
+
+        if !response.fills.is_empty() {
+            if let Ok(rates) = self.exchange.get_rates().await {
+                self.realtime.publish(realtime::RATES_CHANNEL, &rates).await;
+            }
+        }
+
+        Ok(response)
+    }
+
+    pub async fn cancel_exchange_order(&self, request: CancelOrderRequest) -> Result<Order> {
+        self.exchange.cancel_order(request).await
+    }
+
+    pub async fn get_exchange_order(&self, order_id: &str) -> Result<Order> {
+        self.exchange.get_order(order_id).await
+    }
+
+    pub async fn list_open_exchange_orders(&self, base_token: &str, quote_token: &str) -> Result<Vec<Order>> {
+        self.exchange.list_open_orders(base_token, quote_token).await
     }
 
     pub async fn get_liquidity_pool_info(&self) -> Result<LiquidityInfoResponse> {
         self.exchange.get_liquidity_info().await
     }
 
+    pub async fn get_bonding_curve_info(&self) -> Result<BondingCurveInfo> {
+        self.bonding_curve.info().await
+    }
+
+    pub async fn buy_from_bonding_curve(&self, request: BondingCurveBuyRequest) -> Result<BondingCurveTradeResponse> {
+        self.bonding_curve.buy(&request.wallet_id, request.deposit).await
+    }
+
+    pub async fn sell_to_bonding_curve(&self, request: BondingCurveSellRequest) -> Result<BondingCurveTradeResponse> {
+        self.bonding_curve.sell(&request.wallet_id, request.amount).await
+    }
+
+    /// Debit `wallet_id`'s internal CSR balance and settle the equivalent
+    /// out to the configured chain, returning the transaction hash to track
+    /// with [`Self::bridge_from_chain`].
+    pub async fn bridge_to_chain(&self, wallet_id: &str, amount: Decimal) -> Result<String> {
+        let settlement = self.settlement.as_ref().ok_or_else(|| anyhow!("No settlement backend configured"))?;
+
+        let balance = self.storage.get_balance(wallet_id).await?;
+        if balance < amount {
+            return Err(anyhow!("Insufficient balance to bridge {} CSR", amount));
+        }
+
+        let tx_hash = settlement.withdraw(wallet_id, amount).await?;
+        self.storage.update_balance(wallet_id, balance - amount).await?;
+        Ok(tx_hash)
+    }
+
+    /// Check a previously submitted inbound transfer, and once it has
+    /// reached the backend's confirmation depth, mint the confirmed amount
+    /// into the wallet it actually settled to.
+    pub async fn bridge_from_chain(&self, tx_hash: &str) -> Result<Decimal> {
+        let settlement = self.settlement.as_ref().ok_or_else(|| anyhow!("No settlement backend configured"))?;
+
+        match settlement.confirm_transfer(tx_hash).await? {
+            TransferStatus::Confirmed { to, amount } => {
+                let balance = self.storage.get_balance(&to).await.unwrap_or(dec!(0));
+                self.storage.update_balance(&to, balance + amount).await?;
+                Ok(amount)
+            }
+            TransferStatus::Pending => Err(anyhow!("Transfer {} is not yet confirmed", tx_hash)),
+            TransferStatus::Failed => Err(anyhow!("Transfer {} failed on-chain", tx_hash)),
+        }
+    }
+
     pub async fn get_analytics_data(&self, wallet_id: Option<&String>) -> Result<AnalyticsOverviewResponse> {
-        self.analytics.get_overview(wallet_id).await
+        let mut overview = self.analytics.get_overview(wallet_id).await?;
+
+        if let Some(wallet_id) = wallet_id {
+            overview.wallet_internal_balance = self.storage.get_balance(wallet_id).await.ok();
+            overview.wallet_external_balance = self.get_external_wallet_balance(wallet_id).await.ok();
+        }
+
+        Ok(overview)
     }
 
     pub async fn get_earnings_details(&self, wallet_id: &str) -> Result<EarningsBreakdownResponse> {
         self.analytics.get_earnings_breakdown(wallet_id).await
     }
+
+    /// Per-block reward breakdown across every wallet, so an auditor can
+    /// reconcile emissions down to the individual payout instead of trusting
+    /// `AnalyticsOverviewResponse::total_rewards_distributed` alone.
+    pub async fn get_block_rewards(&self, block_height: u64) -> Result<BlockRewardsResponse> {
+        let rewards = self.storage.get_rewards_for_block(block_height).await?;
+
+        let mut totals_by_type: Vec<(RewardType, Decimal)> = Vec::new();
+        for reward in &rewards {
+            match totals_by_type
+                .iter_mut()
+                .find(|(t, _)| std::mem::discriminant(t) == std::mem::discriminant(&reward.reward_type))
+            {
+                Some(entry) => entry.1 += reward.amount,
+                None => totals_by_type.push((reward.reward_type.clone(), reward.amount)),
+            }
+        }
+
+        let block_time = DateTime::from_timestamp(block_height as i64 * 10, 0)
+            .ok_or_else(|| anyhow::anyhow!("invalid block height"))?;
+
+        Ok(BlockRewardsResponse {
+            block_height,
+            block_time,
+            rewards: rewards
+                .into_iter()
+                .map(|r| RewardDistribution {
+                    recipient_wallet: r.wallet_id,
+                    reward_type: r.reward_type,
+                    amount: r.amount,
+                    commission: Decimal::ZERO,
+                })
+                .collect(),
+            totals_by_type,
+        })
+    }
 }
 
 /// Default configuration for development
@@ -724,6 +1601,11 @@ impl Default for CaesarConfig {
                 min_transaction: dec!(0.01),
                 max_transaction: dec!(1000000),
                 transaction_fee: dec!(0.001), // 0.1% fee
+                max_rate_age_secs: 30,
+                finality_poll_interval_secs: 1,
+                required_confirmations: 6,
+                treasury_wallet: "CSR_TREASURY".to_string(),
+                stats_tps_window_secs: 60,
             },
             rewards: RewardConfig {
                 base_rate_per_hour: dec!(1.0),
@@ -732,6 +1614,11 @@ impl Default for CaesarConfig {
                 storage_multiplier: dec!(1.2),
                 validation_multiplier: dec!(3.0),
                 hosting_multiplier: dec!(1.8),
+                target_locked_ratio: dec!(0.6),
+                inflation_p_gain: dec!(0.1),
+                inflation_d_gain: dec!(0.05),
+                max_inflation: dec!(0.1), // 10% max annual inflation
+                epoch_hours: dec!(8760),  // one year
             },
             staking: StakingConfig {
                 base_apy: dec!(4.2),
@@ -739,18 +1626,35 @@ impl Default for CaesarConfig {
                 max_stake: dec!(100000.0),
                 unstaking_cooldown_hours: 72,
                 compound_frequency_hours: 24,
+                warmup_cooldown_rate: dec!(9), // at most 9% of effective stake activates/cools per epoch
+                target_locked_ratio: dec!(0.667),
+                inflation_p_gain: dec!(0.1),
+                inflation_d_gain: dec!(0.05),
+                max_inflation: dec!(0.1), // 10% max annual inflation
+                epoch_hours: dec!(24),
+                reward_partitions: 8,
             },
             exchange: ExchangeConfig {
                 csr_usd_rate: dec!(1.48),
                 volatility: dec!(0.05),
                 liquidity_pool: dec!(10000000),
                 slippage_tolerance: dec!(0.02),
+                spread: dec!(0.02),
+                curve_mode: CurveMode::ConstantProduct,
+                amplification: dec!(100),
+                quote_max_age_secs: 60,
+            },
+            bonding_curve: BondingCurveConfig {
+                curve: BondingCurveKind::Linear { slope: dec!(0.0000001), min_price: dec!(0.01) },
+                exit_fee: dec!(0.01), // 1% exit fee
+                fee_wallet: Some("CSR_REWARDS_POOL".to_string()),
             },
             database: DatabaseConfig {
                 url: "sqlite::memory:".to_string(),
                 redis_url: None,
                 pool_size: 10,
             },
+            wallet_backend: WalletBackendConfig::default(),
         }
     }
 }
@@ -774,6 +1678,7 @@ mod tests {
         let request = CreateWalletRequest {
             user_id: "test_user".to_string(),
             initial_balance: Some(dec!(100)),
+            external_descriptor: None,
         };
 
         let wallet = caesar.create_new_wallet(request).await;
@@ -787,5 +1692,348 @@ pub type Address = String;
 pub type Abi = String;
 pub type U256 = u256;
 
-#[derive(Clone, Copy, Debug)]
+/// Decimals assumed by [`u256::from_decimal`]/[`u256::to_decimal`] when a
+/// caller has no token-specific scale of its own (e.g. `TokenAmount` in
+/// [`banking_interop_bridge`] threads its own `decimals` field instead).
+pub const U256_DEFAULT_DECIMALS: u32 = 18;
+
+/// Fixed-width unsigned 256-bit integer, stored as two big-endian `u128`
+/// limbs (`.0` high, `.1` low). Exists so on-chain amounts that exceed
+/// `Decimal`'s ~28-29 significant digits — full 18-decimal wei balances, gas
+/// totals — can be held and compared exactly; [`Self::from_decimal`]/
+/// [`Self::to_decimal`] are the only lossy boundary, and only when the value
+/// actually exceeds `Decimal`'s range.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct u256(pub u128, pub u128);
+
+impl u256 {
+    /// Zero value.
+    pub const fn zero() -> Self {
+        u256(0, 0)
+    }
+
+    /// Construct from a low-word `u128` (high word zero).
+    pub const fn from_u128(value: u128) -> Self {
+        u256(0, value)
+    }
+
+    /// Low 128 bits, for values that fit in a `u128`.
+    pub fn low_u128(&self) -> u128 {
+        self.1
+    }
+
+    /// High 128 bits; zero for any value that fits in a `u128`.
+    pub fn high_u128(&self) -> u128 {
+        self.0
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0 == 0 && self.1 == 0
+    }
+
+    /// Big-endian byte representation, high limb first.
+    pub fn to_be_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        bytes[0..16].copy_from_slice(&self.0.to_be_bytes());
+        bytes[16..32].copy_from_slice(&self.1.to_be_bytes());
+        bytes
+    }
+
+    /// Inverse of [`Self::to_be_bytes`].
+    pub fn from_be_bytes(bytes: [u8; 32]) -> Self {
+        let mut high = [0u8; 16];
+        let mut low = [0u8; 16];
+        high.copy_from_slice(&bytes[0..16]);
+        low.copy_from_slice(&bytes[16..32]);
+        u256(u128::from_be_bytes(high), u128::from_be_bytes(low))
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_add(rhs) {
+            (sum, false) => Some(sum),
+            (_, true) => None,
+        }
+    }
+
+    pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let (low, carry_low) = self.1.overflowing_add(rhs.1);
+        let (high, carry_high1) = self.0.overflowing_add(rhs.0);
+        let (high, carry_high2) = high.overflowing_add(carry_low as u128);
+        (u256(high, low), carry_high1 || carry_high2)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_sub(rhs) {
+            (diff, false) => Some(diff),
+            (_, true) => None,
+        }
+    }
+
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let (low, borrow_low) = self.1.overflowing_sub(rhs.1);
+        let (high, borrow_high1) = self.0.overflowing_sub(rhs.0);
+        let (high, borrow_high2) = high.overflowing_sub(borrow_low as u128);
+        (u256(high, low), borrow_high1 || borrow_high2)
+    }
+
+    /// Schoolbook multiplication over 64-bit limbs (each `u128` half split in
+    /// two, since `u128 * u128` can itself overflow a `u128`), widened to 512
+    /// bits so overflow beyond 256 bits can be detected rather than wrapped.
+    pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        let a = self.to_u64_limbs();
+        let b = rhs.to_u64_limbs();
+        let mut acc = [0u128; 8];
+
+        for (i, &ai) in a.iter().enumerate() {
+            if ai == 0 {
+                continue;
+            }
+            let mut carry: u128 = 0;
+            for (j, &bj) in b.iter().enumerate() {
+                if i + j >= 8 {
+                    break;
+                }
+                let sum = (ai as u128) * (bj as u128) + acc[i + j] + carry;
+                acc[i + j] = sum & (u64::MAX as u128);
+                carry = sum >> 64;
+            }
+            let mut k = i + b.len();
+            while carry > 0 {
+                if k >= 8 {
+                    return (u256::zero(), true);
+                }
+                let sum = acc[k] + carry;
+                acc[k] = sum & (u64::MAX as u128);
+                carry = sum >> 64;
+                k += 1;
+            }
+        }
+
+        let overflowed = acc[4..8].iter().any(|&limb| limb != 0);
+        let low = [acc[0] as u64, acc[1] as u64];
+        let high = [acc[2] as u64, acc[3] as u64];
+        (Self::from_u64_limbs([low[0], low[1], high[0], high[1]]), overflowed)
+    }
+
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_mul(rhs) {
+            (product, false) => Some(product),
+            (_, true) => None,
+        }
+    }
+
+    /// `None` only on division by zero; division itself cannot overflow.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None;
+        }
+        if self < rhs {
+            return Some(u256::zero());
+        }
+
+        // Bit-by-bit restoring long division; simple and exact, at the cost
+        // of 256 iterations regardless of operand size.
+        let mut quotient = u256::zero();
+        let mut remainder = u256::zero();
+        for i in (0..256u32).rev() {
+            remainder = remainder.shl1();
+            if self.bit(i) {
+                remainder.1 |= 1;
+            }
+            if remainder >= rhs {
+                remainder = remainder
+                    .checked_sub(rhs)
+                    .expect("remainder >= rhs just checked");
+                quotient = quotient.set_bit(i);
+            }
+        }
+        Some(quotient)
+    }
+
+    /// `(result, true)` only on division by zero, in which case `result` is
+    /// zero; mirrors `checked_div` otherwise.
+    pub fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+        match self.checked_div(rhs) {
+            Some(quotient) => (quotient, false),
+            None => (u256::zero(), true),
+        }
+    }
+
+    /// Scale a non-negative `Decimal` up to the exact integer representation
+    /// `value * 10^decimals`, e.g. an 18-decimal CSR amount into wei. Errs if
+    /// `value` is negative, the scaling overflows `Decimal`, or the scaled
+    /// amount doesn't fit in the low 128 bits.
+    pub fn from_decimal(value: Decimal, decimals: u32) -> Result<Self> {
+        if value.is_sign_negative() {
+            return Err(anyhow!("Cannot convert a negative amount to u256"));
+        }
+        let scale = Decimal::try_from(
+            10u128
+                .checked_pow(decimals)
+                .ok_or_else(|| anyhow!("decimals {} too large to scale", decimals))?,
+        )
+        .map_err(|e| anyhow!("decimals {} too large to scale: {}", decimals, e))?;
+        let scaled = value
+            .checked_mul(scale)
+            .ok_or_else(|| anyhow!("amount overflowed scaling to {} decimals", decimals))?
+            .trunc();
+        let low = scaled
+            .to_string()
+            .parse::<u128>()
+            .map_err(|_| anyhow!("amount does not fit in 128 bits"))?;
+        Ok(u256::from_u128(low))
+    }
+
+    /// Inverse of [`Self::from_decimal`]: narrow back to a human `Decimal`,
+    /// dividing by `10^decimals`. Errs if the value is too large for
+    /// `Decimal`'s ~28-29 significant digits to represent exactly.
+    pub fn to_decimal(&self, decimals: u32) -> Result<Decimal> {
+        if self.0 != 0 {
+            return Err(anyhow!("u256 value is too large to represent as a Decimal"));
+        }
+        let scale = Decimal::try_from(
+            10u128
+                .checked_pow(decimals)
+                .ok_or_else(|| anyhow!("decimals {} too large to scale", decimals))?,
+        )
+        .map_err(|e| anyhow!("decimals {} too large to scale: {}", decimals, e))?;
+        let low = Decimal::try_from(self.1)
+            .map_err(|e| anyhow!("u256 value does not fit in a Decimal: {}", e))?;
+        low.checked_div(scale)
+            .ok_or_else(|| anyhow!("decimal conversion overflow"))
+    }
+
+    fn to_u64_limbs(self) -> [u64; 4] {
+        [
+            self.1 as u64,
+            (self.1 >> 64) as u64,
+            self.0 as u64,
+            (self.0 >> 64) as u64,
+        ]
+    }
+
+    fn from_u64_limbs(limbs: [u64; 4]) -> Self {
+        let low = (limbs[0] as u128) | ((limbs[1] as u128) << 64);
+        let high = (limbs[2] as u128) | ((limbs[3] as u128) << 64);
+        u256(high, low)
+    }
+
+    fn bit(&self, i: u32) -> bool {
+        if i < 128 {
+            (self.1 >> i) & 1 == 1
+        } else {
+            (self.0 >> (i - 128)) & 1 == 1
+        }
+    }
+
+    fn set_bit(&self, i: u32) -> Self {
+        if i < 128 {
+            u256(self.0, self.1 | (1u128 << i))
+        } else {
+            u256(self.0 | (1u128 << (i - 128)), self.1)
+        }
+    }
+
+    fn shl1(&self) -> Self {
+        let carry = self.1 >> 127;
+        u256((self.0 << 1) | carry, self.1 << 1)
+    }
+}
+
+impl From<u128> for u256 {
+    fn from(value: u128) -> Self {
+        u256::from_u128(value)
+    }
+}
+
+#[cfg(test)]
+mod u256_tests {
+    use super::*;
+
+    #[test]
+    fn from_u128_round_trips_through_be_bytes() {
+        let value = u256::from_u128(u128::MAX);
+        assert_eq!(u256::from_be_bytes(value.to_be_bytes()), value);
+    }
+
+    #[test]
+    fn add_sub_round_trip() {
+        let a = u256::from_u128(123_456_789);
+        let b = u256::from_u128(987_654_321);
+        let sum = a.checked_add(b).unwrap();
+        assert_eq!(sum.checked_sub(b).unwrap(), a);
+    }
+
+    #[test]
+    fn add_overflow_detected_at_low_limb_boundary() {
+        let max_low = u256(0, u128::MAX);
+        let (wrapped, overflowed) = max_low.overflowing_add(u256::from_u128(1));
+        assert!(!overflowed);
+        assert_eq!(wrapped, u256(1, 0));
+
+        let max_value = u256(u128::MAX, u128::MAX);
+        let (_, overflowed) = max_value.overflowing_add(u256::from_u128(1));
+        assert!(overflowed);
+    }
+
+    #[test]
+    fn sub_underflow_is_none() {
+        let a = u256::from_u128(1);
+        let b = u256::from_u128(2);
+        assert!(a.checked_sub(b).is_none());
+    }
+
+    #[test]
+    fn mul_matches_u128_for_small_values() {
+        let a = u256::from_u128(1_000_000);
+        let b = u256::from_u128(2_000_000);
+        assert_eq!(a.checked_mul(b).unwrap(), u256::from_u128(2_000_000_000_000));
+    }
+
+    #[test]
+    fn mul_overflow_detected_beyond_256_bits() {
+        let max_value = u256(u128::MAX, u128::MAX);
+        assert!(max_value.checked_mul(u256::from_u128(2)).is_none());
+    }
+
+    #[test]
+    fn div_matches_u128_for_small_values() {
+        let a = u256::from_u128(1_000_000_000);
+        let b = u256::from_u128(7);
+        assert_eq!(a.checked_div(b).unwrap(), u256::from_u128(1_000_000_000 / 7));
+    }
+
+    #[test]
+    fn div_by_zero_is_none() {
+        assert!(u256::from_u128(1).checked_div(u256::zero()).is_none());
+    }
+
+    #[test]
+    fn decimal_round_trip_within_token_decimals() {
+        let amount = dec!(1234.56);
+        let wei = u256::from_decimal(amount, 18).unwrap();
+        assert_eq!(wei.to_decimal(18).unwrap(), amount);
+    }
+
+    #[test]
+    fn decimal_conversion_rejects_negative() {
+        assert!(u256::from_decimal(dec!(-1), 18).is_err());
+    }
+
+    /// A value with both limbs set can't be narrowed back to `Decimal`.
+    #[test]
+    fn to_decimal_overflow_on_high_limb() {
+        let value = u256(1, 0);
+        assert!(value.to_decimal(18).is_err());
+    }
+
+    /// `EconomicsConfig::default().max_transaction` is the largest amount the
+    /// rest of the economy is expected to move in one transfer; scaled to 18
+    /// decimals it must still round-trip exactly through `u256`.
+    #[test]
+    fn max_transaction_boundary_round_trips() {
+        let max_transaction = CaesarConfig::default().economics.max_transaction;
+        let wei = u256::from_decimal(max_transaction, 18).unwrap();
+        assert_eq!(wei.to_decimal(18).unwrap(), max_transaction);
+    }
+}
@@ -32,6 +32,11 @@ pub struct WalletResponse {
 pub struct CreateWalletRequest {
     pub user_id: String,
     pub initial_balance: Option<Decimal>,
+    /// Pairs the created wallet to a remote chain account (an output
+    /// descriptor, xpub, or node-specific account identifier) via
+    /// [`crate::wallet_backend::ExternalWallet`]. `None` leaves the wallet
+    /// purely internal.
+    pub external_descriptor: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -56,6 +61,99 @@ pub struct Transaction {
     pub fee: Decimal,
     pub description: String,
     pub timestamp: DateTime<Utc>,
+    /// Rate applied when the transfer converted between assets, stored so a
+    /// reversal can reconstruct the inverse conversion. `None` for same-asset
+    /// transfers.
+    #[serde(default)]
+    pub applied_rate: Option<Rate>,
+    /// Optional structured reference a payer attaches for reconciliation.
+    #[serde(default)]
+    pub memo: Option<Memo>,
+}
+
+/// Structured reference attached to a transfer for reconciliation against an
+/// external invoice or account, mirroring the memo-to-id mapping used by
+/// Stellar/Horizon integrations.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Memo {
+    Text(String),
+    Id(u64),
+    Hash([u8; 32]),
+}
+
+impl Memo {
+    /// Canonical key used to index and look the memo up in storage.
+    pub fn index_key(&self) -> String {
+        match self {
+            Memo::Text(text) => format!("text:{}", text),
+            Memo::Id(id) => format!("id:{}", id),
+            Memo::Hash(bytes) => {
+                let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+                format!("hash:{}", hex)
+            }
+        }
+    }
+
+    /// Whether the memo must be unique among unconfirmed transactions. `Text`
+    /// memos are free-form and may repeat; `Id`/`Hash` memos are reconciliation
+    /// keys and must be unambiguous.
+    pub fn requires_uniqueness(&self) -> bool {
+        matches!(self, Memo::Id(_) | Memo::Hash(_))
+    }
+}
+
+/// A base/quote conversion rate with a symmetric spread.
+///
+/// Mirrors the `Rate` abstraction used by the swap codebases: the quoted
+/// `rate` is quote-per-base, and `spread` widens the effective price on both
+/// sides so the venue earns the maker margin.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Rate {
+    pub base: String,
+    pub quote: String,
+    pub rate: Decimal,
+    pub spread: Decimal,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl Rate {
+    /// Convert `amount` of `from_asset` into `to_asset`, using checked decimal
+    /// arithmetic so overflow surfaces as an error rather than a panic.
+    pub fn convert(
+        &self,
+        amount: Decimal,
+        from_asset: &str,
+        to_asset: &str,
+    ) -> anyhow::Result<Decimal> {
+        use rust_decimal_macros::dec;
+
+        let half_spread = self.spread / dec!(2);
+        if from_asset == self.base && to_asset == self.quote {
+            // Selling the base asset: hit the bid (rate minus half the spread).
+            amount
+                .checked_mul(self.rate)
+                .and_then(|x| x.checked_mul(dec!(1) - half_spread))
+                .ok_or_else(|| anyhow::anyhow!("conversion overflow"))
+        } else if from_asset == self.quote && to_asset == self.base {
+            // Buying the base asset: pay the ask (rate plus half the spread).
+            let ask = self
+                .rate
+                .checked_mul(dec!(1) + half_spread)
+                .ok_or_else(|| anyhow::anyhow!("conversion overflow"))?;
+            amount
+                .checked_div(ask)
+                .ok_or_else(|| anyhow::anyhow!("conversion overflow"))
+        } else {
+            Err(anyhow::anyhow!(
+                "rate {}/{} cannot convert {} -> {}",
+                self.base,
+                self.quote,
+                from_asset,
+                to_asset
+            ))
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -75,6 +173,7 @@ pub enum TransactionType {
 pub enum TransactionStatus {
     Pending,
     Completed,
+    Confirmed,
     Failed,
     Cancelled,
 }
@@ -94,6 +193,126 @@ pub struct TransactionResponse {
     pub confirmation_count: u32,
 }
 
+impl TransactionResponse {
+    /// Coarser-grained confirmation tier derived from `confirmation_count`
+    /// against `required_confirmations`, supplementing the raw count the
+    /// same way ledger RPCs expose a named status alongside a numeric
+    /// confirmation depth.
+    pub fn confirmation_status(&self, required_confirmations: u32) -> TransactionConfirmationStatus {
+        if self.confirmation_count == 0 {
+            TransactionConfirmationStatus::Processed
+        } else if self.confirmation_count < required_confirmations {
+            TransactionConfirmationStatus::Confirmed
+        } else {
+            TransactionConfirmationStatus::Finalized
+        }
+    }
+}
+
+/// Named confirmation tier supplementing a transaction's raw
+/// `confirmation_count`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransactionConfirmationStatus {
+    Processed,
+    Confirmed,
+    Finalized,
+}
+
+/// One entry in a wallet's accounting statement, from
+/// `CaesarStorage::get_wallet_ledger`. `net_value` is the signed effect this
+/// transaction had on the wallet — negative for an outgoing transfer
+/// (inclusive of its fee), positive for an incoming one — and `balance_after`
+/// is the running balance immediately after it landed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletLedgerEntry {
+    pub transaction_id: String,
+    pub counterparty: String,
+    pub net_value: Decimal,
+    pub fee: Decimal,
+    pub balance_after: Decimal,
+    pub status: TransactionStatus,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Everything `backup::BackupManager::export_wallet_backup` bundles for one
+/// wallet before encrypting it — the full set `import_wallet_backup` needs
+/// to recreate the wallet elsewhere.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletBackup {
+    pub wallet: Wallet,
+    pub transactions: Vec<TransactionResponse>,
+    pub rewards: Vec<RewardEntry>,
+    pub stakes: Vec<StakeInfo>,
+}
+
+/// Opaque forward cursor over transaction history, ordered strictly by
+/// `(timestamp, transaction_id)` so pagination is stable under concurrent
+/// inserts — a resumed page never skips or repeats an entry.
+#[derive(Debug, Clone)]
+pub struct Cursor {
+    pub timestamp: DateTime<Utc>,
+    pub transaction_id: String,
+}
+
+impl Cursor {
+    /// Encode to an opaque token clients echo back verbatim.
+    pub fn encode(&self) -> String {
+        let raw = format!("{}|{}", self.timestamp.to_rfc3339(), self.transaction_id);
+        raw.bytes().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    /// Decode a token produced by [`Cursor::encode`].
+    pub fn decode(token: &str) -> anyhow::Result<Self> {
+        if token.len() % 2 != 0 {
+            return Err(anyhow::anyhow!("invalid cursor"));
+        }
+        let bytes = (0..token.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&token[i..i + 2], 16))
+            .collect::<Result<Vec<u8>, _>>()
+            .map_err(|_| anyhow::anyhow!("invalid cursor"))?;
+        let raw = String::from_utf8(bytes).map_err(|_| anyhow::anyhow!("invalid cursor"))?;
+        let (ts, id) = raw
+            .split_once('|')
+            .ok_or_else(|| anyhow::anyhow!("invalid cursor"))?;
+        Ok(Self {
+            timestamp: DateTime::parse_from_rfc3339(ts)
+                .map_err(|_| anyhow::anyhow!("invalid cursor"))?
+                .with_timezone(&Utc),
+            transaction_id: id.to_string(),
+        })
+    }
+}
+
+/// Write-ahead journal entry describing the intended balance deltas of a
+/// transfer. Written `Pending` before any balance is touched and flipped to
+/// `Committed` once every update has landed, so a crash mid-transfer leaves a
+/// replayable record rather than vanished funds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalEntry {
+    pub transaction_id: String,
+    pub from_wallet: String,
+    pub to_wallet: String,
+    /// Destination of the collected fee, when a multi-party move is journaled.
+    pub treasury_wallet: Option<String>,
+    pub from_pre: Decimal,
+    pub to_pre: Decimal,
+    pub treasury_pre: Decimal,
+    pub from_delta: Decimal,
+    pub to_delta: Decimal,
+    pub treasury_delta: Decimal,
+    pub status: JournalStatus,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JournalStatus {
+    Pending,
+    Committed,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransactionsResponse {
     pub wallet_id: String,
@@ -101,12 +320,140 @@ pub struct TransactionsResponse {
     pub total_count: usize,
 }
 
+/// Filters for a cursor-paginated transaction history query, following the
+/// from/to-window-plus-type-filter pattern brokerage activity-history APIs
+/// use. `cursor` is an opaque [`Cursor::encode`] token from a previous
+/// [`TransactionHistoryResponse::next_cursor`]; omit it to start from the
+/// most recent transaction.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TransactionQuery {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub transaction_type: Option<TransactionType>,
+    pub status: Option<TransactionStatus>,
+    #[serde(default = "default_transaction_query_limit")]
+    pub limit: usize,
+    pub cursor: Option<String>,
+}
+
+fn default_transaction_query_limit() -> usize {
+    50
+}
+
+/// One page of a filtered transaction history query. `next_cursor` is
+/// `Some` only when the page was full, so a client knows to stop paging
+/// when `None` comes back without guessing from the page length.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionHistoryResponse {
+    pub wallet_id: String,
+    pub transactions: Vec<TransactionResponse>,
+    pub next_cursor: Option<String>,
+}
+
+/// Delta-sync envelope keyed on a monotonically increasing
+/// `server_knowledge` counter — the incremental-update model budgeting APIs
+/// use so a client polling periodically downloads only what changed since
+/// its last sync instead of a full snapshot. Pass `server_knowledge` back
+/// as `last_knowledge_of_server` on the next request to continue from here.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeltaResponse<T> {
+    pub server_knowledge: u64,
+    pub changed: Vec<T>,
+}
+
+/// One transaction as seen by a delta sync. `deleted` tombstones a
+/// transaction that has been cancelled or failed since the client's last
+/// sync, so the client can drop a row it already has instead of keeping a
+/// stale one.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionDelta {
+    #[serde(flatten)]
+    pub transaction: TransactionResponse,
+    pub deleted: bool,
+}
+
+/// One reward as seen by a delta sync. Rewards are never retracted, so
+/// `deleted` is always `false` — the field exists for symmetry with
+/// [`TransactionDelta`] so clients can treat every delta kind uniformly.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RewardDelta {
+    #[serde(flatten)]
+    pub reward: RewardEntry,
+    pub deleted: bool,
+}
+
+/// A wallet's balance as seen by a delta sync. Present in `changed` only
+/// when the balance moved since the client's last sync.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BalanceDelta {
+    pub wallet_id: String,
+    pub balance: Decimal,
+    pub deleted: bool,
+}
+
+/// The ledger state a response was read at, mirroring the slot/version
+/// context a ledger RPC attaches so a caller can reason about staleness or a
+/// reorg instead of trusting the payload alone.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResponseContext {
+    pub block_height: u64,
+    pub block_time: DateTime<Utc>,
+    #[serde(default)]
+    pub api_version: Option<String>,
+}
+
+/// Wraps a response value with its [`ResponseContext`], or not. Untagged so
+/// an endpoint can keep returning the bare `T` it always has (existing
+/// clients keep working unchanged) while a context-aware client asks for —
+/// and transparently deserializes — the `with_context` form.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Contextualized<T> {
+    Bare(T),
+    WithContext { value: T, context: ResponseContext },
+}
+
+/// One reward payout folded into a [`BlockRewardsResponse`], letting an
+/// auditor reconcile emissions down to the individual payout instead of
+/// trusting `AnalyticsOverviewResponse::total_rewards_distributed` alone.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RewardDistribution {
+    pub recipient_wallet: String,
+    pub reward_type: RewardType,
+    pub amount: Decimal,
+    /// Validator/delegate commission withheld from `amount`. Always zero
+    /// today — no commission-sharing mechanism exists yet — kept as a field
+    /// so introducing one later doesn't need an API-breaking change.
+    pub commission: Decimal,
+}
+
+/// Every reward credited within one block, with totals broken out per
+/// [`RewardType`] so auditors and dashboards can reconcile emissions at the
+/// individual-payout level rather than trusting a single running total.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockRewardsResponse {
+    pub block_height: u64,
+    pub block_time: DateTime<Utc>,
+    pub rewards: Vec<RewardDistribution>,
+    pub totals_by_type: Vec<(RewardType, Decimal)>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SendTransactionRequest {
     pub from_wallet: String,
     pub to_wallet: String,
     pub amount: Decimal,
     pub description: Option<String>,
+    /// Source asset; defaults to CSR when omitted.
+    #[serde(default)]
+    pub from_asset: Option<String>,
+    /// Destination asset; when it differs from `from_asset` the transfer is
+    /// converted through the current rate.
+    #[serde(default)]
+    pub to_asset: Option<String>,
+    /// Optional structured reference for reconciliation.
+    #[serde(default)]
+    pub memo: Option<Memo>,
 }
 
 // ============ Reward Models ============
@@ -224,12 +571,24 @@ pub struct MultiplierInfo {
 pub struct StakeInfo {
     pub stake_id: String,
     pub wallet_id: String,
+    /// Amount requested via `stake()`. Only `effective_amount` of this counts
+    /// toward rewards and the network-wide staked total until warmup clears.
     pub amount: Decimal,
     pub start_date: DateTime<Utc>,
     pub lock_period_days: Option<u32>,
     pub apy: Decimal,
     pub accumulated_rewards: Decimal,
     pub is_active: bool,
+    /// Portion of `amount` that has cleared warmup (or has yet to cool down)
+    /// and is actually counted by `calculate_rewards` and the network total.
+    pub effective_amount: Decimal,
+    /// Set by `unstake()`. While true, `effective_amount` ramps down toward
+    /// zero instead of up toward `amount`; the stake is finalized and its
+    /// funds released once it reaches zero.
+    pub deactivating: bool,
+    /// End of the last interval credited into `accumulated_rewards` by a
+    /// reward distribution epoch. Starts at `start_date`.
+    pub last_reward_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -273,6 +632,9 @@ pub struct UnstakeResponse {
     pub rewards_claimed: Decimal,
     pub transaction_id: String,
     pub cooldown_ends: DateTime<Utc>,
+    /// Principal still active on the original stake after a partial
+    /// unstake. Zero when the whole stake was withdrawn.
+    pub remaining_principal: Decimal,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -292,6 +654,60 @@ pub struct StakeRewardBreakdown {
     pub days_staked: u32,
 }
 
+/// Warmup/cooldown breakdown of a single stake's `amount`, as returned by
+/// [`crate::staking::StakingManager::get_activation_status`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StakeActivationStatus {
+    pub stake_id: String,
+    pub requested_amount: Decimal,
+    /// Portion already warmed up (or not yet cooled down) that counts toward rewards.
+    pub effective_amount: Decimal,
+    /// Portion still ramping up toward `requested_amount`.
+    pub activating_amount: Decimal,
+    /// Portion ramping down toward zero following `unstake()`.
+    pub deactivating_amount: Decimal,
+    /// True once the stake has fully cooled down and its funds were released.
+    pub inactive: bool,
+}
+
+/// One epoch's worth of network-wide stake activation, as recorded by
+/// [`crate::staking::StakingManager::run_activation_epoch`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StakeActivationPeriod {
+    pub period_start: DateTime<Utc>,
+    /// Total effective (reward-earning) stake across the network after this period.
+    pub total_effective: Decimal,
+    /// Amount that newly became effective this period.
+    pub activated: Decimal,
+    /// Amount that newly left effective stake this period.
+    pub deactivated: Decimal,
+}
+
+/// Progress of the current (or last completed) staking reward distribution
+/// epoch, split into deterministic partitions so the workload can be spread
+/// across multiple invocations. See
+/// [`crate::staking::StakingManager::distribute_staking_rewards`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EpochRewardStatus {
+    pub epoch_id: String,
+    /// Total reward pool computed for this epoch.
+    pub total_pool: Decimal,
+    /// Sum credited so far across completed partitions.
+    pub distributed: Decimal,
+    /// Partitions not yet processed; empty once the epoch is fully distributed.
+    pub partitions_remaining: Vec<u32>,
+    pub started_at: DateTime<Utc>,
+}
+
+/// Active stake bucketed by requested lock period. See
+/// [`crate::staking::StakingManager::get_lock_distribution`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LockPeriodDistribution {
+    pub days: u32,
+    pub amount: Decimal,
+    pub percentage: Decimal,
+}
+
 // ============ Exchange Models ============
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -300,6 +716,10 @@ pub struct ExchangeRate {
     pub to_token: String,
     pub rate: Decimal,
     pub inverse_rate: Decimal,
+    /// Price a seller receives (mid-rate minus half the spread).
+    pub bid: Decimal,
+    /// Price a buyer pays (mid-rate plus half the spread).
+    pub ask: Decimal,
     pub timestamp: DateTime<Utc>,
     pub volume_24h: Decimal,
 }
@@ -309,6 +729,8 @@ pub struct ExchangeRatesResponse {
     pub rates: Vec<ExchangeRate>,
     pub base_currency: String,
     pub last_updated: DateTime<Utc>,
+    /// True when the external price feed has gone quiet (or is not attached).
+    pub feed_stale: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -318,6 +740,12 @@ pub struct SwapRequest {
     pub to_token: String,
     pub amount: Decimal,
     pub slippage_tolerance: Option<Decimal>,
+    /// The bid/ask rate the client quoted (e.g. from `GET /exchange/rates`)
+    /// and is willing to trade at. When set alongside `quoted_at`, the swap
+    /// is rejected if the quote has expired or the market has since moved
+    /// past `slippage_tolerance` from it.
+    pub quoted_rate: Option<Decimal>,
+    pub quoted_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -335,6 +763,139 @@ pub struct SwapResponse {
     pub timestamp: DateTime<Utc>,
 }
 
+/// A single constraint on how a swap's price or quantity must be shaped for
+/// one trading pair, mirroring the filter model large spot exchanges attach
+/// to each market so trade sizes are always normalized to the pair's
+/// precision.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum TradingFilter {
+    /// The executed price must land in `[min_price, max_price]` and be a
+    /// multiple of `tick_size`.
+    PriceFilter {
+        min_price: Decimal,
+        max_price: Decimal,
+        tick_size: Decimal,
+    },
+    /// The traded quantity must land in `[min_qty, max_qty]` and be a
+    /// multiple of `step_size`.
+    LotSize {
+        min_qty: Decimal,
+        max_qty: Decimal,
+        step_size: Decimal,
+    },
+    /// `price * quantity` must be at least `min_notional`.
+    MinNotional { min_notional: Decimal },
+}
+
+/// Static trading metadata for one pair: its symbols, decimal precision, and
+/// the [`TradingFilter`]s a swap against it must satisfy before execution.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExchangePairInfo {
+    pub base_token: String,
+    pub quote_token: String,
+    pub base_precision: u32,
+    pub quote_precision: u32,
+    pub filters: Vec<TradingFilter>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderType {
+    Market,
+    Limit,
+    StopLimit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderSide {
+    Buy,
+    Sell,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderStatus {
+    New,
+    PartiallyFilled,
+    Filled,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeInForce {
+    GoodTilCancelled,
+    ImmediateOrCancel,
+    FillOrKill,
+}
+
+/// A resting or filled order against a trading pair. Market orders fill (or
+/// fail) immediately; a limit order fills in [`ExchangeEngine::place_order`]
+/// when the pair's rate already crosses `price` on arrival, and otherwise
+/// rests. Nothing fills a resting order later.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Order {
+    pub order_id: String,
+    pub wallet_id: String,
+    pub base_token: String,
+    pub quote_token: String,
+    pub side: OrderSide,
+    pub order_type: OrderType,
+    /// Limit/trigger price; ignored for `OrderType::Market`.
+    pub price: Option<Decimal>,
+    pub quantity: Decimal,
+    pub filled_quantity: Decimal,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlaceOrderRequest {
+    pub wallet_id: String,
+    pub base_token: String,
+    pub quote_token: String,
+    pub side: OrderSide,
+    pub order_type: OrderType,
+    pub price: Option<Decimal>,
+    pub quantity: Decimal,
+    pub time_in_force: TimeInForce,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlaceOrderResponse {
+    pub order: Order,
+    /// Synthetic exchange fills produced immediately by matching (a market
+    /// order, or a limit order that crossed the pair's rate on arrival).
+    pub fills: Vec<Transaction>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CancelOrderRequest {
+    pub order_id: String,
+}
+
+/// Resting order-book snapshot for a pair, oldest order first.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrdersResponse {
+    pub orders: Vec<Order>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderBookSwapResult {
+    pub from_token: String,
+    pub to_token: String,
+    pub from_amount: Decimal,
+    pub to_amount: Decimal,
+    /// Volume-weighted effective rate across the filled levels.
+    pub effective_rate: Decimal,
+    /// Realized slippage versus the top-of-book price.
+    pub slippage: Decimal,
+    pub top_of_book: Decimal,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LiquidityInfoResponse {
     pub total_liquidity: Decimal,
@@ -345,6 +906,59 @@ pub struct LiquidityInfoResponse {
     pub apy: Decimal,
 }
 
+/// Depth and slippage metrics derived from the AMM reserves. See
+/// [`crate::exchange::ExchangeEngine::get_market_depth`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarketDepth {
+    pub bid_liquidity: Decimal,
+    pub ask_liquidity: Decimal,
+    pub spread: Decimal,
+    pub depth_10_percent: Decimal,
+    pub slippage_100k: Decimal,
+}
+
+// ============ Bonding Curve Models ============
+
+/// Mint CSR by depositing `deposit` reserve tokens into the curve.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BondingCurveBuyRequest {
+    pub wallet_id: String,
+    pub deposit: Decimal,
+}
+
+/// Burn `amount` CSR and withdraw the reserve tokens it backs.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BondingCurveSellRequest {
+    pub wallet_id: String,
+    pub amount: Decimal,
+}
+
+/// Result of a buy or sell against [`crate::bonding_curve::BondingCurveEngine`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BondingCurveTradeResponse {
+    pub wallet_id: String,
+    /// CSR minted (buy) or burned (sell).
+    pub csr_amount: Decimal,
+    /// Reserve tokens deposited (buy) or withdrawn (sell), net of any exit fee.
+    pub reserve_amount: Decimal,
+    /// Exit fee withheld from a sell's reserve payout; zero for a buy.
+    pub fee: Decimal,
+    /// Spot price immediately after the trade settles.
+    pub price: Decimal,
+    /// Total CSR supply outstanding against the curve after the trade.
+    pub supply: Decimal,
+    /// Total reserve backing `supply`, i.e. `curve.reserve(supply)`.
+    pub reserve: Decimal,
+}
+
+/// Current curve state, for quoting without trading.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BondingCurveInfo {
+    pub supply: Decimal,
+    pub reserve: Decimal,
+    pub spot_price: Decimal,
+}
+
 // ============ Analytics Models ============
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -358,6 +972,12 @@ pub struct AnalyticsOverviewResponse {
     pub transactions_24h: u64,
     pub volume_24h: Decimal,
     pub price_change_24h: Decimal,
+    /// Internal CSR balance of the `wallet_id` query param, if one was given.
+    pub wallet_internal_balance: Option<Decimal>,
+    /// Synced balance of the `wallet_id` query param's paired external chain
+    /// wallet, if it was given, has an `external_descriptor`, and the sync
+    /// succeeded.
+    pub wallet_external_balance: Option<Decimal>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -388,6 +1008,21 @@ pub enum TrendDirection {
     Stable,
 }
 
+/// Point-in-time snapshot of the metrics in [`AnalyticsOverviewResponse`],
+/// written periodically so 24h-change and trend fields can diff against a
+/// real prior reading instead of a hardcoded one.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricsSnapshot {
+    pub timestamp: DateTime<Utc>,
+    pub circulating_supply: Decimal,
+    pub price: Decimal,
+    pub total_staked: Decimal,
+    pub total_stakers: u64,
+    pub active_wallets_24h: u64,
+    pub transactions_24h: u64,
+    pub volume_24h: Decimal,
+}
+
 // ============ System Models ============
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -0,0 +1,273 @@
+//! Caesar Payment Request URIs
+//!
+//! Lets a wallet mint a shareable `caesar:<wallet_id>?amount=<decimal>
+//! &memo=<text>&expires=<ts>&sig=<hex>` invoice URI, and lets the API
+//! resolve one back into a priced transfer preview. The `sig` is an
+//! HMAC-SHA256 over the URI's fields keyed by a key derived per-wallet from
+//! a process-wide master key, so a payer can't alter the amount or memo
+//! before submitting the resulting `SendTransactionRequest`.
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Duration, Utc};
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::models::SendTransactionRequest;
+use crate::EconomicsConfig;
+
+type HmacSha256 = Hmac<Sha256>;
+
+const URI_SCHEME: &str = "caesar:";
+
+/// A minted payment request, ready to render as a URI or hand to a QR
+/// encoder — the `uri` field alone is enough for either.
+#[derive(Debug, Clone, Serialize)]
+pub struct PaymentRequest {
+    pub wallet_id: String,
+    pub amount: Decimal,
+    pub memo: Option<String>,
+    pub expires_at: DateTime<Utc>,
+    pub uri: String,
+}
+
+/// A `caesar:` URI resolved into a priced, ready-to-submit transfer.
+#[derive(Debug, Clone, Serialize)]
+pub struct PaymentRequestPreview {
+    pub to_wallet: String,
+    pub amount: Decimal,
+    pub memo: Option<String>,
+    pub transaction_fee: Decimal,
+    pub total_with_fee: Decimal,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl PaymentRequestPreview {
+    /// The transfer this preview describes, ready for
+    /// `CaesarEconomicSystem::process_transaction`.
+    pub fn into_send_request(self, from_wallet: String) -> SendTransactionRequest {
+        SendTransactionRequest {
+            from_wallet,
+            to_wallet: self.to_wallet,
+            amount: self.amount,
+            description: self.memo,
+            from_asset: None,
+            to_asset: None,
+            memo: None,
+        }
+    }
+}
+
+/// Mints and resolves signed payment-request URIs. Holds a process-wide
+/// master key that per-wallet signing keys are derived from, rather than
+/// persisting a key per wallet; nothing about the signature needs to survive
+/// a restart since a request also carries its own expiry.
+pub struct PaymentRequestManager {
+    master_key: Vec<u8>,
+}
+
+impl PaymentRequestManager {
+    pub fn new() -> Self {
+        let mut master_key = vec![0u8; 32];
+        rand::thread_rng().fill_bytes(&mut master_key);
+        Self { master_key }
+    }
+
+    /// Derive a per-wallet signing key from the master key so a leaked
+    /// signature for one wallet's requests can't be replayed to forge
+    /// another wallet's.
+    fn wallet_key(&self, wallet_id: &str) -> Result<Vec<u8>> {
+        let mut mac = HmacSha256::new_from_slice(&self.master_key)
+            .map_err(|e| anyhow!("failed to derive wallet signing key: {}", e))?;
+        mac.update(wallet_id.as_bytes());
+        Ok(mac.finalize().into_bytes().to_vec())
+    }
+
+    fn sign(&self, wallet_id: &str, canonical: &str) -> Result<String> {
+        let wallet_key = self.wallet_key(wallet_id)?;
+        let mut mac = HmacSha256::new_from_slice(&wallet_key)
+            .map_err(|e| anyhow!("failed to sign payment request: {}", e))?;
+        mac.update(canonical.as_bytes());
+        Ok(hex::encode(mac.finalize().into_bytes()))
+    }
+
+    /// Mint a shareable, signed `caesar:` URI for `wallet_id`.
+    pub fn create_request(
+        &self,
+        wallet_id: &str,
+        amount: Decimal,
+        memo: Option<String>,
+        ttl_seconds: i64,
+    ) -> Result<PaymentRequest> {
+        let expires_at = Utc::now() + Duration::seconds(ttl_seconds);
+        let canonical = canonical_message(wallet_id, amount, memo.as_deref(), expires_at.timestamp());
+        let signature = self.sign(wallet_id, &canonical)?;
+
+        let mut uri = format!("{}{}?amount={}&expires={}", URI_SCHEME, wallet_id, amount, expires_at.timestamp());
+        if let Some(memo) = &memo {
+            uri.push_str("&memo=");
+            uri.push_str(&encode_query_value(memo));
+        }
+        uri.push_str("&sig=");
+        uri.push_str(&signature);
+
+        Ok(PaymentRequest { wallet_id: wallet_id.to_string(), amount, memo, expires_at, uri })
+    }
+
+    /// Parse a `caesar:` URI, verify its signature and expiry, and price a
+    /// transfer preview against `config`'s transaction bounds and fee.
+    pub fn parse_request(&self, uri: &str, config: &EconomicsConfig) -> Result<PaymentRequestPreview> {
+        let (wallet_id, params) = parse_uri(uri)?;
+
+        let amount_str = params.get("amount").ok_or_else(|| anyhow!("payment request is missing amount"))?;
+        let amount = Decimal::from_str(amount_str).map_err(|e| anyhow!("invalid amount in payment request: {}", e))?;
+
+        let expires_str = params.get("expires").ok_or_else(|| anyhow!("payment request is missing expires"))?;
+        let expires_ts: i64 = expires_str.parse().map_err(|e| anyhow!("invalid expires in payment request: {}", e))?;
+        let expires_at = DateTime::from_timestamp(expires_ts, 0)
+            .ok_or_else(|| anyhow!("invalid expires timestamp in payment request"))?;
+
+        let memo = params.get("memo").map(|m| decode_query_value(m));
+
+        let signature = params.get("sig").ok_or_else(|| anyhow!("payment request is missing sig"))?;
+        let canonical = canonical_message(&wallet_id, amount, memo.as_deref(), expires_ts);
+        let expected = self.sign(&wallet_id, &canonical)?;
+        if !constant_time_eq(signature.as_bytes(), expected.as_bytes()) {
+            return Err(anyhow!("payment request signature is invalid"));
+        }
+
+        if Utc::now() > expires_at {
+            return Err(anyhow!("payment request expired at {}", expires_at.to_rfc3339()));
+        }
+
+        if amount < config.min_transaction {
+            return Err(anyhow!(
+                "Amount below minimum transaction of {}",
+                config.min_transaction
+            ));
+        }
+        if amount > config.max_transaction {
+            return Err(anyhow!(
+                "Amount exceeds maximum transaction of {}",
+                config.max_transaction
+            ));
+        }
+
+        let transaction_fee = amount * config.transaction_fee;
+
+        Ok(PaymentRequestPreview {
+            to_wallet: wallet_id,
+            amount,
+            memo,
+            transaction_fee,
+            total_with_fee: amount + transaction_fee,
+            expires_at,
+        })
+    }
+}
+
+impl Default for PaymentRequestManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The fields a signature covers, in a fixed order, so signer and verifier
+/// always hash the same bytes regardless of how the URI is laid out.
+fn canonical_message(wallet_id: &str, amount: Decimal, memo: Option<&str>, expires_ts: i64) -> String {
+    format!("{}|{}|{}|{}", wallet_id, amount, memo.unwrap_or(""), expires_ts)
+}
+
+/// Split a `caesar:<wallet_id>?k=v&k=v...` URI into its wallet id and query
+/// parameters.
+fn parse_uri(uri: &str) -> Result<(String, HashMap<String, String>)> {
+    let rest = uri.strip_prefix(URI_SCHEME).ok_or_else(|| anyhow!("payment request URI must start with {}", URI_SCHEME))?;
+    let (wallet_id, query) = match rest.split_once('?') {
+        Some((wallet_id, query)) => (wallet_id, query),
+        None => (rest, ""),
+    };
+    if wallet_id.is_empty() {
+        return Err(anyhow!("payment request URI is missing a wallet id"));
+    }
+
+    let mut params = HashMap::new();
+    for pair in query.split('&').filter(|p| !p.is_empty()) {
+        match pair.split_once('=') {
+            Some((key, value)) => {
+                params.insert(key.to_string(), value.to_string());
+            }
+            None => return Err(anyhow!("malformed payment request query parameter: {}", pair)),
+        }
+    }
+
+    Ok((wallet_id.to_string(), params))
+}
+
+/// Minimal percent-encoding for the handful of characters that would
+/// otherwise break our `&`/`=`-delimited query string.
+fn encode_query_value(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'&' | b'=' | b'%' | b'+' => out.push_str(&format!("%{:02X}", byte)),
+            b' ' => out.push('+'),
+            _ => out.push(byte as char),
+        }
+    }
+    out
+}
+
+fn decode_query_value(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut bytes = value.bytes().peekable();
+    while let Some(byte) = bytes.next() {
+        match byte {
+            b'+' => out.push(' '),
+            b'%' => {
+                let hi = bytes.next();
+                let lo = bytes.next();
+                if let (Some(hi), Some(lo)) = (hi, lo) {
+                    if let Ok(decoded) = u8::from_str_radix(&format!("{}{}", hi as char, lo as char), 16) {
+                        out.push(decoded as char);
+                        continue;
+                    }
+                }
+            }
+            _ => out.push(byte as char),
+        }
+    }
+    out
+}
+
+/// Request body for minting a payment request on a wallet.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreatePaymentRequestRequest {
+    pub amount: Decimal,
+    #[serde(default)]
+    pub memo: Option<String>,
+    #[serde(default = "default_payment_request_ttl_secs")]
+    pub ttl_seconds: i64,
+}
+
+fn default_payment_request_ttl_secs() -> i64 {
+    900
+}
+
+/// Request body for resolving a `caesar:` URI into a transfer preview.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ParsePaymentRequestRequest {
+    pub uri: String,
+}
+
+/// Compare two byte strings without leaking timing information about where
+/// they first differ, so a signature check doesn't become a side channel.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b.iter()).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
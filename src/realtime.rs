@@ -0,0 +1,84 @@
+//! Caesar Realtime Streaming Hub
+//!
+//! Backs the `/api/v1/caesar/ws` endpoint. `CaesarEconomicSystem` publishes
+//! JSON deltas onto named channels here after transfers, reward claims,
+//! swaps and order fills; WebSocket clients subscribe
+//! to the channels they care about (`balance:<wallet_id>`, `rewards:<wallet_id>`,
+//! `rates`) and get them fanned out, instead of polling the REST endpoints.
+
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::{broadcast, RwLock};
+
+/// Channel every client can subscribe to for live exchange-rate quotes.
+pub const RATES_CHANNEL: &str = "rates";
+
+/// Channel name for live balance deltas on one wallet.
+pub fn balance_channel(wallet_id: &str) -> String {
+    format!("balance:{}", wallet_id)
+}
+
+/// Channel name for live reward deltas on one wallet.
+pub fn rewards_channel(wallet_id: &str) -> String {
+    format!("rewards:{}", wallet_id)
+}
+
+/// How many unconsumed messages a channel buffers before a lagging
+/// subscriber starts missing them. Deltas are cheap to recompute from a
+/// follow-up poll, so we favor a bounded buffer over unbounded memory growth.
+const CHANNEL_CAPACITY: usize = 256;
+
+/// Fans published JSON deltas out to every subscriber of a channel.
+///
+/// Channels are created lazily on first subscribe or publish and live for
+/// the lifetime of the process; there's no explicit teardown since a
+/// `broadcast::Sender` with no receivers is just dead weight, not a leak.
+pub struct RealtimeHub {
+    channels: Arc<RwLock<HashMap<String, broadcast::Sender<String>>>>,
+}
+
+impl RealtimeHub {
+    pub fn new() -> Self {
+        Self {
+            channels: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    async fn sender_for(&self, channel: &str) -> broadcast::Sender<String> {
+        if let Some(tx) = self.channels.read().await.get(channel) {
+            return tx.clone();
+        }
+        let mut channels = self.channels.write().await;
+        channels
+            .entry(channel.to_string())
+            .or_insert_with(|| broadcast::channel(CHANNEL_CAPACITY).0)
+            .clone()
+    }
+
+    /// Subscribe to a channel's future deltas. Past deltas are not replayed;
+    /// callers that need current state should pair this with a REST fetch.
+    pub async fn subscribe(&self, channel: &str) -> broadcast::Receiver<String> {
+        self.sender_for(channel).await.subscribe()
+    }
+
+    /// Publish a delta to every current subscriber of a channel. Silently a
+    /// no-op when nobody is listening, same as any other fire-and-forget
+    /// broadcast.
+    pub async fn publish<T: Serialize>(&self, channel: &str, payload: &T) {
+        let json = match serde_json::to_string(payload) {
+            Ok(json) => json,
+            Err(e) => {
+                tracing::warn!("Failed to serialize realtime payload for {}: {}", channel, e);
+                return;
+            }
+        };
+        let _ = self.sender_for(channel).await.send(json);
+    }
+}
+
+impl Default for RealtimeHub {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -22,6 +22,20 @@ pub struct RewardCalculator {
     // Cache for performance
     pending_cache: Arc<tokio::sync::RwLock<HashMap<String, Decimal>>>,
     earnings_cache: Arc<tokio::sync::RwLock<HashMap<String, DailyEarnings>>>,
+
+    // Persistent PD-controller state steering emission toward the target lock ratio.
+    inflation: Arc<tokio::sync::RwLock<InflationController>>,
+}
+
+/// Integral state of the inflation PD controller, carried between epochs.
+#[derive(Debug, Clone)]
+struct InflationController {
+    /// Locked ratio observed in the previous epoch (derivative term).
+    last_locked_ratio: Decimal,
+    /// Inflation emitted in the previous epoch (integral term).
+    last_inflation: Decimal,
+    /// Per-hour base rate derived for the current epoch.
+    base_rate_per_hour: Decimal,
 }
 
 #[derive(Debug, Clone)]
@@ -35,14 +49,62 @@ impl RewardCalculator {
     /// Create a new RewardCalculator with proper storage initialization
     /// This constructor requires storage to be provided to ensure safety
     pub fn new(config: RewardConfig, storage: Arc<CaesarStorage>) -> Self {
+        let inflation = InflationController {
+            last_locked_ratio: config.target_locked_ratio,
+            last_inflation: dec!(0),
+            base_rate_per_hour: config.base_rate_per_hour,
+        };
         Self {
             config,
             storage,
             pending_cache: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
             earnings_cache: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
+            inflation: Arc::new(tokio::sync::RwLock::new(inflation)),
         }
     }
 
+    /// Advance the inflation controller by one epoch and return the new per-hour
+    /// base emission rate.
+    ///
+    /// Steers emission toward `target_locked_ratio` with a PD law:
+    /// `new = last + p·(target − current) − d·(current − last)`, clamped to
+    /// `[0, max_inflation]`. The per-hour base rate is then
+    /// `new · total_supply / epoch_hours`. Controller state (the integral term)
+    /// persists between calls; the reward math is guarded against overflow.
+    pub async fn update_inflation(
+        &self,
+        participating_supply: Decimal,
+        total_supply: Decimal,
+    ) -> Result<Decimal> {
+        if total_supply.is_zero() {
+            return Err(anyhow::anyhow!("total_supply must be positive"));
+        }
+
+        let current = participating_supply / total_supply;
+        let mut ctrl = self.inflation.write().await;
+
+        let new_inflation = (ctrl.last_inflation
+            + self.config.inflation_p_gain * (self.config.target_locked_ratio - current)
+            - self.config.inflation_d_gain * (current - ctrl.last_locked_ratio))
+            .clamp(dec!(0), self.config.max_inflation);
+
+        // Spread inflation across the epoch, guarding against overflow.
+        let base_rate = new_inflation
+            .checked_mul(total_supply)
+            .and_then(|x| x.checked_div(self.config.epoch_hours))
+            .ok_or_else(|| anyhow::anyhow!("inflation emission exceeds representable bounds"))?;
+
+        ctrl.last_locked_ratio = current;
+        ctrl.last_inflation = new_inflation;
+        ctrl.base_rate_per_hour = base_rate;
+
+        debug!(
+            "Inflation epoch: locked {:.4}, inflation {:.6}, base rate {}/hr",
+            current, new_inflation, base_rate
+        );
+        Ok(base_rate)
+    }
+
     /// Create a new RewardCalculator for testing purposes with default storage
     #[cfg(test)]
     pub fn new_for_testing(config: RewardConfig) -> Self {
@@ -152,7 +214,9 @@ impl RewardCalculator {
             return Err(anyhow::anyhow!("Invalid duration_hours: must be between 0 and 24"));
         }
 
-        let base_rate = self.config.base_rate_per_hour;
+        // Use the controller-driven per-hour rate for this epoch, which the
+        // inflation subsystem steers toward the target lock ratio.
+        let base_rate = self.inflation.read().await.base_rate_per_hour;
         let duration = Decimal::from_f64_retain(request.duration_hours).unwrap_or(dec!(1));
 
         // Safe decimal conversions with validation
@@ -263,6 +327,8 @@ impl RewardCalculator {
             fee: dec!(0),
             description: "Claimed pending rewards".to_string(),
             timestamp: Utc::now(),
+            applied_rate: None,
+            memo: None,
         };
 
         self.storage.create_transaction(transaction).await?;
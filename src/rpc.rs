@@ -0,0 +1,148 @@
+//! JSON-RPC 2.0 transport
+//!
+//! Exposes part of the REST surface in `lib.rs` to ecosystem wallet/daemon
+//! clients that speak JSON-RPC rather than REST: balance, send, stake, swap
+//! and rates. The other REST operations have no RPC method. Every method
+//! dispatches onto the
+//! same public `impl CaesarEconomicSystem` methods the REST handlers call,
+//! so this is purely a transport adapter with no business logic of its own.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Arc;
+
+use crate::models::{SendTransactionRequest, StakeRequest, SwapRequest};
+use crate::CaesarEconomicSystem;
+
+/// Standard JSON-RPC 2.0 reserved error codes.
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+/// Implementation-defined "server error" range (-32000 to -32099); used for
+/// any `anyhow::Error` surfaced by the underlying `CaesarEconomicSystem` call.
+const SERVER_ERROR: i64 = -32000;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct JsonRpcRequest {
+    pub jsonrpc: String,
+    #[serde(default)]
+    pub id: Option<Value>,
+    pub method: String,
+    #[serde(default)]
+    pub params: Value,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonRpcError {
+    pub code: i64,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonRpcResponse {
+    pub jsonrpc: &'static str,
+    pub id: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<JsonRpcError>,
+}
+
+impl JsonRpcResponse {
+    fn ok(id: Option<Value>, result: Value) -> Self {
+        Self { jsonrpc: "2.0", id, result: Some(result), error: None }
+    }
+
+    fn err(id: Option<Value>, code: i64, message: impl Into<String>) -> Self {
+        Self {
+            jsonrpc: "2.0",
+            id,
+            result: None,
+            error: Some(JsonRpcError { code, message: message.into() }),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct WalletIdParams {
+    wallet_id: String,
+}
+
+/// Route one method name onto the matching `CaesarEconomicSystem` call,
+/// deserializing `params` into whatever that call expects.
+async fn call(caesar: &Arc<CaesarEconomicSystem>, method: &str, params: Value) -> Result<Value, (i64, String)> {
+    macro_rules! parse_params {
+        ($ty:ty) => {
+            serde_json::from_value::<$ty>(params)
+                .map_err(|e| (INVALID_PARAMS, format!("invalid params for {}: {}", method, e)))?
+        };
+    }
+
+    let result = match method {
+        "caesar_getBalance" => {
+            let p: WalletIdParams = parse_params!(WalletIdParams);
+            caesar.get_wallet_balance(&p.wallet_id).await
+                .and_then(|r| Ok(serde_json::to_value(r)?))
+        }
+        "caesar_sendTransaction" => {
+            let p: SendTransactionRequest = parse_params!(SendTransactionRequest);
+            caesar.process_transaction(p).await
+                .and_then(|r| Ok(serde_json::to_value(r)?))
+        }
+        "caesar_stake" => {
+            let p: StakeRequest = parse_params!(StakeRequest);
+            caesar.stake_tokens_for_wallet(p).await
+                .and_then(|r| Ok(serde_json::to_value(r)?))
+        }
+        "caesar_swap" => {
+            let p: SwapRequest = parse_params!(SwapRequest);
+            caesar.execute_token_swap(p).await
+                .and_then(|r| Ok(serde_json::to_value(r)?))
+        }
+        "caesar_getRates" => {
+            caesar.get_current_exchange_rates().await
+                .and_then(|r| Ok(serde_json::to_value(r)?))
+        }
+        other => return Err((METHOD_NOT_FOUND, format!("method not found: {}", other))),
+    };
+
+    result.map_err(|e: anyhow::Error| (SERVER_ERROR, e.to_string()))
+}
+
+/// Handle one already-parsed JSON-RPC request.
+async fn dispatch_one(caesar: &Arc<CaesarEconomicSystem>, request: JsonRpcRequest) -> JsonRpcResponse {
+    if request.jsonrpc != "2.0" {
+        return JsonRpcResponse::err(request.id, INVALID_REQUEST, "jsonrpc must be \"2.0\"");
+    }
+
+    match call(caesar, &request.method, request.params).await {
+        Ok(result) => JsonRpcResponse::ok(request.id, result),
+        Err((code, message)) => JsonRpcResponse::err(request.id, code, message),
+    }
+}
+
+/// Handle a raw request body: either a single `{jsonrpc, id, method,
+/// params}` object or a batch array of them, per the JSON-RPC 2.0 spec.
+/// Returns the matching single response or response array as a `Value`.
+pub async fn handle_payload(caesar: &Arc<CaesarEconomicSystem>, payload: Value) -> Value {
+    match payload {
+        Value::Array(calls) => {
+            let mut responses = Vec::with_capacity(calls.len());
+            for call in calls {
+                responses.push(handle_one_value(caesar, call).await);
+            }
+            Value::Array(responses)
+        }
+        single => handle_one_value(caesar, single).await,
+    }
+}
+
+async fn handle_one_value(caesar: &Arc<CaesarEconomicSystem>, payload: Value) -> Value {
+    match serde_json::from_value::<JsonRpcRequest>(payload) {
+        Ok(request) => serde_json::to_value(dispatch_one(caesar, request).await)
+            .unwrap_or_else(|_| serde_json::json!({"jsonrpc": "2.0", "id": null, "error": {"code": SERVER_ERROR, "message": "failed to encode response"}})),
+        Err(e) => serde_json::to_value(JsonRpcResponse::err(None, PARSE_ERROR, format!("invalid JSON-RPC request: {}", e)))
+            .unwrap_or_else(|_| serde_json::json!({"jsonrpc": "2.0", "id": null, "error": {"code": PARSE_ERROR, "message": "parse error"}})),
+    }
+}
@@ -0,0 +1,190 @@
+//! Caesar Settlement - optional EVM on-chain settlement backend
+//!
+//! `CaesarEconomicSystem::bridge_to_chain`/`bridge_from_chain` delegate to a
+//! [`SettlementBackend`] to move value between the internal ledger and a
+//! real chain. The trait itself has no on-chain dependency, so the in-memory
+//! economy builds and runs with no backend configured (`settlement: None`);
+//! [`EthersBackend`], the only real implementation, is gated behind the
+//! `evm_settlement` feature so enabling on-chain settlement is opt-in.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BridgeToChainRequest {
+    pub wallet_id: String,
+    pub amount: Decimal,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BridgeToChainResponse {
+    pub tx_hash: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BridgeFromChainResponse {
+    pub tx_hash: String,
+    pub amount: Decimal,
+}
+
+/// Outcome of [`SettlementBackend::confirm_transfer`] for a previously
+/// submitted transaction hash.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TransferStatus {
+    /// Still below the backend's configured confirmation depth.
+    Pending,
+    /// Reached the confirmation depth; carries what it actually settled so
+    /// the caller can credit the right wallet for the right amount.
+    Confirmed { to: String, amount: Decimal },
+    /// Mined but reverted.
+    Failed,
+}
+
+/// On-chain settlement operations an EVM-backed economy can delegate to.
+#[async_trait]
+pub trait SettlementBackend: Send + Sync {
+    /// Submit an on-chain transfer crediting `to` with `amount` from an
+    /// externally-held reserve, returning the transaction hash to track via
+    /// [`Self::confirm_transfer`].
+    async fn deposit(&self, to: &str, amount: Decimal) -> Result<String>;
+
+    /// Submit an on-chain transfer debiting the backend's hot wallet and
+    /// sending `amount` to `to`, returning the transaction hash.
+    async fn withdraw(&self, to: &str, amount: Decimal) -> Result<String>;
+
+    /// Confirmation status of a previously submitted transfer.
+    async fn confirm_transfer(&self, tx_hash: &str) -> Result<TransferStatus>;
+}
+
+#[cfg(feature = "evm_settlement")]
+mod ethers_backend {
+    use super::{SettlementBackend, TransferStatus};
+    use anyhow::{anyhow, Result};
+    use async_trait::async_trait;
+    use ethers::abi::Abi;
+    use ethers::contract::Contract;
+    use ethers::middleware::{Middleware, SignerMiddleware};
+    use ethers::providers::{Http, Provider};
+    use ethers::signers::{LocalWallet, Signer};
+    use ethers::types::{Address, U256};
+    use rust_decimal::Decimal;
+    use std::str::FromStr;
+    use std::sync::Arc;
+
+    type Client = SignerMiddleware<Provider<Http>, LocalWallet>;
+
+    /// Real EVM settlement backend for an ERC-20 token, built on
+    /// `ethers::middleware::SignerMiddleware<Provider<Http>, LocalWallet>`.
+    pub struct EthersBackend {
+        client: Arc<Client>,
+        token: Contract<Client>,
+        /// Block confirmations required before a transfer is treated as final.
+        confirmations: u64,
+        /// ERC-20 `decimals()` this token's `U256` amounts are scaled by.
+        decimals: u32,
+    }
+
+    impl EthersBackend {
+        pub async fn new(
+            rpc_url: &str,
+            wallet: LocalWallet,
+            token_address: Address,
+            abi: Abi,
+            confirmations: u64,
+            decimals: u32,
+        ) -> Result<Self> {
+            let provider = Provider::<Http>::try_from(rpc_url)
+                .map_err(|e| anyhow!("invalid RPC endpoint: {}", e))?;
+            let chain_id = provider.get_chainid().await?.as_u64();
+            let client = Arc::new(SignerMiddleware::new(provider, wallet.with_chain_id(chain_id)));
+            let token = Contract::new(token_address, abi, client.clone());
+
+            Ok(Self { client, token, confirmations, decimals })
+        }
+
+        /// Scale a `Decimal` CSR amount up to the token's on-chain integer
+        /// precision. `rust_decimal::Decimal` tops out at 28-29 significant
+        /// digits, comfortably above 18-decimal wei granularity for any
+        /// balance this economy actually reaches.
+        fn to_wei(&self, amount: Decimal) -> Result<U256> {
+            let scale = Decimal::from(10u64.pow(self.decimals));
+            let scaled = amount
+                .checked_mul(scale)
+                .ok_or_else(|| anyhow!("amount overflowed scaling to {} decimals", self.decimals))?
+                .trunc();
+            U256::from_dec_str(&scaled.to_string()).map_err(|e| anyhow!("invalid on-chain amount: {}", e))
+        }
+
+        fn parse_address(to: &str) -> Result<Address> {
+            Address::from_str(to).map_err(|e| anyhow!("invalid recipient address {}: {}", to, e))
+        }
+
+        async fn transfer(&self, to: &str, amount: Decimal) -> Result<String> {
+            let to = Self::parse_address(to)?;
+            let amount = self.to_wei(amount)?;
+            let call = self.token.method::<_, bool>("transfer", (to, amount))?;
+            let pending = call.send().await?;
+            Ok(format!("{:?}", pending.tx_hash()))
+        }
+    }
+
+    #[async_trait]
+    impl SettlementBackend for EthersBackend {
+        async fn deposit(&self, to: &str, amount: Decimal) -> Result<String> {
+            self.transfer(to, amount).await
+        }
+
+        async fn withdraw(&self, to: &str, amount: Decimal) -> Result<String> {
+            self.transfer(to, amount).await
+        }
+
+        async fn confirm_transfer(&self, tx_hash: &str) -> Result<TransferStatus> {
+            let hash = ethers::types::H256::from_str(tx_hash)
+                .map_err(|e| anyhow!("invalid transaction hash {}: {}", tx_hash, e))?;
+
+            let receipt = match self.client.get_transaction_receipt(hash).await? {
+                Some(receipt) => receipt,
+                None => return Ok(TransferStatus::Pending),
+            };
+
+            if receipt.status.map(|s| s.as_u64()) != Some(1) {
+                return Ok(TransferStatus::Failed);
+            }
+
+            let Some(mined_block) = receipt.block_number else {
+                return Ok(TransferStatus::Pending);
+            };
+            let current_block = self.client.get_block_number().await?;
+            if current_block.saturating_sub(mined_block).as_u64() < self.confirmations {
+                return Ok(TransferStatus::Pending);
+            }
+
+            // The ERC-20 `Transfer(address,address,uint256)` event is always
+            // the contract's first topic-0 log; decoding it (rather than
+            // trusting the request we originally submitted) is what makes
+            // this a real confirmation instead of an optimistic echo.
+            let transfer_log = receipt
+                .logs
+                .iter()
+                .find(|log| log.address == *self.token.address())
+                .ok_or_else(|| anyhow!("Transaction {} has no transfer log for this token", tx_hash))?;
+
+            let decoded = self
+                .token
+                .decode_event::<(Address, Address, U256)>("Transfer", transfer_log.topics.clone(), transfer_log.data.clone())?;
+            let (_from, to, wei) = decoded;
+
+            let scale = Decimal::from(10u64.pow(self.decimals));
+            let amount = Decimal::from_str(&wei.to_string())
+                .map_err(|e| anyhow!("on-chain amount out of range: {}", e))?
+                / scale;
+
+            Ok(TransferStatus::Confirmed { to: format!("{:?}", to), amount })
+        }
+    }
+}
+
+#[cfg(feature = "evm_settlement")]
+pub use ethers_backend::EthersBackend;
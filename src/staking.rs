@@ -13,15 +13,58 @@ use crate::models::*;
 use crate::storage::CaesarStorage;
 use crate::StakingConfig;
 
+/// A fixed reward pool to be split across stakes by integer "points" for one
+/// distribution round. Splitting on `u128` points with multiply-before-divide
+/// is deterministic across platforms, unlike the old `f64` compounding.
+struct PointValue {
+    rewards: Decimal,
+    total_points: u128,
+}
+
+impl PointValue {
+    /// Reward owed to a holder of `points` in this round. Multiplies before
+    /// dividing so integer truncation doesn't bias small stakes toward zero.
+    fn share(&self, points: u128) -> Decimal {
+        if self.total_points == 0 {
+            return dec!(0);
+        }
+        self.rewards * Decimal::from(points) / Decimal::from(self.total_points)
+    }
+}
+
+/// One stake's computed slice of a distribution round, carried between
+/// [`StakingManager::reward_shares`] and its callers.
+struct RewardShare {
+    stake_id: String,
+    principal: Decimal,
+    apy: Decimal,
+    seconds_staked: i64,
+    reward: Decimal,
+}
+
+impl RewardShare {
+    /// A zero-reward slice for a stake that accrued no points this round.
+    fn zero(stake: &StakeInfo, seconds_staked: i64) -> Self {
+        Self {
+            stake_id: stake.stake_id.clone(),
+            principal: stake.amount,
+            apy: stake.apy,
+            seconds_staked,
+            reward: dec!(0),
+        }
+    }
+}
+
 /// Staking manager for token staking and rewards
 pub struct StakingManager {
     config: StakingConfig,
+    total_supply: Decimal,
     storage: Arc<CaesarStorage>,
 }
 
 impl StakingManager {
-    pub async fn new(config: StakingConfig, storage: Arc<CaesarStorage>) -> Result<Self> {
-        Ok(Self { config, storage })
+    pub async fn new(config: StakingConfig, total_supply: Decimal, storage: Arc<CaesarStorage>) -> Result<Self> {
+        Ok(Self { config, total_supply, storage })
     }
 
     /// Get all active stakes for a wallet
@@ -34,51 +77,200 @@ impl StakingManager {
         self.storage.get_total_staked(wallet_id).await
     }
 
-    /// Get current APY rate
-    pub fn get_current_apy(&self) -> Decimal {
-        self.config.base_apy
+    /// Configured total token supply this manager's inflation is computed against.
+    pub async fn get_total_supply(&self) -> Result<Decimal> {
+        Ok(self.total_supply)
+    }
+
+    /// Effective (reward-earning) stake across every wallet in the network.
+    pub async fn get_total_effective_stake(&self) -> Result<Decimal> {
+        let stakes = self.storage.get_all_active_stakes().await?;
+        Ok(stakes.iter().map(|s| s.effective_amount).sum())
+    }
+
+    /// Distinct wallets holding an active stake.
+    pub async fn get_total_stakers(&self) -> Result<u64> {
+        let stakes = self.storage.get_all_active_stakes().await?;
+        let wallets: std::collections::HashSet<&str> =
+            stakes.iter().map(|s| s.wallet_id.as_str()).collect();
+        Ok(wallets.len() as u64)
+    }
+
+    /// Active stake, bucketed by requested `lock_period_days` (0 for
+    /// unlocked), as `(days, amount, percentage of total staked)`.
+    pub async fn get_lock_distribution(&self) -> Result<Vec<LockPeriodDistribution>> {
+        let stakes = self.storage.get_all_active_stakes().await?;
+        let mut buckets: std::collections::BTreeMap<u32, Decimal> = std::collections::BTreeMap::new();
+        let mut total = dec!(0);
+        for stake in &stakes {
+            *buckets.entry(stake.lock_period_days.unwrap_or(0)).or_insert(dec!(0)) += stake.amount;
+            total += stake.amount;
+        }
+
+        Ok(buckets
+            .into_iter()
+            .map(|(days, amount)| {
+                let percentage = if total > dec!(0) { (amount / total) * dec!(100) } else { dec!(0) };
+                LockPeriodDistribution { days, amount, percentage }
+            })
+            .collect())
+    }
+
+    /// Current network-wide APY, derived from the staking PD controller's
+    /// last epoch. Falls back to `base_apy` before the first epoch has run
+    /// or while nothing is staked.
+    pub async fn get_current_apy(&self) -> Result<Decimal> {
+        match self.storage.get_staking_inflation_state().await? {
+            Some((_, _, effective_apy)) => Ok(effective_apy),
+            None => Ok(self.config.base_apy),
+        }
+    }
+
+    /// Active inflation rate and locked-ratio status, for
+    /// [`crate::analytics::AnalyticsEngine::get_staking_analytics`].
+    ///
+    /// Returns `(current_apy, inflation_rate, locked_ratio, target_locked_ratio)`.
+    pub async fn get_inflation_status(&self) -> Result<(Decimal, Decimal, Decimal, Decimal)> {
+        let current_apy = self.get_current_apy().await?;
+        let (locked_ratio, inflation_rate) = match self.storage.get_staking_inflation_state().await? {
+            Some((locked_ratio, inflation, _)) => (locked_ratio, inflation),
+            None => (dec!(0), dec!(0)),
+        };
+        Ok((current_apy, inflation_rate, locked_ratio, self.config.target_locked_ratio))
+    }
+
+    /// Advance the staking inflation PD controller by one epoch and return
+    /// the new effective APY.
+    ///
+    /// Namada-style controller: `error = target_locked_ratio - locked_ratio`,
+    /// `delta = locked_ratio - last_locked_ratio`, and
+    /// `inflation = clamp(last_inflation + p_gain·error - d_gain·delta, 0,
+    /// max_inflation)`, all on an annual basis. The annual token emission
+    /// (`inflation · total_supply`) is then expressed as an effective APY
+    /// over the currently-effective stake, so stakers actually earn
+    /// `emission / total_staked` rather than the flat `base_apy`. Falls back
+    /// to `base_apy` while nothing is staked, since the ratio is undefined.
+    /// Controller state persists in storage between epochs.
+    pub async fn update_inflation(&self) -> Result<Decimal> {
+        let total_staked = self.get_total_effective_stake().await?;
+        let locked_ratio = if self.total_supply.is_zero() {
+            dec!(0)
+        } else {
+            total_staked / self.total_supply
+        };
+
+        let (last_locked_ratio, last_inflation) = match self.storage.get_staking_inflation_state().await? {
+            Some((ratio, inflation, _)) => (ratio, inflation),
+            None => (locked_ratio, dec!(0)),
+        };
+
+        let error = self.config.target_locked_ratio - locked_ratio;
+        let delta = locked_ratio - last_locked_ratio;
+        let inflation = (last_inflation
+            + self.config.inflation_p_gain * error
+            - self.config.inflation_d_gain * delta)
+            .clamp(dec!(0), self.config.max_inflation);
+
+        let annual_emission = inflation * self.total_supply;
+        let effective_apy = if total_staked.is_zero() {
+            self.config.base_apy
+        } else {
+            (annual_emission / total_staked) * dec!(100)
+        };
+
+        self.storage
+            .set_staking_inflation_state(locked_ratio, inflation, effective_apy)
+            .await?;
+
+        debug!(
+            "Staking inflation epoch: locked {:.4} (target {:.4}), inflation {:.6}, effective APY {}%",
+            locked_ratio, self.config.target_locked_ratio, inflation, effective_apy
+        );
+        Ok(effective_apy)
     }
 
     /// Calculate accumulated staking rewards
     pub async fn calculate_rewards(&self, wallet_id: &str) -> Result<Decimal> {
         let stakes = self.get_stakes(wallet_id).await?;
-        let mut total_rewards = dec!(0);
+        Ok(Self::reward_shares(&stakes)
+            .into_iter()
+            .map(|share| share.reward)
+            .sum())
+    }
 
+    /// Split the round's reward pool across a wallet's stakes by integer points.
+    ///
+    /// The pool is the APY interest the stakes accrued over the elapsed time,
+    /// computed entirely in `Decimal`; each stake's points are
+    /// `effective_amount * seconds_staked` (scaled to micro-tokens for
+    /// sub-token precision) and its reward is the point-weighted share of the
+    /// pool. Only `effective_amount` — the portion that has cleared warmup —
+    /// earns anything, so a stake still ramping up or cooling down is paid on
+    /// just its active slice. Using [`PointValue`] keeps the result identical
+    /// on every platform, unlike the old `f64` `powf` compounding.
+    fn reward_shares(stakes: &[StakeInfo]) -> Vec<RewardShare> {
+        let now = Utc::now();
+
+        let mut entries = Vec::with_capacity(stakes.len());
+        let mut total_points: u128 = 0;
+        let mut pool = dec!(0);
         for stake in stakes {
-            let days_staked = (Utc::now() - stake.start_date).num_days() as i32;
-            if days_staked <= 0 {
+            let seconds = (now - stake.start_date).num_seconds();
+            if seconds <= 0 {
                 continue;
             }
+            let points = Self::stake_points(stake.effective_amount, seconds);
+            let time_in_years = Decimal::from(seconds) / dec!(31_536_000);
+            pool += stake.effective_amount * (stake.apy / dec!(100)) * time_in_years;
+            total_points = total_points.saturating_add(points);
+            entries.push((stake, seconds, points));
+        }
 
-            // Calculate compound interest
-            // A = P(1 + r/n)^(nt)
-            // where P = principal, r = annual rate, n = compound frequency, t = time in years
-            let principal = stake.amount;
-            let rate = stake.apy / dec!(100); // Convert percentage to decimal
-            let compounds_per_year = dec!(365) / Decimal::from(self.config.compound_frequency_hours) * dec!(24);
-            let time_in_years = Decimal::from(days_staked) / dec!(365);
-
-            let compound_factor = dec!(1) + (rate / compounds_per_year);
-            let exponent = compounds_per_year * time_in_years;
-
-            // Approximate compound interest calculation
-            let final_amount = principal * Self::power_approximation(compound_factor, exponent);
-            let rewards = final_amount - principal;
+        if total_points == 0 {
+            // No accrued points this round (e.g. every stake started this
+            // instant); there is nothing to split.
+            debug!("skipping reward distribution: total_points == 0");
+            return entries
+                .into_iter()
+                .map(|(stake, seconds, _)| RewardShare::zero(stake, seconds))
+                .collect();
+        }
 
-            total_rewards += rewards;
+        let point_value = PointValue { rewards: pool, total_points };
+        let mut distributed = dec!(0);
+        let last = entries.len() - 1;
+        let mut shares = Vec::with_capacity(entries.len());
+        for (i, (stake, seconds, points)) in entries.into_iter().enumerate() {
+            // Clamp the final recipient to the undistributed remainder so the
+            // distributed total can never exceed the pool through rounding.
+            let reward = if i == last {
+                (point_value.rewards - distributed).max(dec!(0))
+            } else {
+                let reward = point_value.share(points);
+                distributed += reward;
+                reward
+            };
+            shares.push(RewardShare {
+                stake_id: stake.stake_id.clone(),
+                principal: stake.amount,
+                apy: stake.apy,
+                seconds_staked: seconds,
+                reward,
+            });
         }
 
-        Ok(total_rewards)
+        debug_assert!(
+            shares.iter().map(|s| s.reward).sum::<Decimal>() <= point_value.rewards + dec!(0.0000001),
+            "distributed rewards exceeded the pool"
+        );
+        shares
     }
 
-    /// Power approximation for compound interest
-    fn power_approximation(base: Decimal, exponent: Decimal) -> Decimal {
-        // Simple approximation using Taylor series
-        // For more accuracy, use proper math library
-        let exp_f64 = exponent.to_f64().unwrap_or(1.0);
-        let base_f64 = base.to_f64().unwrap_or(1.0);
-        let result = base_f64.powf(exp_f64);
-        Decimal::from_f64_retain(result).unwrap_or(base)
+    /// Points a stake holds in a distribution round: `principal * seconds`, with
+    /// the principal taken in micro-tokens so sub-token stakes still score.
+    fn stake_points(principal: Decimal, seconds: i64) -> u128 {
+        let micro_tokens = (principal * dec!(1_000_000)).trunc().to_u128().unwrap_or(0);
+        micro_tokens.saturating_mul(seconds.max(0) as u128)
     }
 
     /// Stake tokens
@@ -99,26 +291,35 @@ impl StakingManager {
             return Err(anyhow!("Insufficient balance"));
         }
 
-        // Calculate APY based on lock period
+        // Calculate APY based on lock period, off the PD-controller's current
+        // network rate rather than the static `base_apy` so new stakes
+        // respond to how far the network is from its target locked ratio.
+        let base_apy = self.get_current_apy().await?;
         let apy = if let Some(lock_days) = request.lock_period_days {
             // Higher APY for longer lock periods
             let bonus = Decimal::from(lock_days) / dec!(365) * dec!(2); // Up to 2% bonus for 1 year lock
-            self.config.base_apy + bonus
+            base_apy + bonus
         } else {
-            self.config.base_apy
+            base_apy
         };
 
         // Create stake
         let stake_id = format!("STK_{}", Uuid::new_v4());
+        let now = Utc::now();
         let stake = StakeInfo {
             stake_id: stake_id.clone(),
             wallet_id: request.wallet_id.clone(),
             amount: request.amount,
-            start_date: Utc::now(),
+            start_date: now,
             lock_period_days: request.lock_period_days,
             apy,
             accumulated_rewards: dec!(0),
             is_active: true,
+            // Starts at zero and ramps up toward `amount` over successive
+            // activation epochs, bounded by `warmup_cooldown_rate`.
+            effective_amount: dec!(0),
+            deactivating: false,
+            last_reward_at: now,
         };
 
         self.storage.create_stake(stake).await?;
@@ -142,6 +343,8 @@ impl StakingManager {
             fee: dec!(0),
             description: format!("Staked {} CSR for {} days", request.amount, days),
             timestamp: Utc::now(),
+            applied_rate: None,
+            memo: None,
         };
 
         self.storage.create_transaction(transaction.clone()).await?;
@@ -183,43 +386,88 @@ impl StakingManager {
         if amount_to_unstake > stake.amount {
             return Err(anyhow!("Cannot unstake more than staked amount"));
         }
+        if amount_to_unstake <= dec!(0) {
+            return Err(anyhow!("Unstake amount must be positive"));
+        }
+
+        if stake.deactivating {
+            return Err(anyhow!("Stake is already cooling down"));
+        }
 
         // Calculate rewards
         let days_staked = (Utc::now() - stake.start_date).num_days() as i32;
         let time_in_years = Decimal::from(days_staked.max(1)) / dec!(365);
         let rewards = amount_to_unstake * (stake.apy / dec!(100)) * time_in_years;
 
-        // Update or deactivate stake
-        if amount_to_unstake >= stake.amount {
-            self.storage.deactivate_stake(&stake.stake_id).await?;
-        } else {
-            // Partial unstake not implemented yet, would update stake amount
-            return Err(anyhow!("Partial unstaking not yet supported"));
-        }
-
-        // Return funds to wallet
-        let balance = self.storage.get_balance(&request.wallet_id).await?;
+        // Funds aren't moved yet: unstake() only starts cooldown, ramping
+        // `effective_amount` down to zero over successive activation epochs
+        // (see `run_activation_epoch`). The payout is locked in now so it
+        // can't be re-derived from a reward calculation that keeps accruing
+        // while the stake cools down, and released once cooldown finishes.
         let total_return = amount_to_unstake + rewards;
-        self.storage.update_balance(&request.wallet_id, balance + total_return).await?;
 
-        // Create transaction record
         let transaction = Transaction {
             transaction_id: format!("TX_{}", Uuid::new_v4()),
             from_wallet: "STAKING_POOL".to_string(),
             to_wallet: request.wallet_id.clone(),
             amount: total_return,
             transaction_type: TransactionType::Unstaking,
-            status: TransactionStatus::Completed,
+            status: TransactionStatus::Pending,
             fee: dec!(0),
-            description: format!("Unstaked {} CSR with {} CSR rewards", amount_to_unstake, rewards),
+            description: format!("Unstaking {} CSR with {} CSR rewards, cooling down", amount_to_unstake, rewards),
             timestamp: Utc::now(),
+            applied_rate: None,
+            memo: None,
         };
-
         self.storage.create_transaction(transaction.clone()).await?;
 
+        let remaining_principal = if amount_to_unstake < stake.amount {
+            // Partial unstake: split the withdrawn slice into its own stake
+            // row so it can cool down independently through the existing
+            // deactivating/effective_amount machinery, while this row keeps
+            // its `start_date`/`apy`/`lock_period_days` and keeps accruing
+            // on whatever principal remains.
+            let withdrawn_effective = stake.effective_amount.min(amount_to_unstake);
+            let remaining_amount = stake.amount - amount_to_unstake;
+            let remaining_effective = stake.effective_amount - withdrawn_effective;
+
+            let withdrawn_stake = StakeInfo {
+                stake_id: format!("STK_{}", Uuid::new_v4()),
+                wallet_id: request.wallet_id.clone(),
+                amount: amount_to_unstake,
+                start_date: stake.start_date,
+                lock_period_days: stake.lock_period_days,
+                apy: stake.apy,
+                accumulated_rewards: dec!(0),
+                is_active: true,
+                effective_amount: withdrawn_effective,
+                deactivating: true,
+                last_reward_at: stake.last_reward_at,
+            };
+            self.storage.create_stake(withdrawn_stake.clone()).await?;
+            self.storage
+                .start_stake_cooldown(&withdrawn_stake.stake_id, total_return, &transaction.transaction_id)
+                .await?;
+
+            self.storage.reduce_stake(&stake.stake_id, remaining_amount).await?;
+            self.storage
+                .update_stake_effective_amount(&stake.stake_id, remaining_effective)
+                .await?;
+
+            remaining_amount
+        } else {
+            self.storage
+                .start_stake_cooldown(&stake.stake_id, total_return, &transaction.transaction_id)
+                .await?;
+            dec!(0)
+        };
+
         let cooldown_ends = Utc::now() + Duration::hours(self.config.unstaking_cooldown_hours as i64);
 
-        info!("Wallet {} unstaked {} CSR with {} CSR rewards", request.wallet_id, amount_to_unstake, rewards);
+        info!(
+            "Wallet {} started cooldown on {} CSR of stake {} with {} CSR rewards, {} CSR remaining active",
+            request.wallet_id, amount_to_unstake, stake.stake_id, rewards, remaining_principal
+        );
 
         Ok(UnstakeResponse {
             wallet_id: request.wallet_id,
@@ -227,36 +475,376 @@ impl StakingManager {
             rewards_claimed: rewards,
             transaction_id: transaction.transaction_id,
             cooldown_ends,
+            remaining_principal,
         })
     }
 
+    /// Immediately pay a stake's principal and `accumulated_rewards` out to
+    /// `destination_wallet` (the stake's own wallet when `None`) and
+    /// deactivate it, bypassing the gradual cooldown ramp `unstake()` uses.
+    /// `early_withdraw` skips the lock-period check. Returns the amount paid
+    /// out.
+    pub async fn withdraw_stake(
+        &self,
+        stake_id: &str,
+        destination_wallet: Option<&str>,
+        early_withdraw: bool,
+    ) -> Result<Decimal> {
+        let payout = self
+            .storage
+            .withdraw_stake(stake_id, destination_wallet, early_withdraw)
+            .await?;
+        info!("Stake {} withdrawn, {} CSR paid out", stake_id, payout);
+        Ok(payout)
+    }
+
+    /// Apply each active stake's APY pro-rata for the time elapsed since its
+    /// `last_reward_at`, crediting the result into `accumulated_rewards`.
+    /// Unlike [`Self::process_reward_partition`], which credits a fixed,
+    /// network-wide inflation pool split by points, this credits each stake
+    /// independently off its own `apy` — a direct top-up path for a caller
+    /// that wants up-to-date rewards (e.g. before [`Self::withdraw_stake`],
+    /// which does not accrue on its own) without waiting on the partitioned
+    /// epoch to cycle through.
+    pub async fn accrue_stake_rewards(&self, now: DateTime<Utc>) -> Result<()> {
+        for stake in self.storage.get_all_active_stakes().await? {
+            let elapsed = now - stake.last_reward_at;
+            if elapsed <= Duration::zero() {
+                continue;
+            }
+            let time_in_years = Decimal::from(elapsed.num_seconds()) / (dec!(365) * dec!(86400));
+            let reward = stake.effective_amount * (stake.apy / dec!(100)) * time_in_years;
+            if reward <= dec!(0) {
+                continue;
+            }
+            self.storage.credit_stake_reward(&stake.stake_id, reward, now).await?;
+        }
+        Ok(())
+    }
+
     /// Get rewards breakdown for all stakes
     pub async fn get_rewards_breakdown(&self, wallet_id: &str) -> Result<Vec<StakeRewardBreakdown>> {
         let stakes = self.get_stakes(wallet_id).await?;
-        let mut breakdown = Vec::new();
+        Ok(Self::reward_shares(&stakes)
+            .into_iter()
+            .map(|share| StakeRewardBreakdown {
+                stake_id: share.stake_id,
+                principal: share.principal,
+                rewards: share.reward,
+                apy: share.apy,
+                days_staked: (share.seconds_staked / 86_400) as u32,
+            })
+            .collect())
+    }
 
-        for stake in stakes {
-            let days_staked = (Utc::now() - stake.start_date).num_days() as u32;
-            let time_in_years = Decimal::from(days_staked) / dec!(365);
-            let rewards = stake.amount * (stake.apy / dec!(100)) * time_in_years;
+    /// Warmup/cooldown breakdown for a single stake: how much of its
+    /// requested amount is effective, still activating, or cooling down.
+    pub async fn get_activation_status(&self, stake_id: &str) -> Result<StakeActivationStatus> {
+        let stake = self.storage.get_stake(stake_id).await?;
 
-            breakdown.push(StakeRewardBreakdown {
-                stake_id: stake.stake_id,
-                principal: stake.amount,
-                rewards,
-                apy: stake.apy,
-                days_staked,
-            });
+        let (activating_amount, deactivating_amount) = if stake.deactivating {
+            (dec!(0), stake.effective_amount)
+        } else {
+            (stake.amount - stake.effective_amount, dec!(0))
+        };
+
+        Ok(StakeActivationStatus {
+            stake_id: stake.stake_id,
+            requested_amount: stake.amount,
+            effective_amount: stake.effective_amount,
+            activating_amount,
+            deactivating_amount,
+            inactive: !stake.is_active,
+        })
+    }
+
+    /// Advance warmup/cooldown by one activation epoch (called periodically,
+    /// e.g. daily, by [`Self::distribute_staking_rewards`]).
+    ///
+    /// At most `warmup_cooldown_rate` percent of the network's current
+    /// effective stake may move per epoch: that budget is split pro-rata
+    /// across stakes still warming up, and an independent budget of the same
+    /// size across stakes cooling down. A stake whose cooldown reaches zero
+    /// is finalized immediately: its locked-in payout from `unstake()` is
+    /// released and the stake is deactivated.
+    pub async fn run_activation_epoch(&self) -> Result<StakeActivationPeriod> {
+        let period_start = Utc::now();
+        let stakes = self.storage.get_all_active_stakes().await?;
+        let network_effective: Decimal = stakes.iter().map(|s| s.effective_amount).sum();
+
+        let activating: Vec<&StakeInfo> = stakes
+            .iter()
+            .filter(|s| !s.deactivating && s.effective_amount < s.amount)
+            .collect();
+        let deactivating: Vec<&StakeInfo> = stakes
+            .iter()
+            .filter(|s| s.deactivating && s.effective_amount > dec!(0))
+            .collect();
+
+        let activated = self.apply_activation_budget(&activating, network_effective, true).await?;
+        let deactivated = self.apply_activation_budget(&deactivating, network_effective, false).await?;
+
+        let period = StakeActivationPeriod {
+            period_start,
+            total_effective: network_effective + activated - deactivated,
+            activated,
+            deactivated,
+        };
+        self.storage.record_activation_period(&period).await?;
+
+        debug!(
+            "Activation epoch: {} activated, {} deactivated, {} total effective stake",
+            period.activated, period.deactivated, period.total_effective
+        );
+        Ok(period)
+    }
+
+    /// Moves a bounded pool of stake toward (`grow = true`) or away from
+    /// (`grow = false`) each stake's `effective_amount`, split pro-rata by
+    /// its remaining distance to target. The last recipient is clamped to
+    /// the undistributed remainder so rounding can't exceed the budget, the
+    /// same trick [`Self::reward_shares`] uses. Persists each stake's new
+    /// `effective_amount` and finalizes any cooldown that reaches zero.
+    /// Returns the total amount moved.
+    async fn apply_activation_budget(
+        &self,
+        stakes: &[&StakeInfo],
+        network_effective: Decimal,
+        grow: bool,
+    ) -> Result<Decimal> {
+        if stakes.is_empty() {
+            return Ok(dec!(0));
+        }
+
+        let demand: Vec<Decimal> = stakes
+            .iter()
+            .map(|s| if grow { s.amount - s.effective_amount } else { s.effective_amount })
+            .collect();
+        let total_demand: Decimal = demand.iter().sum();
+        if total_demand <= dec!(0) {
+            return Ok(dec!(0));
         }
 
-        Ok(breakdown)
+        let budget = if network_effective <= dec!(0) {
+            // Nothing is effective yet, so a percentage of zero would never
+            // let the first stakes activate. Bootstrap the network by
+            // letting initial demand in uncapped.
+            total_demand
+        } else {
+            (network_effective * (self.config.warmup_cooldown_rate / dec!(100))).min(total_demand)
+        };
+
+        let last = stakes.len() - 1;
+        let mut distributed = dec!(0);
+        for (i, stake) in stakes.iter().enumerate() {
+            let share = if i == last {
+                (budget - distributed).max(dec!(0))
+            } else {
+                let share = budget * demand[i] / total_demand;
+                distributed += share;
+                share
+            };
+
+            let new_effective = if grow {
+                stake.effective_amount + share
+            } else {
+                (stake.effective_amount - share).max(dec!(0))
+            };
+            self.storage.update_stake_effective_amount(&stake.stake_id, new_effective).await?;
+
+            if !grow && new_effective <= dec!(0) {
+                self.finalize_cooldown(&stake.stake_id).await?;
+            }
+        }
+
+        Ok(budget)
+    }
+
+    /// Release the payout `unstake()` locked in once a stake's cooldown
+    /// reaches zero effective amount, and deactivate the stake.
+    async fn finalize_cooldown(&self, stake_id: &str) -> Result<()> {
+        let (payout, transaction_id) = self.storage.get_pending_payout(stake_id).await?;
+        let stake = self.storage.get_stake(stake_id).await?;
+
+        let balance = self.storage.get_balance(&stake.wallet_id).await?;
+        self.storage.update_balance(&stake.wallet_id, balance + payout).await?;
+        self.storage.update_transaction_status(&transaction_id, TransactionStatus::Completed).await?;
+        self.storage.deactivate_stake(stake_id).await?;
+
+        info!(
+            "Wallet {} finished cooldown on stake {}, released {} CSR",
+            stake.wallet_id, stake_id, payout
+        );
+        Ok(())
     }
 
-    /// Process automated staking rewards distribution
+    /// Process automated staking rewards distribution: advances
+    /// warmup/cooldown and the inflation PD controller for the network, then
+    /// credits one partition's worth of the current (or newly started)
+    /// reward epoch. Call this periodically (e.g. every block or cron tick);
+    /// a full epoch finishes after `reward_partitions` calls.
     pub async fn distribute_staking_rewards(&self) -> Result<()> {
-        // This would be called periodically to compound rewards
-        // For now, rewards are calculated on-demand
-        debug!("Processing staking rewards distribution");
+        self.run_activation_epoch().await?;
+        self.update_inflation().await?;
+        self.process_reward_partition().await?;
+        Ok(())
+    }
+
+    /// Current (or last completed) reward distribution epoch, for
+    /// observability and to let a caller tell whether a crash left one
+    /// partially credited.
+    pub async fn get_epoch_reward_status(&self) -> Result<Option<EpochRewardStatus>> {
+        self.storage.get_epoch_reward_status().await
+    }
+
+    /// Credit one deterministic partition of active stakes toward the
+    /// current reward epoch, starting a new epoch if the last one finished
+    /// (or none has ever run). The epoch pool is the controller's annual
+    /// emission (`inflation_rate * total_supply`) scaled down to
+    /// `epoch_hours`, split evenly across `reward_partitions` slices so each
+    /// call only touches a fraction of all stakes. `partitions_remaining` is
+    /// persisted after every partition, so a crash mid-epoch resumes from
+    /// the partitions not yet processed instead of re-crediting them.
+    async fn process_reward_partition(&self) -> Result<()> {
+        let status = match self.storage.get_epoch_reward_status().await? {
+            Some(status) if !status.partitions_remaining.is_empty() => status,
+            _ => {
+                let (_, inflation_rate, _, _) = self.get_inflation_status().await?;
+                let total_pool = inflation_rate * self.total_supply
+                    * (self.config.epoch_hours / dec!(8760));
+                EpochRewardStatus {
+                    epoch_id: format!("EPOCH_{}", Uuid::new_v4()),
+                    total_pool,
+                    distributed: dec!(0),
+                    partitions_remaining: (0..self.config.reward_partitions).collect(),
+                    started_at: Utc::now(),
+                }
+            }
+        };
+
+        let partition = status.partitions_remaining[0];
+        let slice_pool = status.total_pool / Decimal::from(self.config.reward_partitions.max(1));
+        let stakes: Vec<StakeInfo> = self
+            .storage
+            .get_all_active_stakes()
+            .await?
+            .into_iter()
+            .filter(|s| Self::partition_of(&s.stake_id, self.config.reward_partitions) == partition)
+            .collect();
+
+        let now = Utc::now();
+        let shares = Self::partition_reward_shares(&stakes, slice_pool, now);
+
+        let mut credited = dec!(0);
+        for (stake_id, wallet_id, reward) in shares {
+            if reward <= dec!(0) {
+                continue;
+            }
+            self.storage.credit_stake_reward(&stake_id, reward, now).await?;
+            self.storage
+                .create_reward(RewardEntry {
+                    reward_id: format!("RWD_{}", Uuid::new_v4()),
+                    wallet_id: wallet_id.clone(),
+                    amount: reward,
+                    reward_type: RewardType::StakingReward,
+                    source: RewardSource {
+                        source_type: "staking_epoch".to_string(),
+                        description: format!("Staking reward epoch {}, partition {}", status.epoch_id, partition),
+                        multiplier: dec!(1),
+                        resource_metrics: None,
+                    },
+                    timestamp: now,
+                    claimed: false,
+                })
+                .await?;
+            self.storage
+                .create_transaction(Transaction {
+                    transaction_id: format!("TX_{}", Uuid::new_v4()),
+                    from_wallet: "STAKING_POOL".to_string(),
+                    to_wallet: wallet_id,
+                    amount: reward,
+                    transaction_type: TransactionType::Reward,
+                    status: TransactionStatus::Completed,
+                    fee: dec!(0),
+                    description: format!("Staking reward for stake {} (epoch {})", stake_id, status.epoch_id),
+                    timestamp: now,
+                    applied_rate: None,
+                    memo: None,
+                })
+                .await?;
+            credited += reward;
+        }
+
+        let mut partitions_remaining = status.partitions_remaining;
+        partitions_remaining.retain(|p| *p != partition);
+        let updated = EpochRewardStatus {
+            distributed: status.distributed + credited,
+            partitions_remaining,
+            ..status
+        };
+
+        debug!(
+            "Reward epoch {}: credited partition {} ({} CSR), {} partition(s) remaining",
+            updated.epoch_id, partition, credited, updated.partitions_remaining.len()
+        );
+        self.storage.set_epoch_reward_status(&updated).await?;
         Ok(())
     }
+
+    /// Split `pool` across `stakes` by integer points accrued since each
+    /// stake's `last_reward_at`, the same clamp-last-to-remainder scheme as
+    /// [`Self::reward_shares`]. Returns `(stake_id, wallet_id, reward)`.
+    fn partition_reward_shares(
+        stakes: &[StakeInfo],
+        pool: Decimal,
+        now: DateTime<Utc>,
+    ) -> Vec<(String, String, Decimal)> {
+        let mut entries = Vec::with_capacity(stakes.len());
+        let mut total_points: u128 = 0;
+        for stake in stakes {
+            let seconds = (now - stake.last_reward_at).num_seconds();
+            if seconds <= 0 {
+                continue;
+            }
+            let points = Self::stake_points(stake.effective_amount, seconds);
+            total_points = total_points.saturating_add(points);
+            entries.push((stake, points));
+        }
+
+        if total_points == 0 {
+            return Vec::new();
+        }
+
+        let point_value = PointValue { rewards: pool, total_points };
+        let mut distributed = dec!(0);
+        let last = entries.len() - 1;
+        let mut shares = Vec::with_capacity(entries.len());
+        for (i, (stake, points)) in entries.into_iter().enumerate() {
+            let reward = if i == last {
+                (point_value.rewards - distributed).max(dec!(0))
+            } else {
+                let reward = point_value.share(points);
+                distributed += reward;
+                reward
+            };
+            shares.push((stake.stake_id.clone(), stake.wallet_id.clone(), reward));
+        }
+        shares
+    }
+
+    /// Deterministic partition index for a stake, stable across process
+    /// restarts so resumed epochs never relocate a stake mid-distribution
+    /// (unlike `std`'s `DefaultHasher`, which reseeds every process).
+    /// 32-bit FNV-1a.
+    fn partition_of(stake_id: &str, partitions: u32) -> u32 {
+        const FNV_OFFSET_BASIS: u32 = 0x811c_9dc5;
+        const FNV_PRIME: u32 = 0x0100_0193;
+
+        let mut hash = FNV_OFFSET_BASIS;
+        for byte in stake_id.bytes() {
+            hash ^= byte as u32;
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+        hash % partitions.max(1)
+    }
 }
\ No newline at end of file
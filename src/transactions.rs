@@ -1,11 +1,15 @@
 //! Caesar Transaction Processing - Handle token transfers
 
 use anyhow::{Result, anyhow};
-use chrono::Utc;
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use futures_util::future::join_all;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use rust_decimal::prelude::ToPrimitive;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use tokio::sync::RwLock;
 use tracing::{info, warn, error};
 use uuid::Uuid;
 
@@ -14,15 +18,192 @@ use crate::storage::CaesarStorage;
 use crate::EconomicsConfig;
 use serde::{Deserialize, Serialize};
 
+/// Source of conversion rates for multi-asset transfers.
+///
+/// A static, config-backed provider covers fixed operator rates; a streaming
+/// provider can be wired to a live feed so cross-asset transfers price against
+/// the current market.
+#[async_trait]
+pub trait RateProvider: Send + Sync {
+    async fn get_rate(&self, from_asset: &str, to_asset: &str) -> Result<Rate>;
+}
+
+/// Rate provider backed by a static, operator-configured table. Rates match in
+/// either orientation of the pair.
+#[derive(Default)]
+pub struct StaticRateProvider {
+    rates: HashMap<(String, String), Rate>,
+}
+
+impl StaticRateProvider {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a rate, keyed by its `base/quote` pair.
+    pub fn with_rate(mut self, rate: Rate) -> Self {
+        self.rates.insert((rate.base.clone(), rate.quote.clone()), rate);
+        self
+    }
+}
+
+#[async_trait]
+impl RateProvider for StaticRateProvider {
+    async fn get_rate(&self, from_asset: &str, to_asset: &str) -> Result<Rate> {
+        self.rates
+            .get(&(from_asset.to_string(), to_asset.to_string()))
+            .or_else(|| self.rates.get(&(to_asset.to_string(), from_asset.to_string())))
+            .cloned()
+            .ok_or_else(|| anyhow!("no rate configured for {}/{}", from_asset, to_asset))
+    }
+}
+
+/// Rate provider fed by a live stream, holding the most recent rate.
+pub struct StreamingRateProvider {
+    latest: Arc<RwLock<Rate>>,
+}
+
+impl StreamingRateProvider {
+    pub fn new(initial: Rate) -> Self {
+        Self { latest: Arc::new(RwLock::new(initial)) }
+    }
+
+    /// Shared handle a feed task can write new rates into.
+    pub fn handle(&self) -> Arc<RwLock<Rate>> {
+        self.latest.clone()
+    }
+
+    /// Publish a fresh rate.
+    pub async fn push(&self, rate: Rate) {
+        *self.latest.write().await = rate;
+    }
+}
+
+#[async_trait]
+impl RateProvider for StreamingRateProvider {
+    async fn get_rate(&self, from_asset: &str, to_asset: &str) -> Result<Rate> {
+        let rate = self.latest.read().await.clone();
+        let matches = (from_asset == rate.base && to_asset == rate.quote)
+            || (from_asset == rate.quote && to_asset == rate.base);
+        if matches {
+            Ok(rate)
+        } else {
+            Err(anyhow!("stream carries no rate for {}/{}", from_asset, to_asset))
+        }
+    }
+}
+
+/// Decides how a collected fee is split across destination wallets.
+///
+/// The returned amounts must sum to the input fee so that total supply is
+/// conserved; the processor enforces this before committing. A single-treasury
+/// policy is the default; richer policies can burn a share or fan out to a
+/// validator set.
+pub trait FeePolicy: Send + Sync {
+    fn distribute(&self, fee: Decimal) -> Vec<(String, Decimal)>;
+}
+
+/// Routes the whole fee to the configured treasury wallet.
+pub struct TreasuryFeePolicy {
+    treasury: String,
+}
+
+impl TreasuryFeePolicy {
+    pub fn new(treasury: impl Into<String>) -> Self {
+        Self { treasury: treasury.into() }
+    }
+}
+
+impl FeePolicy for TreasuryFeePolicy {
+    fn distribute(&self, fee: Decimal) -> Vec<(String, Decimal)> {
+        vec![(self.treasury.clone(), fee)]
+    }
+}
+
+/// Burns a fraction of each fee and routes the remainder to the treasury.
+pub struct BurnSplitFeePolicy {
+    treasury: String,
+    burn_wallet: String,
+    burn_ratio: Decimal,
+}
+
+impl BurnSplitFeePolicy {
+    pub fn new(treasury: impl Into<String>, burn_wallet: impl Into<String>, burn_ratio: Decimal) -> Self {
+        Self {
+            treasury: treasury.into(),
+            burn_wallet: burn_wallet.into(),
+            burn_ratio,
+        }
+    }
+}
+
+impl FeePolicy for BurnSplitFeePolicy {
+    fn distribute(&self, fee: Decimal) -> Vec<(String, Decimal)> {
+        let burned = fee * self.burn_ratio;
+        // Give the treasury the remainder so the split always conserves the fee.
+        let to_treasury = fee - burned;
+        vec![
+            (self.burn_wallet.clone(), burned),
+            (self.treasury.clone(), to_treasury),
+        ]
+    }
+}
+
+/// Source of the current chain tip used for confirmation tracking.
+#[async_trait]
+pub trait BlockHeightProvider: Send + Sync {
+    async fn get_block_height(&self) -> Result<u64>;
+}
+
+/// Fallback height source derived from wall-clock time, used when no chain
+/// connection is wired. Advances one unit every ten seconds.
+pub struct TimestampBlockHeightProvider;
+
+#[async_trait]
+impl BlockHeightProvider for TimestampBlockHeightProvider {
+    async fn get_block_height(&self) -> Result<u64> {
+        Ok(Utc::now().timestamp() as u64 / 10)
+    }
+}
+
 /// Transaction processor for handling transfers
 pub struct TransactionProcessor {
     config: EconomicsConfig,
     storage: Arc<CaesarStorage>,
+    rate_provider: Arc<dyn RateProvider>,
+    block_height: Arc<dyn BlockHeightProvider>,
+    fee_policy: Arc<dyn FeePolicy>,
 }
 
 impl TransactionProcessor {
     pub async fn new(config: EconomicsConfig, storage: Arc<CaesarStorage>) -> Result<Self> {
-        Ok(Self { config, storage })
+        let fee_policy: Arc<dyn FeePolicy> =
+            Arc::new(TreasuryFeePolicy::new(config.treasury_wallet.clone()));
+        Ok(Self {
+            config,
+            storage,
+            rate_provider: Arc::new(StaticRateProvider::new()),
+            block_height: Arc::new(TimestampBlockHeightProvider),
+            fee_policy,
+        })
+    }
+
+    /// Wire a fee distribution policy (treasury-only, burn split, validator fan-out).
+    pub fn with_fee_policy(mut self, policy: Arc<dyn FeePolicy>) -> Self {
+        self.fee_policy = policy;
+        self
+    }
+
+    /// Wire a rate provider for multi-asset transfers (live feed or static table).
+    pub fn with_rate_provider(mut self, provider: Arc<dyn RateProvider>) -> Self {
+        self.rate_provider = provider;
+        self
+    }
+
+    /// Wire a block-height provider for confirmation tracking.
+    pub fn with_block_height_provider(mut self, provider: Arc<dyn BlockHeightProvider>) -> Self {
+        self.block_height = provider;
+        self
     }
 
     /// Process a transaction
@@ -33,7 +214,7 @@ impl TransactionProcessor {
         // Get sender balance
         let sender_balance = self.storage.get_balance(&request.from_wallet).await?;
 
-        // Calculate fee
+        // Fee is always computed on the source-asset amount, before conversion.
         let fee = request.amount * self.config.transaction_fee;
         let total_amount = request.amount + fee;
 
@@ -46,6 +227,22 @@ impl TransactionProcessor {
             ));
         }
 
+        // Resolve assets and, when they differ, the credited amount + applied rate.
+        let from_asset = request.from_asset.clone().unwrap_or_else(|| "CSR".to_string());
+        let to_asset = request.to_asset.clone().unwrap_or_else(|| from_asset.clone());
+        let (credited_amount, applied_rate) = self.resolve_conversion(
+            request.amount,
+            &from_asset,
+            &to_asset,
+        ).await?;
+
+        // Reject reuse of a reconciliation memo that is still in flight.
+        if let Some(memo) = &request.memo {
+            if memo.requires_uniqueness() && self.storage.memo_has_unconfirmed(memo).await? {
+                return Err(anyhow!("memo {:?} already in use by an unconfirmed transaction", memo));
+            }
+        }
+
         // Create transaction
         let transaction_id = format!("TX_{}", Uuid::new_v4().to_string().replace("-", "").to_uppercase()[..16].to_string());
 
@@ -59,19 +256,33 @@ impl TransactionProcessor {
             fee,
             description: request.description.unwrap_or_else(|| "Token transfer".to_string()),
             timestamp: Utc::now(),
+            applied_rate,
+            memo: request.memo.clone(),
         };
 
         // Store transaction
         self.storage.create_transaction(transaction.clone()).await?;
 
+        // Index the memo for reconciliation lookups.
+        if let Some(memo) = &transaction.memo {
+            self.storage.index_memo(memo, &transaction_id).await?;
+        }
+
         // Process balances atomically
-        match self.execute_transfer(&request.from_wallet, &request.to_wallet, request.amount, fee).await {
+        match self.execute_transfer(&transaction_id, &request.from_wallet, &request.to_wallet, request.amount, credited_amount, fee).await {
             Ok(_) => {
                 info!(
                     "Transaction {} processed: {} CSR from {} to {}",
                     transaction_id, request.amount, request.from_wallet, request.to_wallet
                 );
 
+                // Balances applied: advance the ledger row Pending -> Completed.
+                self.storage
+                    .update_transaction_status(&transaction_id, TransactionStatus::Completed)
+                    .await?;
+
+                let block_height = self.block_height.get_block_height().await.ok();
+
                 // Return success response
                 Ok(TransactionResponse {
                     transaction_id,
@@ -83,13 +294,18 @@ impl TransactionProcessor {
                     fee,
                     description: transaction.description,
                     timestamp: transaction.timestamp,
-                    block_height: Some(self.get_current_block_height()),
-                    confirmation_count: 1,
+                    block_height,
+                    confirmation_count: 0,
                 })
             }
             Err(e) => {
                 error!("Transaction failed: {}", e);
 
+                self.storage
+                    .update_transaction_status(&transaction_id, TransactionStatus::Failed)
+                    .await
+                    .ok();
+
                 // Return failed transaction
                 Ok(TransactionResponse {
                     transaction_id,
@@ -136,62 +352,252 @@ impl TransactionProcessor {
         self.storage.get_wallet(&request.from_wallet).await
             .map_err(|_| anyhow!("Sender wallet not found"))?;
 
-        // Create receiver wallet if it doesn't exist
-        if self.storage.get_wallet(&request.to_wallet).await.is_err() {
-            warn!("Receiver wallet {} not found, creating new wallet", request.to_wallet);
-            // In production, this would require proper user registration
-        }
+        // Verify receiver wallet exists. `wallet_id`s are server-generated
+        // (see `CaesarStorage::create_wallet`), so there is no "create it on
+        // the fly" fallback that preserves `to_wallet` as the destination —
+        // a caller-supplied id that doesn't resolve to a real wallet is
+        // always a typo or a stale reference, and must fail the transfer
+        // rather than silently debit the sender with nowhere for the funds
+        // to land.
+        self.storage.get_wallet(&request.to_wallet).await
+            .map_err(|_| anyhow!("Receiver wallet not found"))?;
 
         Ok(())
     }
 
-    /// Execute the actual transfer
+    /// Resolve the destination-asset credit and the rate applied.
+    ///
+    /// For a same-asset transfer the credit equals the source amount and no
+    /// rate is recorded. For a cross-asset transfer the current rate is looked
+    /// up, rejected if stale, and used to convert; a conversion where either
+    /// leg rounds to zero is rejected.
+    async fn resolve_conversion(
+        &self,
+        amount: Decimal,
+        from_asset: &str,
+        to_asset: &str,
+    ) -> Result<(Decimal, Option<Rate>)> {
+        if from_asset == to_asset {
+            return Ok((amount, None));
+        }
+
+        let rate = self.rate_provider.get_rate(from_asset, to_asset).await?;
+
+        // Reject stale rates.
+        let age = Utc::now() - rate.timestamp;
+        if age > Duration::seconds(self.config.max_rate_age_secs) {
+            return Err(anyhow!("conversion rate is stale ({}s old)", age.num_seconds()));
+        }
+
+        let credited = rate.convert(amount, from_asset, to_asset)?;
+        if amount.is_zero() || credited.is_zero() {
+            return Err(anyhow!("conversion rounds to zero"));
+        }
+
+        Ok((credited, Some(rate)))
+    }
+
+    /// Execute the actual transfer.
+    ///
+    /// The common two-party path (no fee) commits both balances in a single
+    /// atomic swap so partial application is impossible. A fee turns the move
+    /// multi-party, so it is staged through the write-ahead journal instead:
+    /// the intended deltas are recorded `Pending`, applied, then flipped to
+    /// `Committed`, leaving any crash mid-flight replayable by [`recover`].
+    ///
+    /// [`recover`]: Self::recover
     async fn execute_transfer(
         &self,
+        transaction_id: &str,
         from_wallet: &str,
         to_wallet: &str,
-        amount: Decimal,
+        source_amount: Decimal,
+        credit_amount: Decimal,
         fee: Decimal,
     ) -> Result<()> {
-        // Get current balances
-        let sender_balance = self.storage.get_balance(from_wallet).await?;
-        let receiver_balance = self.storage.get_balance(to_wallet).await
-            .unwrap_or(dec!(0)); // Handle new wallets
+        let total_deduction = source_amount + fee;
+
+        if fee.is_zero() {
+            // Common two-party path: one atomic, balance-guarded transfer, no
+            // journal required. The balance check happens inside the same
+            // transaction as the debit/credit, so a concurrent transfer
+            // against the same sender can't both pass a stale check.
+            self.storage
+                .execute_transfer(from_wallet, to_wallet, total_deduction, credit_amount)
+                .await
+                .map_err(|_| anyhow!("Insufficient balance during execution"))?;
+        } else {
+            // Multi-party move (fee leg): journal intended deltas, apply, commit.
+            let sender_balance = self.storage.get_balance(from_wallet).await?;
+            let receiver_balance = self.storage.get_balance(to_wallet).await
+                .unwrap_or(dec!(0)); // Handle new wallets
+
+            if sender_balance < total_deduction {
+                return Err(anyhow!("Insufficient balance during execution"));
+            }
 
-        // Calculate new balances
-        let total_deduction = amount + fee;
-        if sender_balance < total_deduction {
-            return Err(anyhow!("Insufficient balance during execution"));
+            let new_sender_balance = sender_balance - total_deduction;
+            let new_receiver_balance = receiver_balance + credit_amount;
+
+            let treasury = self.config.treasury_wallet.clone();
+            let treasury_pre = self.storage.get_balance(&treasury).await.unwrap_or(dec!(0));
+            let entry = JournalEntry {
+                transaction_id: transaction_id.to_string(),
+                from_wallet: from_wallet.to_string(),
+                to_wallet: to_wallet.to_string(),
+                treasury_wallet: Some(treasury),
+                from_pre: sender_balance,
+                to_pre: receiver_balance,
+                treasury_pre,
+                from_delta: -total_deduction,
+                to_delta: credit_amount,
+                treasury_delta: fee,
+                status: JournalStatus::Pending,
+                created_at: Utc::now(),
+            };
+            self.storage.write_journal_entry(&entry).await?;
+            self.storage.update_balance(from_wallet, new_sender_balance).await?;
+            self.storage.update_balance(to_wallet, new_receiver_balance).await?;
+            self.credit_fee(transaction_id, from_wallet, fee).await?;
+            self.storage.mark_journal_committed(transaction_id).await?;
         }
 
-        let new_sender_balance = sender_balance - total_deduction;
-        let new_receiver_balance = receiver_balance + amount;
+        Ok(())
+    }
 
-        // Update balances
-        self.storage.update_balance(from_wallet, new_sender_balance).await?;
-        self.storage.update_balance(to_wallet, new_receiver_balance).await?;
+    /// Replay any transfers left `Pending` in the journal by a crash.
+    ///
+    /// If a journaled transfer's balances still match the recorded pre-state no
+    /// update landed, so it is completed; otherwise it was applied partially and
+    /// every leg is rolled back. Either way the entry is then marked
+    /// `Committed`. Returns the number of entries recovered.
+    pub async fn recover(&self) -> Result<usize> {
+        let pending = self.storage.get_pending_journal_entries().await?;
+        let count = pending.len();
+
+        for entry in pending {
+            let from_now = self.storage.get_balance(&entry.from_wallet).await.unwrap_or(dec!(0));
+            let to_now = self.storage.get_balance(&entry.to_wallet).await.unwrap_or(dec!(0));
+
+            if from_now == entry.from_pre && to_now == entry.to_pre {
+                // Nothing applied before the crash: complete the transfer.
+                self.storage.update_balance(&entry.from_wallet, entry.from_pre + entry.from_delta).await?;
+                self.storage.update_balance(&entry.to_wallet, entry.to_pre + entry.to_delta).await?;
+                if let Some(treasury) = &entry.treasury_wallet {
+                    let treasury_now = self.storage.get_balance(treasury).await.unwrap_or(dec!(0));
+                    self.storage.update_balance(treasury, treasury_now + entry.treasury_delta).await?;
+                }
+                info!("Recovered journaled transfer {} by completing it", entry.transaction_id);
+            } else {
+                // Partial application: roll every leg back to its pre-state.
+                self.storage.update_balance(&entry.from_wallet, entry.from_pre).await?;
+                self.storage.update_balance(&entry.to_wallet, entry.to_pre).await?;
+                if let Some(treasury) = &entry.treasury_wallet {
+                    self.storage.update_balance(treasury, entry.treasury_pre).await?;
+                }
+                warn!("Rolled back partial journaled transfer {}", entry.transaction_id);
+            }
 
-        // Fee goes to treasury/system
-        if fee > dec!(0) {
-            self.process_fee(fee).await?;
+            self.storage.mark_journal_committed(&entry.transaction_id).await?;
         }
 
-        Ok(())
+        Ok(count)
     }
 
-    /// Process transaction fee
-    async fn process_fee(&self, fee: Decimal) -> Result<()> {
-        // In production, fees would go to treasury or be distributed
-        // For now, we just log it
-        info!("Transaction fee collected: {} CSR", fee);
+    /// Credit a collected fee to its policy-chosen destinations and record a
+    /// `Fee` ledger entry per destination.
+    ///
+    /// Enforces the conservation invariant `sum(fee_destinations) == fee` before
+    /// touching any balance, so no supply is created or destroyed by rounding.
+    async fn credit_fee(&self, source_tx: &str, from_wallet: &str, fee: Decimal) -> Result<()> {
+        if fee.is_zero() {
+            return Ok(());
+        }
+
+        let destinations = self.fee_policy.distribute(fee);
+        let distributed: Decimal = destinations.iter().map(|(_, amount)| *amount).sum();
+        if distributed != fee {
+            return Err(anyhow!(
+                "fee distribution {} does not conserve collected fee {}",
+                distributed,
+                fee
+            ));
+        }
+
+        for (wallet, amount) in destinations {
+            if amount.is_zero() {
+                continue;
+            }
+
+            let balance = self.storage.get_balance(&wallet).await.unwrap_or(dec!(0));
+            self.storage.update_balance(&wallet, balance + amount).await?;
+
+            let fee_id = format!(
+                "FEE_{}",
+                Uuid::new_v4().to_string().replace('-', "").to_uppercase()[..16].to_string()
+            );
+            let ledger = Transaction {
+                transaction_id: fee_id,
+                from_wallet: from_wallet.to_string(),
+                to_wallet: wallet.clone(),
+                amount,
+                transaction_type: TransactionType::Fee,
+                status: TransactionStatus::Completed,
+                fee: dec!(0),
+                description: format!("Fee collected from transaction {}", source_tx),
+                timestamp: Utc::now(),
+                applied_rate: None,
+                memo: None,
+            };
+            self.storage.create_transaction(ledger).await?;
+        }
+
+        info!("Transaction fee {} CSR collected from {}", fee, from_wallet);
         Ok(())
     }
 
-    /// Get current block height (simulated)
-    fn get_current_block_height(&self) -> u64 {
-        // In production, this would connect to actual blockchain
-        // For now, simulate with timestamp
-        Utc::now().timestamp() as u64 / 10
+    /// Poll block height until the transaction is buried at least
+    /// `target_confirmations` deep, then mark it `Confirmed`.
+    ///
+    /// Models the swap protocols' `poll_until_block_height_is_gte` loop: the
+    /// transaction must already carry a recorded `block_height`, and finality is
+    /// reached once the tip is that height plus the required depth. Callers can
+    /// `await` this to block until the transfer is final.
+    pub async fn wait_for_finality(
+        &self,
+        transaction_id: &str,
+        target_confirmations: u64,
+    ) -> Result<TransactionResponse> {
+        let tx = self.storage.get_transaction(transaction_id).await?;
+        let anchor = tx
+            .block_height
+            .ok_or_else(|| anyhow!("transaction {} has no block height to confirm", transaction_id))?;
+        let target_height = anchor + target_confirmations;
+        let interval =
+            std::time::Duration::from_secs(self.config.finality_poll_interval_secs.max(1));
+
+        loop {
+            let height = self.block_height.get_block_height().await?;
+            if height >= target_height {
+                break;
+            }
+            tokio::time::sleep(interval).await;
+        }
+
+        self.storage
+            .update_transaction_status(transaction_id, TransactionStatus::Confirmed)
+            .await?;
+
+        let mut confirmed = self.storage.get_transaction(transaction_id).await?;
+        confirmed.status = TransactionStatus::Confirmed;
+        confirmed.confirmation_count = target_confirmations as u32;
+
+        info!(
+            "Transaction {} reached finality at {} confirmations",
+            transaction_id, target_confirmations
+        );
+
+        Ok(confirmed)
     }
 
     /// Process batch transactions
@@ -211,6 +617,86 @@ impl TransactionProcessor {
         Ok(results)
     }
 
+    /// Execute a batch with conflict-aware parallelism.
+    ///
+    /// Two requests conflict when their wallet sets overlap (shared sender or
+    /// receiver). Non-conflicting requests run concurrently; conflicting ones
+    /// are serialized in submission order. The batch is partitioned into
+    /// conflict-free "waves" — each request joins the earliest wave in which
+    /// none of its wallets are already claimed — and each wave runs concurrently
+    /// before the next begins, so a wallet is never read and written by two
+    /// tasks at once. The returned order always matches the input order.
+    pub async fn process_batch_parallel(
+        &self,
+        transactions: Vec<SendTransactionRequest>,
+    ) -> Result<Vec<TransactionResponse>> {
+        // Greedily assign each request (by input index) to a conflict-free wave.
+        let mut waves: Vec<Vec<usize>> = Vec::new();
+        let mut wave_claims: Vec<HashSet<String>> = Vec::new();
+
+        for (idx, request) in transactions.iter().enumerate() {
+            let wallets = self.claimed_wallets(request);
+            let mut placed = false;
+            for (w, claims) in wave_claims.iter_mut().enumerate() {
+                if wallets.iter().all(|wallet| !claims.contains(wallet)) {
+                    claims.extend(wallets.iter().cloned());
+                    waves[w].push(idx);
+                    placed = true;
+                    break;
+                }
+            }
+            if !placed {
+                wave_claims.push(wallets.iter().cloned().collect());
+                waves.push(vec![idx]);
+            }
+        }
+
+        // Execute wave by wave, scattering results back into input order.
+        let mut results: Vec<Option<TransactionResponse>> =
+            (0..transactions.len()).map(|_| None).collect();
+        let mut pending: Vec<Option<SendTransactionRequest>> =
+            transactions.into_iter().map(Some).collect();
+
+        for wave in waves {
+            let wave_futures = wave.iter().map(|&idx| {
+                let request = pending[idx].take().expect("each request executes once");
+                async move { (idx, self.process(request).await) }
+            });
+
+            for (idx, result) in join_all(wave_futures).await {
+                match result {
+                    Ok(response) => results[idx] = Some(response),
+                    Err(e) => error!("Batch transaction {} failed: {}", idx, e),
+                }
+            }
+        }
+
+        Ok(results.into_iter().flatten().collect())
+    }
+
+    /// Wallets a request claims for conflict detection: the union of sender,
+    /// receiver, and — when a fee is charged — the treasury, which is credited
+    /// inside the same transfer and must not be contended across a wave.
+    fn claimed_wallets(&self, request: &SendTransactionRequest) -> Vec<String> {
+        let mut wallets = vec![request.from_wallet.clone(), request.to_wallet.clone()];
+        if !self.config.transaction_fee.is_zero() {
+            wallets.push(self.config.treasury_wallet.clone());
+        }
+        wallets
+    }
+
+    /// Look up every transaction carrying a given structured memo, so an
+    /// integrator can reconcile incoming transfers against an external invoice
+    /// or issue request.
+    pub async fn find_by_memo(&self, memo: Memo) -> Result<Vec<TransactionResponse>> {
+        let ids = self.storage.find_transaction_ids_by_memo(&memo).await?;
+        let mut responses = Vec::with_capacity(ids.len());
+        for id in ids {
+            responses.push(self.storage.get_transaction(&id).await?);
+        }
+        Ok(responses)
+    }
+
     /// Reverse a transaction (admin only)
     pub async fn reverse_transaction(&self, transaction_id: &str) -> Result<TransactionResponse> {
         let original = self.storage.get_transaction(transaction_id).await?;
@@ -225,21 +711,69 @@ impl TransactionProcessor {
             to_wallet: original.from_wallet.clone(),
             amount: original.amount,
             description: Some(format!("Reversal of transaction {}", transaction_id)),
+            from_asset: None,
+            to_asset: None,
+            memo: None,
         };
 
         info!("Reversing transaction {}", transaction_id);
         self.process(reverse_request).await
     }
 
-    /// Get transaction statistics
+    /// Page through a wallet's history forward from `cursor`.
+    ///
+    /// Returns up to `limit` entries newest-first and an opaque cursor for the
+    /// next page, or `None` once the history is exhausted. The cursor orders
+    /// strictly by `(timestamp, transaction_id)` so concurrent inserts never
+    /// cause a page to skip or duplicate an entry.
+    pub async fn list_transactions(
+        &self,
+        wallet_id: &str,
+        cursor: Option<Cursor>,
+        limit: usize,
+    ) -> Result<(Vec<TransactionResponse>, Option<Cursor>)> {
+        let page = self
+            .storage
+            .list_transactions_page(wallet_id, cursor.as_ref(), limit)
+            .await?;
+
+        // A full page implies more may remain; hand back a cursor at its tail.
+        let next = if page.len() == limit {
+            page.last().map(|tx| Cursor {
+                timestamp: tx.timestamp,
+                transaction_id: tx.transaction_id.clone(),
+            })
+        } else {
+            None
+        };
+
+        Ok((page, next))
+    }
+
+    /// Get transaction statistics aggregated from the ledger.
     pub async fn get_statistics(&self) -> Result<TransactionStatistics> {
-        // This would aggregate from database
+        let (count, volume, fees) = self.storage.aggregate_transactions().await?;
+
+        let average = if count > 0 {
+            volume / Decimal::from(count)
+        } else {
+            dec!(0)
+        };
+
+        // Rolling TPS over the configured window.
+        let window = self.config.stats_tps_window_secs.max(1);
+        let recent = self
+            .storage
+            .count_transactions_since(Utc::now() - Duration::seconds(window))
+            .await?;
+        let tps = recent as f64 / window as f64;
+
         Ok(TransactionStatistics {
-            total_transactions: 0,
-            total_volume: dec!(0),
-            total_fees: dec!(0),
-            average_transaction_size: dec!(0),
-            transactions_per_second: 0.0,
+            total_transactions: count,
+            total_volume: volume,
+            total_fees: fees,
+            average_transaction_size: average,
+            transactions_per_second: tps,
         })
     }
 }
@@ -0,0 +1,142 @@
+//! Caesar External Wallet Backend - pluggable external chain wallet pairing
+//!
+//! A Caesar wallet is normally purely internal, backed by nothing but
+//! `storage::get_balance`. `create_new_wallet`'s optional `external_descriptor`
+//! can additionally pair it to a remote chain wallet through an
+//! [`ExternalWallet`] implementation. The trait mirrors
+//! [`crate::exchange::PriceFeed`]'s pull-based shape: a backend is never
+//! scanned implicitly, so a long blocking UTXO/account scan never stalls a
+//! request handler. Callers must `sync()` once before the first
+//! `balance()`/`latest_block_height()` call; everything after that reads the
+//! cache `sync()` last refreshed.
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+/// A remote chain account paired to a Caesar wallet via `external_descriptor`.
+#[async_trait]
+pub trait ExternalWallet: Send + Sync {
+    /// One-shot scan of the remote node, refreshing the cached state the
+    /// other methods read. Must be called at least once before them.
+    async fn sync(&self) -> Result<()>;
+
+    /// Balance as of the last `sync()`. Errs if never synced.
+    async fn balance(&self) -> Result<Decimal>;
+
+    /// Chain tip height as of the last `sync()`. Errs if never synced.
+    async fn latest_block_height(&self) -> Result<u64>;
+
+    /// Confirmations for `txid`, queried live against the remote node (a
+    /// single transaction's confirmation depth is cheap enough to not need
+    /// the cached-scan treatment the balance/height getters do).
+    async fn tx_confirmations(&self, txid: &str) -> Result<u64>;
+}
+
+/// Configuration for the HTTP/RPC-backed [`ExternalWallet`] implementation.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WalletBackendConfig {
+    /// Base URL of the remote node's HTTP/RPC endpoint.
+    pub rpc_url: String,
+
+    /// Timeout applied to each request against `rpc_url`.
+    pub request_timeout_secs: u64,
+}
+
+impl Default for WalletBackendConfig {
+    fn default() -> Self {
+        Self {
+            rpc_url: "http://127.0.0.1:8332".to_string(),
+            request_timeout_secs: 10,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcBalanceResponse {
+    balance: Decimal,
+    block_height: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcConfirmationsResponse {
+    confirmations: u64,
+}
+
+/// Balance/height snapshot as of the last [`HttpRpcWallet::sync`].
+#[derive(Debug, Clone, Copy)]
+struct SyncedState {
+    balance: Decimal,
+    block_height: u64,
+}
+
+/// Talks to a configurable HTTP/RPC endpoint on behalf of a single external
+/// `descriptor` (an output descriptor, xpub, or account identifier the
+/// remote node understands), caching the synced balance/height until the
+/// next [`Self::sync`] refreshes it.
+pub struct HttpRpcWallet {
+    config: WalletBackendConfig,
+    descriptor: String,
+    client: reqwest::Client,
+    state: RwLock<Option<SyncedState>>,
+}
+
+impl HttpRpcWallet {
+    pub fn new(config: WalletBackendConfig, descriptor: String) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.request_timeout_secs))
+            .build()
+            .unwrap_or_default();
+        Self { config, descriptor, client, state: RwLock::new(None) }
+    }
+
+    async fn require_synced(&self) -> Result<SyncedState> {
+        self.state
+            .read()
+            .await
+            .ok_or_else(|| anyhow!("External wallet {} has not been synced yet", self.descriptor))
+    }
+}
+
+#[async_trait]
+impl ExternalWallet for HttpRpcWallet {
+    async fn sync(&self) -> Result<()> {
+        let response: RpcBalanceResponse = self
+            .client
+            .get(format!("{}/wallet/{}/balance", self.config.rpc_url, self.descriptor))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        *self.state.write().await = Some(SyncedState {
+            balance: response.balance,
+            block_height: response.block_height,
+        });
+        Ok(())
+    }
+
+    async fn balance(&self) -> Result<Decimal> {
+        Ok(self.require_synced().await?.balance)
+    }
+
+    async fn latest_block_height(&self) -> Result<u64> {
+        Ok(self.require_synced().await?.block_height)
+    }
+
+    async fn tx_confirmations(&self, txid: &str) -> Result<u64> {
+        let response: RpcConfirmationsResponse = self
+            .client
+            .get(format!("{}/tx/{}/confirmations", self.config.rpc_url, txid))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        Ok(response.confirmations)
+    }
+}
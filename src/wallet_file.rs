@@ -0,0 +1,299 @@
+//! Caesar Wallet File - versioned, passphrase-recoverable wallet backup
+//!
+//! Unlike [`crate::backup::BackupManager`], which snapshots a wallet's full
+//! transaction/reward/stake history keyed off a plain SHA-256-derived key, a
+//! [`WalletFile`] is a small, portable `.dat`-style recovery artifact: a
+//! versioned header (magic bytes, format version, Argon2id KDF params)
+//! followed by an Argon2id + ChaCha20-Poly1305 sealed payload carrying just
+//! enough to rebuild the wallet deterministically — its id, owner, balance,
+//! and a derivation seed. The seed is derived from the id on export, and
+//! import re-derives it and rejects a file whose stored seed differs, so
+//! restored derived state is reproducible rather than trusting whatever the
+//! file happened to carry.
+
+use anyhow::{anyhow, Result};
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use rand::RngCore;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+
+use crate::storage::CaesarStorage;
+
+/// Identifies a `WalletFile` blob before any parsing is attempted.
+const MAGIC: &[u8; 4] = b"CWF1";
+
+/// Current on-disk format version. A [`WalletFileManager::import_wallet`] call on a file
+/// with a different version errs clearly instead of failing to decrypt.
+const FORMAT_VERSION: u8 = 1;
+
+/// Nonce length ChaCha20-Poly1305 requires (96 bits).
+const NONCE_LEN: usize = 12;
+
+/// Salt length fed to Argon2id.
+const SALT_LEN: usize = 16;
+
+/// Derived key length (ChaCha20-Poly1305's key size).
+const KEY_LEN: usize = 32;
+
+/// Argon2id parameters baked into every export. Stored in the file's header
+/// (rather than hardcoded on the import side too) so a future export can
+/// tune them without breaking import of files written under the old ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KdfParams {
+    pub memory_kib: u32,
+    pub iterations: u32,
+    pub parallelism: u32,
+}
+
+/// Current export-time defaults (OWASP-recommended Argon2id baseline).
+const KDF_PARAMS: KdfParams = KdfParams { memory_kib: 19_456, iterations: 2, parallelism: 1 };
+
+/// Decrypted contents of a `WalletFile`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct WalletFilePayload {
+    wallet_id: String,
+    user_id: String,
+    balance: Decimal,
+    /// Deterministic per-wallet seed; re-checked against
+    /// [`WalletFileManager::deterministic_seed`] on import rather than
+    /// trusted outright, so a hand-edited payload can't smuggle in a seed
+    /// for a different wallet id.
+    seed: [u8; 32],
+}
+
+/// A versioned, encrypted wallet recovery file.
+///
+/// `export_wallet`/`import_wallet` move this to and from its on-disk byte
+/// layout via [`Self::to_bytes`]/[`Self::from_bytes`]:
+/// `MAGIC | version:u8 | memory_kib:u32 | iterations:u32 | parallelism:u32 | salt:16 | nonce:12 | ciphertext`
+/// (all multi-byte integers little-endian).
+#[derive(Debug, Clone)]
+pub struct WalletFile {
+    pub version: u8,
+    pub kdf_params: KdfParams,
+    pub salt: [u8; SALT_LEN],
+    pub nonce: [u8; NONCE_LEN],
+    pub ciphertext: Vec<u8>,
+}
+
+impl WalletFile {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(MAGIC.len() + 13 + SALT_LEN + NONCE_LEN + self.ciphertext.len());
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(self.version);
+        bytes.extend_from_slice(&self.kdf_params.memory_kib.to_le_bytes());
+        bytes.extend_from_slice(&self.kdf_params.iterations.to_le_bytes());
+        bytes.extend_from_slice(&self.kdf_params.parallelism.to_le_bytes());
+        bytes.extend_from_slice(&self.salt);
+        bytes.extend_from_slice(&self.nonce);
+        bytes.extend_from_slice(&self.ciphertext);
+        bytes
+    }
+
+    /// Inverse of [`Self::to_bytes`]. Errs on a bad magic or truncated
+    /// header; a wrong passphrase or tampered ciphertext only surfaces once
+    /// [`WalletFileManager::import_wallet`] attempts AEAD decryption.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        const HEADER_LEN: usize = 4 + 1 + 4 + 4 + 4 + SALT_LEN + NONCE_LEN;
+        if bytes.len() < HEADER_LEN {
+            return Err(anyhow!("wallet file is truncated"));
+        }
+        if &bytes[0..4] != MAGIC {
+            return Err(anyhow!("not a Caesar wallet file (bad magic bytes)"));
+        }
+
+        let mut offset = 4;
+        let version = bytes[offset];
+        offset += 1;
+
+        let memory_kib = u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap());
+        offset += 4;
+        let iterations = u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap());
+        offset += 4;
+        let parallelism = u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap());
+        offset += 4;
+
+        let mut salt = [0u8; SALT_LEN];
+        salt.copy_from_slice(&bytes[offset..offset + SALT_LEN]);
+        offset += SALT_LEN;
+
+        let mut nonce = [0u8; NONCE_LEN];
+        nonce.copy_from_slice(&bytes[offset..offset + NONCE_LEN]);
+        offset += NONCE_LEN;
+
+        Ok(Self {
+            version,
+            kdf_params: KdfParams { memory_kib, iterations, parallelism },
+            salt,
+            nonce,
+            ciphertext: bytes[offset..].to_vec(),
+        })
+    }
+}
+
+/// Derive a 256-bit ChaCha20-Poly1305 key from a passphrase via Argon2id,
+/// the memory-hard KDF [`crate::backup::BackupManager`]'s plain SHA-256
+/// deliberately skips for that simpler operator tool.
+fn derive_key(passphrase: &str, salt: &[u8; SALT_LEN], params: KdfParams) -> Result<Key> {
+    let argon2_params = Params::new(params.memory_kib, params.iterations, params.parallelism, Some(KEY_LEN))
+        .map_err(|e| anyhow!("invalid Argon2 parameters: {}", e))?;
+    let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, argon2_params);
+
+    let mut key_bytes = [0u8; KEY_LEN];
+    argon2
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key_bytes)
+        .map_err(|e| anyhow!("key derivation failed: {}", e))?;
+    Ok(*Key::from_slice(&key_bytes))
+}
+
+/// Exports and restores versioned, passphrase-recoverable [`WalletFile`]s.
+pub struct WalletFileManager {
+    storage: Arc<CaesarStorage>,
+}
+
+impl WalletFileManager {
+    pub fn new(storage: Arc<CaesarStorage>) -> Self {
+        Self { storage }
+    }
+
+    /// Seal `wallet_id`'s id, owner, and current balance into a
+    /// [`WalletFile`] under `passphrase`.
+    pub async fn export_wallet(&self, wallet_id: &str, passphrase: &str) -> Result<WalletFile> {
+        let wallet = self.storage.get_wallet(wallet_id).await?;
+        let balance = self.storage.get_balance(wallet_id).await?;
+
+        let payload = WalletFilePayload {
+            wallet_id: wallet.wallet_id.clone(),
+            user_id: wallet.user_id.clone(),
+            balance,
+            seed: Self::deterministic_seed(&wallet.wallet_id),
+        };
+        let plaintext = serde_json::to_vec(&payload)?;
+
+        let mut salt = [0u8; SALT_LEN];
+        rand::thread_rng().fill_bytes(&mut salt);
+        let key = derive_key(passphrase, &salt, KDF_PARAMS)?;
+        let cipher = ChaCha20Poly1305::new(&key);
+
+        let mut nonce_bytes = [0u8; NONCE_LEN];
+        rand::thread_rng().fill_bytes(&mut nonce_bytes);
+        let nonce = Nonce::from_slice(&nonce_bytes);
+
+        let ciphertext = cipher
+            .encrypt(nonce, plaintext.as_ref())
+            .map_err(|_| anyhow!("failed to encrypt wallet file"))?;
+
+        Ok(WalletFile {
+            version: FORMAT_VERSION,
+            kdf_params: KDF_PARAMS,
+            salt,
+            nonce: nonce_bytes,
+            ciphertext,
+        })
+    }
+
+    /// Decrypt `file` with `passphrase` (failing with an authentication
+    /// error on a wrong passphrase or tampered payload) and re-register its
+    /// wallet, rebuilding its derivation seed deterministically from the
+    /// recovered id rather than trusting the payload's own copy. Returns the
+    /// restored wallet's id.
+    pub async fn import_wallet(&self, file: &WalletFile, passphrase: &str) -> Result<String> {
+        if file.version != FORMAT_VERSION {
+            return Err(anyhow!("unsupported wallet file version {}", file.version));
+        }
+
+        let key = derive_key(passphrase, &file.salt, file.kdf_params)?;
+        let cipher = ChaCha20Poly1305::new(&key);
+        let nonce = Nonce::from_slice(&file.nonce);
+        let plaintext = cipher
+            .decrypt(nonce, file.ciphertext.as_ref())
+            .map_err(|_| anyhow!("failed to decrypt wallet file: wrong passphrase or corrupted file"))?;
+
+        let payload: WalletFilePayload = serde_json::from_slice(&plaintext)?;
+
+        if payload.seed != Self::deterministic_seed(&payload.wallet_id) {
+            return Err(anyhow!("wallet file seed does not match its wallet id"));
+        }
+
+        self.storage
+            .restore_wallet_from_file(&payload.wallet_id, &payload.user_id, payload.balance)
+            .await?;
+        Ok(payload.wallet_id)
+    }
+
+    /// Deterministic per-wallet seed, re-derived from `wallet_id` on both
+    /// export and import so derived state never depends on a value that
+    /// merely has to round-trip untouched through the file.
+    fn deterministic_seed(wallet_id: &str) -> [u8; 32] {
+        Sha256::digest(wallet_id.as_bytes()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::CreateWalletRequest;
+    use crate::CaesarConfig;
+    use rust_decimal_macros::dec;
+
+    async fn manager_with_wallet(balance: Decimal) -> (WalletFileManager, String) {
+        let storage = Arc::new(
+            CaesarStorage::new(CaesarConfig::default().database)
+                .await
+                .expect("failed to create in-memory test storage"),
+        );
+        let wallet = storage
+            .create_wallet(CreateWalletRequest {
+                user_id: "test_user".to_string(),
+                initial_balance: Some(balance),
+                external_descriptor: None,
+            })
+            .await
+            .expect("failed to create test wallet");
+
+        (WalletFileManager::new(storage), wallet.wallet_id)
+    }
+
+    #[tokio::test]
+    async fn export_then_import_round_trips_balance_and_id() {
+        let (manager, wallet_id) = manager_with_wallet(dec!(1234.56)).await;
+
+        let file = manager.export_wallet(&wallet_id, "correct horse battery staple").await.unwrap();
+        let restored = manager.import_wallet(&file, "correct horse battery staple").await.unwrap();
+
+        assert_eq!(restored, wallet_id);
+        assert_eq!(manager.storage.get_balance(&wallet_id).await.unwrap(), dec!(1234.56));
+    }
+
+    #[tokio::test]
+    async fn byte_layout_round_trips_through_to_bytes_and_from_bytes() {
+        let (manager, wallet_id) = manager_with_wallet(dec!(10)).await;
+        let file = manager.export_wallet(&wallet_id, "pw").await.unwrap();
+
+        let parsed = WalletFile::from_bytes(&file.to_bytes()).unwrap();
+        let restored = manager.import_wallet(&parsed, "pw").await.unwrap();
+        assert_eq!(restored, wallet_id);
+    }
+
+    #[tokio::test]
+    async fn tampered_payload_fails_authentication() {
+        let (manager, wallet_id) = manager_with_wallet(dec!(50)).await;
+        let mut file = manager.export_wallet(&wallet_id, "pw").await.unwrap();
+
+        let last = file.ciphertext.len() - 1;
+        file.ciphertext[last] ^= 0xFF;
+
+        assert!(manager.import_wallet(&file, "pw").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn wrong_passphrase_fails_authentication() {
+        let (manager, wallet_id) = manager_with_wallet(dec!(50)).await;
+        let file = manager.export_wallet(&wallet_id, "right").await.unwrap();
+        assert!(manager.import_wallet(&file, "wrong").await.is_err());
+    }
+}
@@ -0,0 +1,273 @@
+//! Caesar Webhook Delivery Subsystem
+//!
+//! Lets external consumers subscribe to wallet/transaction/reward lifecycle
+//! events and gives operators a resend API to recover deliveries a consumer's
+//! endpoint dropped, without reconciling state by hand. Deliveries are
+//! signed with HMAC-SHA256 over each endpoint's own secret, the same way a
+//! custody API authenticates its webhook payloads.
+
+use chrono::{DateTime, Duration, Utc};
+use hmac::{Hmac, Mac};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+/// Lifecycle events a [`WebhookEndpoint`] can subscribe to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEventType {
+    WalletCreated,
+    TransactionCreated,
+    TransactionUpdated,
+    RewardCreated,
+    StakeCreated,
+}
+
+/// A consumer's subscription: where to deliver events, which ones it wants,
+/// and the secret used to HMAC-sign each delivery so it can verify
+/// authenticity.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookEndpoint {
+    pub endpoint_id: String,
+    pub target_url: String,
+    pub subscribed_events: Vec<WebhookEventType>,
+    pub secret: String,
+    pub active: bool,
+}
+
+/// Status of one delivery attempt sequence for a single event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeliveryStatus {
+    Pending,
+    Delivered,
+    Failed,
+}
+
+/// One event queued or sent to one [`WebhookEndpoint`]. `attempt_count` and
+/// `next_retry_at` record the exponential backoff computed after a failed
+/// attempt; nothing in this module retries on that schedule, and failed
+/// deliveries are sent again only through the resend methods.
+/// `event_ref` points at the domain record (e.g. a transaction id) the
+/// payload describes rather than duplicating it here.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookDelivery {
+    pub delivery_id: String,
+    pub endpoint_id: String,
+    pub event_type: WebhookEventType,
+    pub event_ref: String,
+    pub payload: serde_json::Value,
+    pub status: DeliveryStatus,
+    pub attempt_count: u32,
+    pub last_attempt_at: Option<DateTime<Utc>>,
+    pub next_retry_at: Option<DateTime<Utc>>,
+}
+
+/// Result of replaying every [`DeliveryStatus::Failed`] delivery.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResendWebhooksResponse {
+    pub resent: u32,
+    pub still_failed: u32,
+}
+
+/// Re-fire only the created/updated notifications recorded for one
+/// transaction, rather than every failed delivery in the system.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ResendTransactionWebhooksRequest {
+    pub transaction_id: String,
+    pub resend_created: bool,
+    pub resend_updated: bool,
+}
+
+/// Base of the exponential backoff: after the n-th failed attempt the next
+/// retry is set this many seconds times 2^n out (60s after the first).
+const RETRY_BASE_SECONDS: i64 = 30;
+/// Longest gap allowed between retries.
+const RETRY_MAX_SECONDS: i64 = 3600;
+
+/// In-memory registry of endpoints and their delivery history, plus the
+/// HTTP client used to actually send them.
+pub struct WebhookManager {
+    client: Client,
+    endpoints: Arc<RwLock<HashMap<String, WebhookEndpoint>>>,
+    deliveries: Arc<RwLock<HashMap<String, WebhookDelivery>>>,
+}
+
+impl WebhookManager {
+    pub fn new() -> Self {
+        Self {
+            client: Client::new(),
+            endpoints: Arc::new(RwLock::new(HashMap::new())),
+            deliveries: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    pub async fn register_endpoint(
+        &self,
+        target_url: String,
+        subscribed_events: Vec<WebhookEventType>,
+        secret: String,
+    ) -> WebhookEndpoint {
+        let endpoint = WebhookEndpoint {
+            endpoint_id: format!("WHE_{}", Uuid::new_v4()),
+            target_url,
+            subscribed_events,
+            secret,
+            active: true,
+        };
+        self.endpoints.write().await.insert(endpoint.endpoint_id.clone(), endpoint.clone());
+        endpoint
+    }
+
+    /// Queue `event_type` for every active, subscribed endpoint and attempt
+    /// delivery immediately.
+    pub async fn notify(&self, event_type: WebhookEventType, event_ref: &str, payload: serde_json::Value) {
+        let endpoints: Vec<WebhookEndpoint> = self
+            .endpoints
+            .read()
+            .await
+            .values()
+            .filter(|e| e.active && e.subscribed_events.contains(&event_type))
+            .cloned()
+            .collect();
+
+        for endpoint in endpoints {
+            let delivery = WebhookDelivery {
+                delivery_id: format!("WHD_{}", Uuid::new_v4()),
+                endpoint_id: endpoint.endpoint_id.clone(),
+                event_type,
+                event_ref: event_ref.to_string(),
+                payload: payload.clone(),
+                status: DeliveryStatus::Pending,
+                attempt_count: 0,
+                last_attempt_at: None,
+                next_retry_at: None,
+            };
+            self.deliveries.write().await.insert(delivery.delivery_id.clone(), delivery.clone());
+            self.attempt_delivery(&endpoint, delivery.delivery_id).await;
+        }
+    }
+
+    /// Sign `delivery.payload` with the endpoint's secret and POST it,
+    /// updating the delivery's status/attempt count/next retry time in place.
+    async fn attempt_delivery(&self, endpoint: &WebhookEndpoint, delivery_id: String) {
+        let mut deliveries = self.deliveries.write().await;
+        let Some(delivery) = deliveries.get_mut(&delivery_id) else { return };
+
+        let body = delivery.payload.to_string();
+        let signature = sign(&endpoint.secret, &body);
+        delivery.attempt_count += 1;
+        delivery.last_attempt_at = Some(Utc::now());
+
+        let result = self
+            .client
+            .post(&endpoint.target_url)
+            .header("X-Caesar-Signature", signature)
+            .body(body)
+            .send()
+            .await;
+
+        match result {
+            Ok(response) if response.status().is_success() => {
+                delivery.status = DeliveryStatus::Delivered;
+                delivery.next_retry_at = None;
+            }
+            _ => {
+                warn!("Webhook delivery {} to {} failed", delivery.delivery_id, endpoint.target_url);
+                delivery.status = DeliveryStatus::Failed;
+                let backoff = (RETRY_BASE_SECONDS * 2i64.pow(delivery.attempt_count.min(10)))
+                    .min(RETRY_MAX_SECONDS);
+                delivery.next_retry_at = Some(Utc::now() + Duration::seconds(backoff));
+            }
+        }
+    }
+
+    /// Replay every [`DeliveryStatus::Failed`] delivery across all endpoints.
+    pub async fn resend_failed_deliveries(&self) -> ResendWebhooksResponse {
+        let failed: Vec<(String, String)> = self
+            .deliveries
+            .read()
+            .await
+            .values()
+            .filter(|d| d.status == DeliveryStatus::Failed)
+            .map(|d| (d.delivery_id.clone(), d.endpoint_id.clone()))
+            .collect();
+
+        let mut resent = 0;
+        for (delivery_id, endpoint_id) in failed {
+            let Some(endpoint) = self.endpoints.read().await.get(&endpoint_id).cloned() else { continue };
+            self.attempt_delivery(&endpoint, delivery_id).await;
+            resent += 1;
+        }
+
+        let still_failed = self
+            .deliveries
+            .read()
+            .await
+            .values()
+            .filter(|d| d.status == DeliveryStatus::Failed)
+            .count() as u32;
+
+        debug!("Resent {} failed webhook(s), {} still failed", resent, still_failed);
+        ResendWebhooksResponse { resent, still_failed }
+    }
+
+    /// Re-fire only the created/updated deliveries recorded for
+    /// `request.transaction_id`, regardless of their current status.
+    pub async fn resend_transaction_webhooks(
+        &self,
+        request: ResendTransactionWebhooksRequest,
+    ) -> ResendWebhooksResponse {
+        let matching: Vec<(String, String)> = self
+            .deliveries
+            .read()
+            .await
+            .values()
+            .filter(|d| d.event_ref == request.transaction_id)
+            .filter(|d| match d.event_type {
+                WebhookEventType::TransactionCreated => request.resend_created,
+                WebhookEventType::TransactionUpdated => request.resend_updated,
+                _ => false,
+            })
+            .map(|d| (d.delivery_id.clone(), d.endpoint_id.clone()))
+            .collect();
+
+        let mut resent = 0;
+        for (delivery_id, endpoint_id) in matching {
+            let Some(endpoint) = self.endpoints.read().await.get(&endpoint_id).cloned() else { continue };
+            self.attempt_delivery(&endpoint, delivery_id).await;
+            resent += 1;
+        }
+
+        let still_failed = self
+            .deliveries
+            .read()
+            .await
+            .values()
+            .filter(|d| d.event_ref == request.transaction_id && d.status == DeliveryStatus::Failed)
+            .count() as u32;
+
+        ResendWebhooksResponse { resent, still_failed }
+    }
+}
+
+impl Default for WebhookManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// HMAC-SHA256 of `body` under `secret`, hex-encoded, carried in the
+/// `X-Caesar-Signature` header so a consumer can verify a delivery actually
+/// came from us.
+fn sign(secret: &str, body: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+        .expect("HMAC accepts keys of any length");
+    mac.update(body.as_bytes());
+    hex::encode(mac.finalize().into_bytes())
+}